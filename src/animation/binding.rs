@@ -0,0 +1,153 @@
+use crate::gl::camera::orbit::OrbitController;
+use crate::gl::camera::projection::Projection;
+use crate::gl::camera::Camera;
+use crate::gl::materials::material::Material;
+use crate::scene::graph::SceneGraph;
+use crate::scene::node::NodeId;
+use crate::scene::transform::Transform;
+use glm::Vec3;
+
+use super::tween::AnimatedValue;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Binding {
+    NodeTranslation(NodeId),
+    NodeRotation(NodeId),
+    NodeScale(NodeId),
+    MaterialParam { material: usize, name: String },
+    CameraFieldOfView,
+    OrbitTarget,
+    OrbitDistance,
+    OrbitYaw,
+    OrbitPitch,
+}
+
+#[derive(Default)]
+pub struct AnimationTargets<'a> {
+    scene: Option<&'a mut SceneGraph>,
+    materials: Vec<&'a mut Material>,
+    camera: Option<&'a mut Camera>,
+    orbit: Option<&'a mut OrbitController>,
+}
+
+impl<'a> AnimationTargets<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_scene(mut self, scene: &'a mut SceneGraph) -> Self {
+        self.scene = Some(scene);
+        self
+    }
+
+    pub fn with_material(mut self, material: &'a mut Material) -> Self {
+        self.materials.push(material);
+        self
+    }
+
+    pub fn with_camera(mut self, camera: &'a mut Camera) -> Self {
+        self.camera = Some(camera);
+        self
+    }
+
+    pub fn with_orbit(mut self, orbit: &'a mut OrbitController) -> Self {
+        self.orbit = Some(orbit);
+        self
+    }
+
+    pub fn apply(&mut self, binding: &Binding, value: AnimatedValue) {
+        let applied = match (binding, value) {
+            (Binding::NodeTranslation(node), AnimatedValue::Vec3(translation)) => {
+                self.update_node(*node, |local| local.translation = translation)
+            }
+            (Binding::NodeRotation(node), AnimatedValue::Rotation(rotation)) => {
+                self.update_node(*node, |local| local.rotation = rotation)
+            }
+            (Binding::NodeScale(node), AnimatedValue::Vec3(scale)) => {
+                self.update_node(*node, |local| local.scale = scale)
+            }
+            (Binding::NodeScale(node), AnimatedValue::Float(scale)) => {
+                self.update_node(*node, |local| local.scale = Vec3::repeat(scale))
+            }
+            (Binding::MaterialParam { material, name }, value) => {
+                match self.materials.get_mut(*material) {
+                    Some(material) => {
+                        set_material_param(material, name, value);
+                        true
+                    }
+                    None => false,
+                }
+            }
+            (Binding::CameraFieldOfView, AnimatedValue::Float(fov)) => match self.camera.as_mut() {
+                Some(camera) => {
+                    set_field_of_view(camera, fov);
+                    true
+                }
+                None => false,
+            },
+            (Binding::OrbitTarget, AnimatedValue::Vec3(target)) => {
+                self.update_orbit(|orbit| orbit.target = target)
+            }
+            (Binding::OrbitDistance, AnimatedValue::Float(distance)) => {
+                self.update_orbit(|orbit| {
+                    orbit.distance = distance.clamp(orbit.min_distance, orbit.max_distance)
+                })
+            }
+            (Binding::OrbitYaw, AnimatedValue::Float(yaw)) => {
+                self.update_orbit(|orbit| orbit.yaw = yaw)
+            }
+            (Binding::OrbitPitch, AnimatedValue::Float(pitch)) => {
+                self.update_orbit(|orbit| orbit.pitch = pitch)
+            }
+            _ => false,
+        };
+
+        if !applied {
+            log::warn!("Animated value {:?} can't drive {:?}", value, binding);
+        }
+    }
+
+    fn update_node(&mut self, node: NodeId, update: impl FnOnce(&mut Transform)) -> bool {
+        match self.scene.as_mut() {
+            Some(scene) if scene.contains(node) => {
+                scene.update_local(node, update);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn update_orbit(&mut self, update: impl FnOnce(&mut OrbitController)) -> bool {
+        match self.orbit.as_mut() {
+            Some(orbit) => {
+                update(orbit);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn set_material_param(material: &mut Material, name: &str, value: AnimatedValue) {
+    match value {
+        AnimatedValue::Float(value) => material.set(name, value),
+        AnimatedValue::Vec3(value) => material.set(name, value),
+        AnimatedValue::Vec4(value) => material.set(name, value),
+        AnimatedValue::Rotation(value) => material.set(name, value.coords),
+    }
+}
+
+fn set_field_of_view(camera: &mut Camera, fov: f32) {
+    let projection = match *camera.projection() {
+        Projection::Perspective { near, far, .. } => Projection::Perspective {
+            fov_y: fov,
+            near,
+            far,
+        },
+        Projection::InfiniteReversedZ { near, .. } => {
+            Projection::InfiniteReversedZ { fov_y: fov, near }
+        }
+        orthographic => orthographic,
+    };
+    camera.set_projection(projection);
+}
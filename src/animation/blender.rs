@@ -0,0 +1,197 @@
+use std::rc::Rc;
+
+use super::clip::AnimationClip;
+use super::pose::Pose;
+use super::skeleton::Skeleton;
+
+#[derive(Debug, Clone)]
+pub struct ClipState {
+    pub clip: Rc<AnimationClip>,
+    pub time: f32,
+    pub speed: f32,
+    pub looping: bool,
+}
+
+impl ClipState {
+    pub fn new(clip: Rc<AnimationClip>) -> Self {
+        Self {
+            clip,
+            time: 0.0,
+            speed: 1.0,
+            looping: true,
+        }
+    }
+
+    pub fn advance(&mut self, delta_secs: f32) {
+        let duration = self.clip.duration();
+        self.time += delta_secs * self.speed;
+        self.time = if self.looping && duration > 0.0 {
+            self.time.rem_euclid(duration)
+        } else {
+            self.time.clamp(0.0, duration)
+        };
+    }
+
+    pub fn sample(&self, pose: &mut Pose) {
+        self.clip.sample(self.time, pose);
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AnimationBlender {
+    current: Option<ClipState>,
+    previous: Option<ClipState>,
+    fade_duration: f32,
+    fade_elapsed: f32,
+}
+
+impl AnimationBlender {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn current(&self) -> Option<&ClipState> {
+        self.current.as_ref()
+    }
+
+    pub fn current_mut(&mut self) -> Option<&mut ClipState> {
+        self.current.as_mut()
+    }
+
+    pub fn is_fading(&self) -> bool {
+        self.previous.is_some()
+    }
+
+    pub fn fade_weight(&self) -> f32 {
+        if self.previous.is_none() || self.fade_duration <= 0.0 {
+            return 1.0;
+        }
+        (self.fade_elapsed / self.fade_duration).clamp(0.0, 1.0)
+    }
+
+    pub fn play(&mut self, clip: Rc<AnimationClip>) {
+        self.current = Some(ClipState::new(clip));
+        self.previous = None;
+    }
+
+    pub fn cross_fade(&mut self, clip: Rc<AnimationClip>, duration: f32) {
+        if duration <= 0.0 || self.current.is_none() {
+            self.play(clip);
+            return;
+        }
+        self.previous = self.current.replace(ClipState::new(clip));
+        self.fade_duration = duration;
+        self.fade_elapsed = 0.0;
+    }
+
+    pub fn update(&mut self, delta_secs: f32) {
+        if let Some(current) = self.current.as_mut() {
+            current.advance(delta_secs);
+        }
+        if let Some(previous) = self.previous.as_mut() {
+            previous.advance(delta_secs);
+            self.fade_elapsed += delta_secs;
+            if self.fade_elapsed >= self.fade_duration {
+                self.previous = None;
+            }
+        }
+    }
+
+    pub fn evaluate(&self, skeleton: &Skeleton) -> Pose {
+        let rest = skeleton.rest_pose();
+        let mut pose = rest.clone();
+        if let Some(current) = self.current.as_ref() {
+            current.sample(&mut pose);
+        }
+
+        match self.previous.as_ref() {
+            Some(previous) => {
+                let mut from = rest;
+                previous.sample(&mut from);
+                from.blend(&pose, self.fade_weight())
+            }
+            None => pose,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::clip::Track;
+    use crate::animation::interpolation::Interpolation;
+    use crate::animation::skeleton::Joint;
+    use crate::scene::transform::Transform;
+    use glm::Vec3;
+
+    const TOLERANCE: f32 = 1e-5;
+    const STEP: f32 = 0.25;
+
+    fn skeleton() -> Skeleton {
+        Skeleton::try_new(vec![Joint::new("root", None, Transform::identity())]).unwrap()
+    }
+
+    fn holding(translation: Vec3) -> Rc<AnimationClip> {
+        let track = Track::try_new(
+            vec![0.0, 1.0],
+            vec![translation, translation],
+            Interpolation::Linear,
+        )
+        .unwrap();
+        Rc::new(AnimationClip::new("hold").with_translation(0, track))
+    }
+
+    fn root_translation(blender: &AnimationBlender) -> Vec3 {
+        blender.evaluate(&skeleton()).joints()[0].translation
+    }
+
+    #[test]
+    fn cross_fade_weights_sum_to_one() {
+        let from = Vec3::new(2.0, 0.0, -1.0);
+        let to = Vec3::new(10.0, 4.0, 3.0);
+        let mut blender = AnimationBlender::new();
+        blender.play(holding(from));
+        assert_eq!(blender.fade_weight(), 1.0);
+        assert!((root_translation(&blender) - from).norm() < TOLERANCE);
+
+        blender.cross_fade(holding(to), 1.0);
+        let mut previous_weight = 0.0;
+        while blender.is_fading() {
+            let weight = blender.fade_weight();
+            assert!((0.0..=1.0).contains(&weight));
+            assert!(weight >= previous_weight);
+            previous_weight = weight;
+
+            let expected = from * (1.0 - weight) + to * weight;
+            assert!((root_translation(&blender) - expected).norm() < TOLERANCE);
+            blender.update(STEP);
+        }
+        assert_eq!(blender.fade_weight(), 1.0);
+        assert!((root_translation(&blender) - to).norm() < TOLERANCE);
+    }
+
+    #[test]
+    fn cross_fade_between_equal_poses_keeps_pose() {
+        let translation = Vec3::new(3.0, 3.0, 3.0);
+        let mut blender = AnimationBlender::new();
+        blender.play(holding(translation));
+        blender.cross_fade(holding(translation), 1.0);
+        for _ in 0..3 {
+            blender.update(STEP);
+            assert!(blender.is_fading());
+            assert!((root_translation(&blender) - translation).norm() < TOLERANCE);
+        }
+    }
+
+    #[test]
+    fn cross_fade_without_duration_switches_immediately() {
+        let to = Vec3::new(1.0, 2.0, 3.0);
+        let mut blender = AnimationBlender::new();
+        blender.cross_fade(holding(Vec3::zeros()), 1.0);
+        assert!(!blender.is_fading());
+
+        blender.cross_fade(holding(to), 0.0);
+        assert!(!blender.is_fading());
+        assert!((root_translation(&blender) - to).norm() < TOLERANCE);
+    }
+}
@@ -0,0 +1,330 @@
+use glm::Vec3;
+use na::UnitQuaternion;
+
+use super::error::AnimationError;
+use super::interpolation::{Animatable, Interpolation};
+use super::pose::Pose;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track<T: Animatable> {
+    times: Vec<f32>,
+    values: Vec<T>,
+    interpolation: Interpolation,
+}
+
+impl<T: Animatable> Track<T> {
+    pub fn try_new(
+        times: Vec<f32>,
+        values: Vec<T>,
+        interpolation: Interpolation,
+    ) -> Result<Self, AnimationError> {
+        if times.is_empty() {
+            return Err(AnimationError::EmptyTrack);
+        }
+        if let Some(index) = times.windows(2).position(|pair| pair[1] < pair[0]) {
+            return Err(AnimationError::UnsortedKeyframes(index + 1));
+        }
+        let expected = times.len() * interpolation.values_per_keyframe();
+        if values.len() != expected {
+            return Err(AnimationError::KeyframeCountMismatch {
+                times: times.len(),
+                expected,
+                actual: values.len(),
+            });
+        }
+
+        Ok(Self {
+            times,
+            values,
+            interpolation,
+        })
+    }
+
+    pub fn constant(value: T) -> Self {
+        Self {
+            times: vec![0.0],
+            values: vec![value],
+            interpolation: Interpolation::Step,
+        }
+    }
+
+    pub fn times(&self) -> &[f32] {
+        &self.times
+    }
+
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    pub fn start_time(&self) -> f32 {
+        self.times[0]
+    }
+
+    pub fn end_time(&self) -> f32 {
+        self.times[self.times.len() - 1]
+    }
+
+    pub fn keyframe_value(&self, index: usize) -> &T {
+        match self.interpolation {
+            Interpolation::CubicSpline => &self.values[index * 3 + 1],
+            Interpolation::Step | Interpolation::Linear => &self.values[index],
+        }
+    }
+
+    pub fn sample(&self, time: f32) -> T {
+        let next = self.times.partition_point(|&keyframe| keyframe <= time);
+        if next == 0 {
+            return *self.keyframe_value(0);
+        }
+        if next == self.times.len() {
+            return *self.keyframe_value(next - 1);
+        }
+
+        let previous = next - 1;
+        let span = self.times[next] - self.times[previous];
+        let t = (time - self.times[previous]) / span;
+
+        match self.interpolation {
+            Interpolation::Step => *self.keyframe_value(previous),
+            Interpolation::Linear => self
+                .keyframe_value(previous)
+                .interpolate(self.keyframe_value(next), t),
+            Interpolation::CubicSpline => T::hermite(
+                &self.values[previous * 3 + 1],
+                &self.values[previous * 3 + 2],
+                &self.values[next * 3 + 1],
+                &self.values[next * 3],
+                span,
+                t,
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChannelTarget {
+    Translation(Track<Vec3>),
+    Rotation(Track<UnitQuaternion<f32>>),
+    Scale(Track<Vec3>),
+}
+
+impl ChannelTarget {
+    pub fn end_time(&self) -> f32 {
+        match self {
+            ChannelTarget::Translation(track) | ChannelTarget::Scale(track) => track.end_time(),
+            ChannelTarget::Rotation(track) => track.end_time(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Channel {
+    pub joint: usize,
+    pub target: ChannelTarget,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationClip {
+    name: String,
+    channels: Vec<Channel>,
+    duration: f32,
+}
+
+impl AnimationClip {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            channels: vec![],
+            duration: 0.0,
+        }
+    }
+
+    pub fn with_channel(mut self, joint: usize, target: ChannelTarget) -> Self {
+        self.duration = self.duration.max(target.end_time());
+        self.channels.push(Channel { joint, target });
+        self
+    }
+
+    pub fn with_translation(self, joint: usize, track: Track<Vec3>) -> Self {
+        self.with_channel(joint, ChannelTarget::Translation(track))
+    }
+
+    pub fn with_rotation(self, joint: usize, track: Track<UnitQuaternion<f32>>) -> Self {
+        self.with_channel(joint, ChannelTarget::Rotation(track))
+    }
+
+    pub fn with_scale(self, joint: usize, track: Track<Vec3>) -> Self {
+        self.with_channel(joint, ChannelTarget::Scale(track))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn channels(&self) -> &[Channel] {
+        &self.channels
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    pub fn sample(&self, time: f32, pose: &mut Pose) {
+        for channel in self.channels.iter() {
+            let joint = match pose.joints_mut().get_mut(channel.joint) {
+                Some(joint) => joint,
+                None => continue,
+            };
+            match &channel.target {
+                ChannelTarget::Translation(track) => joint.translation = track.sample(time),
+                ChannelTarget::Rotation(track) => joint.rotation = track.sample(time),
+                ChannelTarget::Scale(track) => joint.scale = track.sample(time),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f32 = 1e-5;
+
+    fn scalar_track(interpolation: Interpolation, values: Vec<f32>) -> Track<f32> {
+        let times = match interpolation {
+            Interpolation::CubicSpline => vec![0.0, 2.0],
+            Interpolation::Step | Interpolation::Linear => vec![0.0, 1.0, 2.0],
+        };
+        Track::try_new(times, values, interpolation).unwrap()
+    }
+
+    fn z_rotation(degrees: f32) -> UnitQuaternion<f32> {
+        UnitQuaternion::from_axis_angle(&Vec3::z_axis(), degrees.to_radians())
+    }
+
+    #[test]
+    fn rejects_malformed_tracks() {
+        assert!(matches!(
+            Track::<f32>::try_new(vec![], vec![], Interpolation::Linear),
+            Err(AnimationError::EmptyTrack)
+        ));
+        assert!(matches!(
+            Track::try_new(vec![0.0, 2.0, 1.0], vec![0.0; 3], Interpolation::Linear),
+            Err(AnimationError::UnsortedKeyframes(2))
+        ));
+        assert!(matches!(
+            Track::try_new(vec![0.0, 1.0], vec![0.0; 2], Interpolation::CubicSpline),
+            Err(AnimationError::KeyframeCountMismatch {
+                times: 2,
+                expected: 6,
+                actual: 2,
+            })
+        ));
+    }
+
+    #[test]
+    fn step_holds_previous_keyframe() {
+        let track = scalar_track(Interpolation::Step, vec![0.0, 10.0, 20.0]);
+        assert_eq!(track.sample(0.0), 0.0);
+        assert_eq!(track.sample(0.99), 0.0);
+        assert_eq!(track.sample(1.0), 10.0);
+        assert_eq!(track.sample(1.5), 10.0);
+        assert_eq!(track.sample(2.0), 20.0);
+    }
+
+    #[test]
+    fn linear_interpolates_between_keyframes() {
+        let track = scalar_track(Interpolation::Linear, vec![0.0, 10.0, 40.0]);
+        assert!((track.sample(0.25) - 2.5).abs() < TOLERANCE);
+        assert!((track.sample(1.0) - 10.0).abs() < TOLERANCE);
+        assert!((track.sample(1.5) - 25.0).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn cubic_spline_uses_in_value_out_layout() {
+        let flat = scalar_track(
+            Interpolation::CubicSpline,
+            vec![99.0, 0.0, 0.0, 0.0, 1.0, 99.0],
+        );
+        assert_eq!(*flat.keyframe_value(0), 0.0);
+        assert_eq!(*flat.keyframe_value(1), 1.0);
+        assert!((flat.sample(0.5) - 0.15625).abs() < TOLERANCE);
+        assert!((flat.sample(1.0) - 0.5).abs() < TOLERANCE);
+
+        let sloped = scalar_track(
+            Interpolation::CubicSpline,
+            vec![-99.0, 0.0, 1.0, 1.0, 2.0, -99.0],
+        );
+        for &time in [0.25, 0.5, 1.0, 1.75].iter() {
+            assert!((sloped.sample(time) - time).abs() < TOLERANCE);
+        }
+
+        let steep = scalar_track(
+            Interpolation::CubicSpline,
+            vec![0.0, 0.0, 3.0, 3.0, 2.0, 0.0],
+        );
+        assert!(steep.sample(0.5) > sloped.sample(0.5));
+    }
+
+    #[test]
+    fn clamps_outside_keyframe_range() {
+        let tracks = [
+            scalar_track(Interpolation::Step, vec![1.0, 2.0, 3.0]),
+            scalar_track(Interpolation::Linear, vec![1.0, 2.0, 3.0]),
+            scalar_track(
+                Interpolation::CubicSpline,
+                vec![7.0, 1.0, 7.0, 7.0, 3.0, 7.0],
+            ),
+        ];
+        for track in tracks.iter() {
+            assert_eq!(track.sample(-1.0), 1.0);
+            assert_eq!(track.sample(track.end_time()), 3.0);
+            assert_eq!(track.sample(10.0), 3.0);
+        }
+        assert_eq!(Track::constant(4.0).sample(-3.0), 4.0);
+        assert_eq!(Track::constant(4.0).sample(3.0), 4.0);
+    }
+
+    #[test]
+    fn rotation_slerp_takes_shortest_path() {
+        let track = Track::try_new(
+            vec![0.0, 1.0],
+            vec![z_rotation(0.0), z_rotation(350.0)],
+            Interpolation::Linear,
+        )
+        .unwrap();
+        let halfway = track.sample(0.5);
+        assert!(halfway.angle_to(&z_rotation(-5.0)) < 1e-3);
+
+        let flipped = UnitQuaternion::new_unchecked(-z_rotation(90.0).into_inner());
+        let track = Track::try_new(
+            vec![0.0, 1.0],
+            vec![z_rotation(0.0), flipped],
+            Interpolation::Linear,
+        )
+        .unwrap();
+        assert!(track.sample(0.5).angle_to(&z_rotation(45.0)) < 1e-3);
+        assert!(track.sample(0.5).angle() < 90.0f32.to_radians());
+    }
+
+    #[test]
+    fn clip_samples_channels_into_pose() {
+        let clip = AnimationClip::new("walk")
+            .with_translation(
+                0,
+                Track::try_new(
+                    vec![0.0, 2.0],
+                    vec![Vec3::zeros(), Vec3::new(4.0, 0.0, 0.0)],
+                    Interpolation::Linear,
+                )
+                .unwrap(),
+            )
+            .with_scale(3, Track::constant(Vec3::repeat(2.0)));
+        assert_eq!(clip.duration(), 2.0);
+
+        let mut pose = Pose::new(vec![Default::default(); 2]);
+        clip.sample(1.0, &mut pose);
+        assert!((pose.joints()[0].translation - Vec3::new(2.0, 0.0, 0.0)).norm() < TOLERANCE);
+        assert_eq!(pose.joints()[1].scale, Vec3::repeat(1.0));
+    }
+}
@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AnimationError {
+    #[error("Animation track has no keyframes")]
+    EmptyTrack,
+    #[error("Keyframe times are not sorted at index {0}")]
+    UnsortedKeyframes(usize),
+    #[error("Track with {times} keyframes expects {expected} values, got {actual}")]
+    KeyframeCountMismatch {
+        times: usize,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("Joint {joint} has invalid parent {parent}")]
+    InvalidJointParent { joint: usize, parent: usize },
+}
@@ -0,0 +1,126 @@
+use glm::{Vec3, Vec4};
+use na::{Quaternion, UnitQuaternion};
+
+const SLERP_EPSILON: f32 = 1.0e-6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    Step,
+    Linear,
+    CubicSpline,
+}
+
+impl Interpolation {
+    pub fn values_per_keyframe(&self) -> usize {
+        match self {
+            Interpolation::CubicSpline => 3,
+            Interpolation::Step | Interpolation::Linear => 1,
+        }
+    }
+}
+
+pub trait Animatable: Copy {
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+
+    fn hermite(
+        start: &Self,
+        start_tangent: &Self,
+        end: &Self,
+        end_tangent: &Self,
+        span: f32,
+        t: f32,
+    ) -> Self;
+}
+
+fn hermite_basis(t: f32) -> [f32; 4] {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    [
+        2.0 * t3 - 3.0 * t2 + 1.0,
+        t3 - 2.0 * t2 + t,
+        -2.0 * t3 + 3.0 * t2,
+        t3 - t2,
+    ]
+}
+
+impl Animatable for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+
+    fn hermite(
+        start: &Self,
+        start_tangent: &Self,
+        end: &Self,
+        end_tangent: &Self,
+        span: f32,
+        t: f32,
+    ) -> Self {
+        let [h00, h10, h01, h11] = hermite_basis(t);
+        h00 * start + h10 * span * start_tangent + h01 * end + h11 * span * end_tangent
+    }
+}
+
+impl Animatable for Vec3 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.lerp(other, t)
+    }
+
+    fn hermite(
+        start: &Self,
+        start_tangent: &Self,
+        end: &Self,
+        end_tangent: &Self,
+        span: f32,
+        t: f32,
+    ) -> Self {
+        let [h00, h10, h01, h11] = hermite_basis(t);
+        start * h00 + start_tangent * (h10 * span) + end * h01 + end_tangent * (h11 * span)
+    }
+}
+
+impl Animatable for Vec4 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.lerp(other, t)
+    }
+
+    fn hermite(
+        start: &Self,
+        start_tangent: &Self,
+        end: &Self,
+        end_tangent: &Self,
+        span: f32,
+        t: f32,
+    ) -> Self {
+        let [h00, h10, h01, h11] = hermite_basis(t);
+        start * h00 + start_tangent * (h10 * span) + end * h01 + end_tangent * (h11 * span)
+    }
+}
+
+impl Animatable for UnitQuaternion<f32> {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let other = if self.coords.dot(&other.coords) < 0.0 {
+            UnitQuaternion::new_unchecked(-other.into_inner())
+        } else {
+            *other
+        };
+        self.try_slerp(&other, t, SLERP_EPSILON)
+            .unwrap_or_else(|| self.nlerp(&other, t))
+    }
+
+    fn hermite(
+        start: &Self,
+        start_tangent: &Self,
+        end: &Self,
+        end_tangent: &Self,
+        span: f32,
+        t: f32,
+    ) -> Self {
+        let [h00, h10, h01, h11] = hermite_basis(t);
+        let coords = start.coords * h00
+            + start_tangent.coords * (h10 * span)
+            + end.coords * h01
+            + end_tangent.coords * (h11 * span);
+        UnitQuaternion::new_normalize(Quaternion::from(coords))
+    }
+}
@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+pub mod binding;
+pub mod blender;
+pub mod clip;
+pub mod easing;
+pub mod error;
+pub mod interpolation;
+pub mod morph;
+pub mod player;
+pub mod pose;
+pub mod skeleton;
+pub mod skin;
+pub mod timeline;
+pub mod tween;
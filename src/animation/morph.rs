@@ -0,0 +1,40 @@
+use super::clip::Track;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MorphAnimation {
+    name: String,
+    tracks: Vec<Track<f32>>,
+    duration: f32,
+}
+
+impl MorphAnimation {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            tracks: vec![],
+            duration: 0.0,
+        }
+    }
+
+    pub fn with_track(mut self, track: Track<f32>) -> Self {
+        self.duration = self.duration.max(track.end_time());
+        self.tracks.push(track);
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn tracks(&self) -> &[Track<f32>] {
+        &self.tracks
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    pub fn sample(&self, time: f32) -> Vec<f32> {
+        self.tracks.iter().map(|track| track.sample(time)).collect()
+    }
+}
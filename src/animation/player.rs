@@ -0,0 +1,85 @@
+use super::binding::AnimationTargets;
+use super::timeline::{PlaybackMode, Timeline};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationPlayer {
+    timeline: Timeline,
+    mode: PlaybackMode,
+    time: f32,
+    pub speed: f32,
+    playing: bool,
+}
+
+impl AnimationPlayer {
+    pub fn new(timeline: Timeline) -> Self {
+        Self {
+            timeline,
+            mode: PlaybackMode::Once,
+            time: 0.0,
+            speed: 1.0,
+            playing: true,
+        }
+    }
+
+    pub fn with_mode(mut self, mode: PlaybackMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    pub fn timeline(&self) -> &Timeline {
+        &self.timeline
+    }
+
+    pub fn mode(&self) -> PlaybackMode {
+        self.mode
+    }
+
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    pub fn local_time(&self) -> f32 {
+        self.mode.wrap(self.time, self.timeline.duration(), None)
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.mode == PlaybackMode::Once && self.time >= self.timeline.duration()
+    }
+
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    pub fn seek(&mut self, time: f32) {
+        self.time = time.max(0.0);
+    }
+
+    pub fn update(&mut self, d_time: f64) {
+        if !self.playing || self.is_finished() {
+            return;
+        }
+        self.time += d_time as f32 / 1000.0 * self.speed;
+
+        let duration = self.timeline.duration();
+        if self.mode != PlaybackMode::Once && duration.is_finite() && duration > 0.0 {
+            self.time %= duration * 2.0;
+        }
+    }
+
+    pub fn apply(&self, targets: &mut AnimationTargets) {
+        self.timeline.apply(self.local_time(), targets);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::scene::transform::Transform;
+
+use super::interpolation::Animatable;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pose {
+    joints: Vec<Transform>,
+}
+
+pub fn blend_transforms(from: &Transform, to: &Transform, weight: f32) -> Transform {
+    Transform {
+        translation: from.translation.interpolate(&to.translation, weight),
+        rotation: from.rotation.interpolate(&to.rotation, weight),
+        scale: from.scale.interpolate(&to.scale, weight),
+    }
+}
+
+impl Pose {
+    pub fn new(joints: Vec<Transform>) -> Self {
+        Self { joints }
+    }
+
+    pub fn len(&self) -> usize {
+        self.joints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.joints.is_empty()
+    }
+
+    pub fn joints(&self) -> &[Transform] {
+        &self.joints
+    }
+
+    pub fn joints_mut(&mut self) -> &mut [Transform] {
+        &mut self.joints
+    }
+
+    pub fn blend(&self, other: &Pose, weight: f32) -> Pose {
+        let weight = weight.clamp(0.0, 1.0);
+        let joints = self
+            .joints
+            .iter()
+            .zip(other.joints.iter())
+            .map(|(from, to)| blend_transforms(from, to, weight))
+            .collect();
+        Pose { joints }
+    }
+}
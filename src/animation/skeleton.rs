@@ -0,0 +1,98 @@
+use glm::Mat4x4;
+
+use crate::scene::transform::Transform;
+
+use super::error::AnimationError;
+use super::pose::Pose;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Joint {
+    pub name: String,
+    pub parent: Option<usize>,
+    pub rest: Transform,
+    pub inverse_bind: Mat4x4,
+}
+
+impl Joint {
+    pub fn new(name: &str, parent: Option<usize>, rest: Transform) -> Self {
+        Self {
+            name: name.into(),
+            parent,
+            rest,
+            inverse_bind: Mat4x4::identity(),
+        }
+    }
+
+    pub fn with_inverse_bind(mut self, inverse_bind: Mat4x4) -> Self {
+        self.inverse_bind = inverse_bind;
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Skeleton {
+    joints: Vec<Joint>,
+}
+
+impl Skeleton {
+    pub fn try_new(joints: Vec<Joint>) -> Result<Self, AnimationError> {
+        for (index, joint) in joints.iter().enumerate() {
+            if let Some(parent) = joint.parent.filter(|&parent| parent >= index) {
+                return Err(AnimationError::InvalidJointParent {
+                    joint: index,
+                    parent,
+                });
+            }
+        }
+        Ok(Self { joints })
+    }
+
+    pub fn with_rest_bind_pose(mut self) -> Self {
+        let world = self.world_matrices(&self.rest_pose());
+        for (joint, world) in self.joints.iter_mut().zip(world.iter()) {
+            joint.inverse_bind = world.try_inverse().unwrap_or_else(Mat4x4::identity);
+        }
+        self
+    }
+
+    pub fn joints(&self) -> &[Joint] {
+        &self.joints
+    }
+
+    pub fn len(&self) -> usize {
+        self.joints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.joints.is_empty()
+    }
+
+    pub fn joint_index(&self, name: &str) -> Option<usize> {
+        self.joints.iter().position(|joint| joint.name == name)
+    }
+
+    pub fn rest_pose(&self) -> Pose {
+        Pose::new(self.joints.iter().map(|joint| joint.rest).collect())
+    }
+
+    pub fn world_matrices(&self, pose: &Pose) -> Vec<Mat4x4> {
+        let mut world: Vec<Mat4x4> = Vec::with_capacity(self.joints.len());
+        for (index, joint) in self.joints.iter().enumerate() {
+            let local = pose.joints().get(index).unwrap_or(&joint.rest).matrix();
+            let matrix = match joint.parent {
+                Some(parent) => world[parent] * local,
+                None => local,
+            };
+            world.push(matrix);
+        }
+        world
+    }
+
+    pub fn skinning_matrices(&self, pose: &Pose) -> Vec<Mat4x4> {
+        self.world_matrices(pose)
+            .iter()
+            .zip(self.joints.iter())
+            .map(|(world, joint)| world * joint.inverse_bind)
+            .collect()
+    }
+}
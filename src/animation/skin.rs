@@ -0,0 +1,41 @@
+use crate::gl::mesh::{JointIndices, JointWeights};
+
+pub const MAX_JOINT_INFLUENCES: usize = 4;
+
+pub struct SkinWeights {
+    pub joints: JointIndices,
+    pub weights: JointWeights,
+}
+
+impl SkinWeights {
+    pub fn from_influences(influences: &[Vec<(usize, f32)>]) -> Self {
+        let mut joints = JointIndices::zeros(influences.len());
+        let mut weights = JointWeights::zeros(influences.len());
+
+        for (vertex, vertex_influences) in influences.iter().enumerate() {
+            let mut strongest: Vec<(usize, f32)> = vertex_influences
+                .iter()
+                .copied()
+                .filter(|&(_, weight)| weight > 0.0)
+                .collect();
+            strongest.sort_by(|a, b| b.1.total_cmp(&a.1));
+            strongest.truncate(MAX_JOINT_INFLUENCES);
+
+            let total: f32 = strongest.iter().map(|&(_, weight)| weight).sum();
+            if total <= 0.0 {
+                weights[(0, vertex)] = 1.0;
+                continue;
+            }
+            for (slot, &(joint, weight)) in strongest.iter().enumerate() {
+                joints[(slot, vertex)] = joint as f32;
+                weights[(slot, vertex)] = weight / total;
+            }
+        }
+
+        Self { joints, weights }
+    }
+
+    pub fn vertices_count(&self) -> usize {
+        self.joints.ncols()
+    }
+}
@@ -0,0 +1,139 @@
+use super::binding::{AnimationTargets, Binding};
+use super::tween::AnimatedCurve;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaybackMode {
+    Once,
+    Loop,
+    PingPong,
+}
+
+impl PlaybackMode {
+    pub fn wrap(&self, time: f32, duration: f32, count: Option<u32>) -> f32 {
+        if duration <= 0.0 || !duration.is_finite() {
+            return time.max(0.0).min(duration.max(0.0));
+        }
+
+        let time = time.max(0.0);
+        let passes = match self {
+            PlaybackMode::Once => Some(1),
+            PlaybackMode::Loop | PlaybackMode::PingPong => count.map(|count| count.max(1)),
+        };
+        let (pass, local) = match passes {
+            Some(passes) if time >= passes as f32 * duration => (passes - 1, duration),
+            _ => ((time / duration) as u32, time % duration),
+        };
+
+        match self {
+            PlaybackMode::PingPong if pass % 2 == 1 => duration - local,
+            _ => local,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Timeline {
+    Tween {
+        binding: Binding,
+        curve: AnimatedCurve,
+    },
+    Delay(f32),
+    Sequence(Vec<Timeline>),
+    Parallel(Vec<Timeline>),
+    Repeat {
+        timeline: Box<Timeline>,
+        mode: PlaybackMode,
+        count: Option<u32>,
+    },
+}
+
+impl Timeline {
+    pub fn tween(binding: Binding, curve: impl Into<AnimatedCurve>) -> Self {
+        Timeline::Tween {
+            binding,
+            curve: curve.into(),
+        }
+    }
+
+    pub fn delay(duration: f32) -> Self {
+        Timeline::Delay(duration)
+    }
+
+    pub fn sequence(timelines: Vec<Timeline>) -> Self {
+        Timeline::Sequence(timelines)
+    }
+
+    pub fn parallel(timelines: Vec<Timeline>) -> Self {
+        Timeline::Parallel(timelines)
+    }
+
+    pub fn repeat(self, mode: PlaybackMode, count: Option<u32>) -> Self {
+        Timeline::Repeat {
+            timeline: Box::new(self),
+            mode,
+            count,
+        }
+    }
+
+    pub fn looped(self) -> Self {
+        self.repeat(PlaybackMode::Loop, None)
+    }
+
+    pub fn ping_pong(self) -> Self {
+        self.repeat(PlaybackMode::PingPong, None)
+    }
+
+    pub fn duration(&self) -> f32 {
+        match self {
+            Timeline::Tween { curve, .. } => curve.duration(),
+            Timeline::Delay(duration) => *duration,
+            Timeline::Sequence(timelines) => timelines.iter().map(Timeline::duration).sum(),
+            Timeline::Parallel(timelines) => {
+                timelines.iter().map(Timeline::duration).fold(0.0, f32::max)
+            }
+            Timeline::Repeat {
+                timeline,
+                mode,
+                count,
+            } => match (mode, count) {
+                (PlaybackMode::Once, _) => timeline.duration(),
+                (_, Some(count)) => timeline.duration() * (*count).max(1) as f32,
+                (_, None) => f32::INFINITY,
+            },
+        }
+    }
+
+    pub fn apply(&self, time: f32, targets: &mut AnimationTargets) {
+        match self {
+            Timeline::Tween { binding, curve } => {
+                let time = time.clamp(0.0, curve.duration().max(0.0));
+                targets.apply(binding, curve.sample(time));
+            }
+            Timeline::Delay(_) => {}
+            Timeline::Sequence(timelines) => {
+                let mut start = 0.0;
+                for timeline in timelines.iter() {
+                    if time < start {
+                        break;
+                    }
+                    let duration = timeline.duration();
+                    timeline.apply((time - start).min(duration), targets);
+                    start += duration;
+                }
+            }
+            Timeline::Parallel(timelines) => {
+                for timeline in timelines.iter() {
+                    timeline.apply(time.min(timeline.duration()), targets);
+                }
+            }
+            Timeline::Repeat {
+                timeline,
+                mode,
+                count,
+            } => {
+                let time = mode.wrap(time, timeline.duration(), *count);
+                timeline.apply(time, targets);
+            }
+        }
+    }
+}
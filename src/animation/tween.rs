@@ -0,0 +1,152 @@
+use std::f32::consts::FRAC_PI_2;
+
+use glm::{Vec3, Vec4};
+use na::UnitQuaternion;
+
+use super::clip::Track;
+use super::easing::Easing;
+use super::interpolation::{Animatable, Interpolation};
+
+const SPIN_KEYFRAMES: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimatedValue {
+    Float(f32),
+    Vec3(Vec3),
+    Vec4(Vec4),
+    Rotation(UnitQuaternion<f32>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Curve<T: Animatable> {
+    Tween {
+        from: T,
+        to: T,
+        duration: f32,
+        easing: Easing,
+    },
+    Keyframes(Track<T>),
+}
+
+impl<T: Animatable> Curve<T> {
+    pub fn tween(from: T, to: T, duration: f32) -> Self {
+        Curve::Tween {
+            from,
+            to,
+            duration,
+            easing: Easing::Linear,
+        }
+    }
+
+    pub fn with_easing(self, easing: Easing) -> Self {
+        match self {
+            Curve::Tween {
+                from, to, duration, ..
+            } => Curve::Tween {
+                from,
+                to,
+                duration,
+                easing,
+            },
+            keyframes => keyframes,
+        }
+    }
+
+    pub fn duration(&self) -> f32 {
+        match self {
+            Curve::Tween { duration, .. } => *duration,
+            Curve::Keyframes(track) => track.end_time(),
+        }
+    }
+
+    pub fn sample(&self, time: f32) -> T {
+        match self {
+            Curve::Tween {
+                from,
+                to,
+                duration,
+                easing,
+            } => {
+                let t = if *duration > 0.0 {
+                    time / duration
+                } else {
+                    1.0
+                };
+                from.interpolate(to, easing.apply(t))
+            }
+            Curve::Keyframes(track) => track.sample(time),
+        }
+    }
+}
+
+impl<T: Animatable> From<Track<T>> for Curve<T> {
+    fn from(track: Track<T>) -> Self {
+        Curve::Keyframes(track)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnimatedCurve {
+    Float(Curve<f32>),
+    Vec3(Curve<Vec3>),
+    Vec4(Curve<Vec4>),
+    Rotation(Curve<UnitQuaternion<f32>>),
+}
+
+impl AnimatedCurve {
+    pub fn duration(&self) -> f32 {
+        match self {
+            AnimatedCurve::Float(curve) => curve.duration(),
+            AnimatedCurve::Vec3(curve) => curve.duration(),
+            AnimatedCurve::Vec4(curve) => curve.duration(),
+            AnimatedCurve::Rotation(curve) => curve.duration(),
+        }
+    }
+
+    pub fn sample(&self, time: f32) -> AnimatedValue {
+        match self {
+            AnimatedCurve::Float(curve) => AnimatedValue::Float(curve.sample(time)),
+            AnimatedCurve::Vec3(curve) => AnimatedValue::Vec3(curve.sample(time)),
+            AnimatedCurve::Vec4(curve) => AnimatedValue::Vec4(curve.sample(time)),
+            AnimatedCurve::Rotation(curve) => AnimatedValue::Rotation(curve.sample(time)),
+        }
+    }
+}
+
+impl From<Curve<f32>> for AnimatedCurve {
+    fn from(curve: Curve<f32>) -> Self {
+        AnimatedCurve::Float(curve)
+    }
+}
+
+impl From<Curve<Vec3>> for AnimatedCurve {
+    fn from(curve: Curve<Vec3>) -> Self {
+        AnimatedCurve::Vec3(curve)
+    }
+}
+
+impl From<Curve<Vec4>> for AnimatedCurve {
+    fn from(curve: Curve<Vec4>) -> Self {
+        AnimatedCurve::Vec4(curve)
+    }
+}
+
+impl From<Curve<UnitQuaternion<f32>>> for AnimatedCurve {
+    fn from(curve: Curve<UnitQuaternion<f32>>) -> Self {
+        AnimatedCurve::Rotation(curve)
+    }
+}
+
+pub fn spin_track(axis: &Vec3, duration: f32) -> Track<UnitQuaternion<f32>> {
+    let axis = na::Unit::new_normalize(*axis);
+    let keys = 0..=SPIN_KEYFRAMES;
+    let times = keys
+        .clone()
+        .map(|key| key as f32 * duration / SPIN_KEYFRAMES as f32)
+        .collect();
+    let values = keys
+        .map(|key| UnitQuaternion::from_axis_angle(&axis, key as f32 * FRAC_PI_2))
+        .collect();
+    Track::try_new(times, values, Interpolation::Linear)
+        .unwrap_or_else(|_| Track::constant(UnitQuaternion::identity()))
+}
@@ -0,0 +1,17 @@
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+#[derive(Error, Debug)]
+pub enum CaptureError {
+    #[error("Can't read pixels from framebuffer. Value = {:?}", .0)]
+    ReadPixelsError(JsValue),
+    #[error("Can't download file {0}. Value = {:?}", .1)]
+    DownloadError(String, JsValue),
+    #[error("Pixel buffer of {width}x{height} image has {actual} bytes, expected {expected}")]
+    PixelBufferSizeMismatch {
+        width: u32,
+        height: u32,
+        expected: usize,
+        actual: usize,
+    },
+}
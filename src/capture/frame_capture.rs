@@ -0,0 +1,101 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use crate::gl;
+use crate::gl::core::instance::GL;
+use crate::utils::web;
+
+use super::error::CaptureError;
+use super::pixels::{flip_rows, unpremultiply_alpha};
+use super::png::encode_rgba;
+
+const PNG_MIME_TYPE: &str = "image/png";
+
+#[derive(Debug)]
+struct CaptureRequest {
+    name: String,
+    frames: usize,
+    time_step: Option<f64>,
+    captured: usize,
+}
+
+#[derive(Debug)]
+pub struct CaptureFrame {
+    pub file_name: String,
+    pub time_step: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FrameCapture {
+    requests: Rc<RefCell<VecDeque<CaptureRequest>>>,
+}
+
+impl FrameCapture {
+    pub fn request_frame(&self, name: &str) {
+        self.requests.borrow_mut().push_back(CaptureRequest {
+            name: name.into(),
+            frames: 1,
+            time_step: None,
+            captured: 0,
+        });
+    }
+
+    pub fn request_sequence(&self, name: &str, frames: usize, time_step: f64) {
+        self.requests.borrow_mut().push_back(CaptureRequest {
+            name: name.into(),
+            frames,
+            time_step: Some(time_step),
+            captured: 0,
+        });
+    }
+
+    pub fn next_frame(&self) -> Option<CaptureFrame> {
+        let mut requests = self.requests.borrow_mut();
+        while requests.front().is_some_and(|r| r.captured >= r.frames) {
+            requests.pop_front();
+        }
+
+        let request = requests.front_mut()?;
+        let file_name = match request.time_step {
+            Some(_) => format!("{}_{:04}.png", request.name, request.captured),
+            None => format!("{}.png", request.name),
+        };
+        request.captured += 1;
+
+        Some(CaptureFrame {
+            file_name,
+            time_step: request.time_step,
+        })
+    }
+
+    pub fn capture(&self, gl: &GL, frame: &CaptureFrame) -> Result<(), CaptureError> {
+        let (width, height, pixels) = read_default_framebuffer(gl)?;
+        let png = encode_rgba(width, height, &pixels)?;
+        web::download_bytes(&frame.file_name, PNG_MIME_TYPE, &png)
+            .map_err(|e| CaptureError::DownloadError(frame.file_name.clone(), e))
+    }
+}
+
+pub fn read_default_framebuffer(gl: &GL) -> Result<(u32, u32, Vec<u8>), CaptureError> {
+    let width = gl.drawing_buffer_width();
+    let height = gl.drawing_buffer_height();
+    let mut pixels = vec![0u8; width as usize * height as usize * 4];
+
+    gl.bind_framebuffer(gl::FRAMEBUFFER, None);
+    gl.read_pixels_with_opt_u8_array(
+        0,
+        0,
+        width,
+        height,
+        gl::RGBA,
+        gl::UNSIGNED_BYTE,
+        Some(pixels.as_mut_slice()),
+    )
+    .map_err(CaptureError::ReadPixelsError)?;
+
+    flip_rows(&mut pixels, width as u32, height as u32);
+    unpremultiply_alpha(&mut pixels);
+
+    Ok((width as u32, height as u32, pixels))
+}
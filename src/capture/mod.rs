@@ -0,0 +1,4 @@
+pub mod error;
+pub mod frame_capture;
+pub mod pixels;
+pub mod png;
@@ -0,0 +1,69 @@
+const BYTES_PER_PIXEL: usize = 4;
+
+pub fn flip_rows(pixels: &mut [u8], width: u32, height: u32) {
+    let stride = width as usize * BYTES_PER_PIXEL;
+    let height = height as usize;
+    for row in 0..height / 2 {
+        let (top, bottom) = pixels.split_at_mut((height - row - 1) * stride);
+        top[row * stride..(row + 1) * stride].swap_with_slice(&mut bottom[..stride]);
+    }
+}
+
+pub fn unpremultiply_alpha(pixels: &mut [u8]) {
+    for pixel in pixels.chunks_exact_mut(BYTES_PER_PIXEL) {
+        let alpha = pixel[3] as u32;
+        if alpha == 0 || alpha == 255 {
+            continue;
+        }
+        for channel in &mut pixel[..3] {
+            *channel = ((*channel as u32 * 255 + alpha / 2) / alpha).min(255) as u8;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(width: u32, height: u32, order: impl Iterator<Item = u32>) -> Vec<u8> {
+        order
+            .take(height as usize)
+            .flat_map(|row| vec![row as u8; width as usize * BYTES_PER_PIXEL])
+            .collect()
+    }
+
+    #[test]
+    fn flips_even_number_of_rows() {
+        let mut pixels = rows(3, 4, 0..);
+        flip_rows(&mut pixels, 3, 4);
+        assert_eq!(pixels, rows(3, 4, (0..4).rev()));
+    }
+
+    #[test]
+    fn flips_odd_number_of_rows() {
+        let mut pixels = rows(2, 5, 0..);
+        flip_rows(&mut pixels, 2, 5);
+        assert_eq!(pixels, rows(2, 5, (0..5).rev()));
+
+        let mut single = rows(4, 1, 0..);
+        flip_rows(&mut single, 4, 1);
+        assert_eq!(single, rows(4, 1, 0..));
+    }
+
+    #[test]
+    fn unpremultiplies_color_channels() {
+        let mut pixels = vec![64, 32, 0, 128, 10, 20, 30, 255, 0, 0, 0, 0, 1, 1, 1, 1];
+        unpremultiply_alpha(&mut pixels);
+        assert_eq!(
+            pixels,
+            [128, 64, 0, 128, 10, 20, 30, 255, 0, 0, 0, 0, 255, 255, 255, 1]
+        );
+    }
+
+    #[test]
+    fn keeps_transparent_pixels_untouched() {
+        let mut pixels = vec![12, 34, 56, 0];
+        unpremultiply_alpha(&mut pixels);
+        assert_eq!(pixels, [12, 34, 56, 0]);
+    }
+}
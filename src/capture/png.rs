@@ -0,0 +1,218 @@
+use super::error::CaptureError;
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+const COLOR_TYPE_RGBA: u8 = 6;
+const BIT_DEPTH: u8 = 8;
+const BYTES_PER_PIXEL: usize = 4;
+
+const FILTER_NONE: u8 = 0;
+
+const DEFLATE_MAX_STORED_BLOCK: usize = 0xFFFF;
+const ADLER_MODULO: u32 = 65521;
+
+pub fn encode_rgba(width: u32, height: u32, pixels: &[u8]) -> Result<Vec<u8>, CaptureError> {
+    let expected = width as usize * height as usize * BYTES_PER_PIXEL;
+    if pixels.len() != expected {
+        return Err(CaptureError::PixelBufferSizeMismatch {
+            width,
+            height,
+            expected,
+            actual: pixels.len(),
+        });
+    }
+
+    let mut ihdr = Vec::with_capacity(13);
+    ihdr.extend_from_slice(&width.to_be_bytes());
+    ihdr.extend_from_slice(&height.to_be_bytes());
+    ihdr.extend_from_slice(&[BIT_DEPTH, COLOR_TYPE_RGBA, 0, 0, 0]);
+
+    let filtered = filter_scanlines(width as usize, pixels);
+    let idat = zlib_stored(&filtered);
+
+    let mut png = Vec::with_capacity(PNG_SIGNATURE.len() + idat.len() + 64);
+    png.extend_from_slice(&PNG_SIGNATURE);
+    write_chunk(&mut png, b"IHDR", &ihdr);
+    write_chunk(&mut png, b"IDAT", &idat);
+    write_chunk(&mut png, b"IEND", &[]);
+    Ok(png)
+}
+
+fn filter_scanlines(width: usize, pixels: &[u8]) -> Vec<u8> {
+    let stride = width * BYTES_PER_PIXEL;
+    if stride == 0 {
+        return vec![];
+    }
+
+    let mut out = Vec::with_capacity(pixels.len() + pixels.len() / stride);
+    for row in pixels.chunks_exact(stride) {
+        out.push(FILTER_NONE);
+        out.extend_from_slice(row);
+    }
+    out
+}
+
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let blocks = data.len() / DEFLATE_MAX_STORED_BLOCK + 1;
+    let mut out = Vec::with_capacity(data.len() + blocks * 5 + 6);
+    out.extend_from_slice(&[0x78, 0x01]);
+
+    let mut chunks = data.chunks(DEFLATE_MAX_STORED_BLOCK).peekable();
+    if chunks.peek().is_none() {
+        out.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+    }
+    while let Some(chunk) = chunks.next() {
+        let is_last = chunks.peek().is_none();
+        let len = chunk.len() as u16;
+        out.push(is_last as u8);
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(chunk);
+    }
+
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let crc_start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[crc_start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+pub fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= ADLER_MODULO;
+        b %= ADLER_MODULO;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_be_bytes([
+            bytes[offset],
+            bytes[offset + 1],
+            bytes[offset + 2],
+            bytes[offset + 3],
+        ])
+    }
+
+    fn chunks(png: &[u8]) -> Vec<(&[u8], &[u8])> {
+        let mut chunks = vec![];
+        let mut offset = PNG_SIGNATURE.len();
+        while offset < png.len() {
+            let length = read_u32(png, offset) as usize;
+            let kind = &png[offset + 4..offset + 8];
+            let data = &png[offset + 8..offset + 8 + length];
+            assert_eq!(
+                read_u32(png, offset + 8 + length),
+                crc32(&png[offset + 4..offset + 8 + length])
+            );
+            chunks.push((kind, data));
+            offset += length + 12;
+        }
+        assert_eq!(offset, png.len());
+        chunks
+    }
+
+    #[test]
+    fn crc32_matches_known_vectors() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+    }
+
+    #[test]
+    fn adler32_matches_known_vectors() {
+        assert_eq!(adler32(b""), 1);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+        assert_eq!(adler32(&[0xFF; 6000]), 0xA497_59EA);
+    }
+
+    #[test]
+    fn encodes_signature_and_chunk_layout() {
+        let pixels: Vec<u8> = (0..3 * 2 * 4).map(|value| value as u8).collect();
+        let png = encode_rgba(3, 2, &pixels).unwrap();
+
+        assert_eq!(png[..8], PNG_SIGNATURE);
+        let chunks = chunks(&png);
+        let kinds: Vec<&[u8]> = chunks.iter().map(|(kind, _)| *kind).collect();
+        assert_eq!(kinds, [&b"IHDR"[..], b"IDAT", b"IEND"]);
+
+        let ihdr = chunks[0].1;
+        assert_eq!(ihdr, [0, 0, 0, 3, 0, 0, 0, 2, 8, 6, 0, 0, 0]);
+        assert!(chunks[2].1.is_empty());
+
+        let idat = chunks[1].1;
+        assert_eq!(idat[..2], [0x78, 0x01]);
+        let stored_len = u16::from_le_bytes([idat[3], idat[4]]);
+        assert_eq!(idat[2], 1);
+        assert_eq!(stored_len, 2 * (1 + 3 * 4));
+        assert_eq!(!stored_len, u16::from_le_bytes([idat[5], idat[6]]));
+
+        let scanlines = &idat[7..idat.len() - 4];
+        assert_eq!(scanlines[0], FILTER_NONE);
+        assert_eq!(scanlines[1..13], pixels[..12]);
+        assert_eq!(scanlines[13], FILTER_NONE);
+        assert_eq!(scanlines[14..], pixels[12..]);
+        assert_eq!(idat[idat.len() - 4..], adler32(scanlines).to_be_bytes()[..]);
+    }
+
+    #[test]
+    fn splits_large_images_into_stored_blocks() {
+        let pixels = vec![7u8; 200 * 100 * 4];
+        let png = encode_rgba(200, 100, &pixels).unwrap();
+        let chunks = chunks(&png);
+        let idat = chunks[1].1;
+
+        let mut offset = 2;
+        let mut total = 0;
+        loop {
+            let is_last = idat[offset] == 1;
+            let len = u16::from_le_bytes([idat[offset + 1], idat[offset + 2]]) as usize;
+            total += len;
+            offset += 5 + len;
+            if is_last {
+                break;
+            }
+        }
+        assert_eq!(total, 100 * (1 + 200 * 4));
+        assert_eq!(offset + 4, idat.len());
+    }
+
+    #[test]
+    fn rejects_mismatched_pixel_buffer() {
+        assert!(matches!(
+            encode_rgba(2, 2, &[0; 15]),
+            Err(CaptureError::PixelBufferSizeMismatch {
+                expected: 16,
+                actual: 15,
+                ..
+            })
+        ));
+    }
+}
@@ -36,7 +36,7 @@ impl<'a> BindedObjCtx<'a> {
 
     pub fn upload_static_elem_buffer(&self, array: &impl AsSlice<i32>) {
         unsafe {
-self.gl.buffer_data_with_array_buffer_view(
+            self.gl.buffer_data_with_array_buffer_view(
                 gl::ELEMENT_ARRAY_BUFFER,
                 &js_sys::Int32Array::view(array.as_slice()),
                 gl::STATIC_DRAW,
@@ -81,7 +81,7 @@ self.gl.buffer_data_with_array_buffer_view(
         }
     }
 
-    pub fn draw_triangles(self, _: &ShaderUseCtx, count: usize) {
+    pub fn draw_triangles(&self, _: &ShaderUseCtx, count: usize) {
         self.gl.draw_elements_instanced_with_i32(
             gl::TRIANGLES,
             count as i32 * 3,
@@ -6,4 +6,6 @@ pub enum GLObjectError {
     CreateVAOError,
     #[error("Can't create gl buffer")]
     CreateBufferError,
+    #[error("Can't create transform feedback")]
+    CreateTransformFeedbackError,
 }
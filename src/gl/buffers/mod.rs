@@ -1,3 +1,5 @@
 pub mod binded_obj_ctx;
 pub mod error;
 pub mod object;
+pub mod transform_feedback;
+pub mod uniform_buffer;
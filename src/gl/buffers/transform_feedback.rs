@@ -0,0 +1,66 @@
+use std::rc::Rc;
+
+use web_sys::{WebGlBuffer, WebGlTransformFeedback};
+
+use crate::gl;
+use crate::gl::core::instance::GL;
+
+use super::error::GLObjectError::{self, CreateTransformFeedbackError};
+
+pub struct TransformFeedback {
+    feedback: WebGlTransformFeedback,
+    gl: Rc<GL>,
+}
+
+impl TransformFeedback {
+    pub fn try_new(gl: &Rc<GL>) -> Result<Self, GLObjectError> {
+        let feedback = gl
+            .create_transform_feedback()
+            .ok_or(CreateTransformFeedbackError)?;
+        Ok(Self {
+            feedback,
+            gl: Rc::clone(gl),
+        })
+    }
+
+    #[must_use]
+    pub fn begin(&self, target: &WebGlBuffer, primitive_mode: u32) -> TransformFeedbackCtx<'_> {
+        TransformFeedbackCtx::new(&self.gl, &self.feedback, target, primitive_mode)
+    }
+}
+
+impl Drop for TransformFeedback {
+    fn drop(&mut self) {
+        self.gl.delete_transform_feedback(Some(&self.feedback));
+    }
+}
+
+pub struct TransformFeedbackCtx<'a> {
+    gl: &'a GL,
+}
+
+impl<'a> TransformFeedbackCtx<'a> {
+    fn new(
+        gl: &'a GL,
+        feedback: &WebGlTransformFeedback,
+        target: &WebGlBuffer,
+        primitive_mode: u32,
+    ) -> Self {
+        gl.bind_transform_feedback(gl::TRANSFORM_FEEDBACK, Some(feedback));
+        gl.bind_buffer_base(gl::TRANSFORM_FEEDBACK_BUFFER, 0, Some(target));
+        gl.enable(gl::RASTERIZER_DISCARD);
+        gl.begin_transform_feedback(primitive_mode);
+        Self { gl }
+    }
+}
+
+impl Drop for TransformFeedbackCtx<'_> {
+    fn drop(&mut self) {
+        self.gl.end_transform_feedback();
+        self.gl.disable(gl::RASTERIZER_DISCARD);
+        self.gl
+            .bind_buffer_base(gl::TRANSFORM_FEEDBACK_BUFFER, 0, None);
+        self.gl
+            .bind_transform_feedback(gl::TRANSFORM_FEEDBACK, None);
+    }
+}
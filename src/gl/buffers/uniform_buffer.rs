@@ -0,0 +1,75 @@
+use std::rc::Rc;
+
+use web_sys::WebGlBuffer;
+
+use crate::gl;
+use crate::gl::core::instance::GL;
+use crate::gl::shader::program::ShaderProgram;
+
+use super::error::GLObjectError::{self, CreateBufferError};
+
+pub struct UniformBuffer {
+    buffer: WebGlBuffer,
+    size: usize,
+    binding: u32,
+    gl: Rc<GL>,
+}
+
+impl UniformBuffer {
+    pub fn try_new(gl: &Rc<GL>, size: usize, binding: u32) -> Result<Self, GLObjectError> {
+        let buffer = gl.create_buffer().ok_or(CreateBufferError)?;
+        gl.bind_buffer(gl::UNIFORM_BUFFER, Some(&buffer));
+        gl.buffer_data_with_i32(gl::UNIFORM_BUFFER, size as i32, gl::DYNAMIC_DRAW);
+        gl.bind_buffer(gl::UNIFORM_BUFFER, None);
+        Ok(Self {
+            buffer,
+            size,
+            binding,
+            gl: Rc::clone(gl),
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn binding(&self) -> u32 {
+        self.binding
+    }
+
+    pub fn attach(&self, program: &ShaderProgram, block_name: &str) -> bool {
+        let gl_program = program.as_gl_program();
+        let index = self.gl.get_uniform_block_index(gl_program, block_name);
+        if index == gl::INVALID_INDEX {
+            log::warn!("Invalid uniform block name: {}", block_name);
+            return false;
+        }
+        self.gl
+            .uniform_block_binding(gl_program, index, self.binding);
+        true
+    }
+
+    pub fn upload(&self, data: &[f32]) {
+        let len = data.len().min(self.size / std::mem::size_of::<f32>());
+        self.gl.bind_buffer(gl::UNIFORM_BUFFER, Some(&self.buffer));
+        unsafe {
+            self.gl.buffer_sub_data_with_i32_and_array_buffer_view(
+                gl::UNIFORM_BUFFER,
+                0,
+                &js_sys::Float32Array::view(&data[..len]),
+            );
+        }
+        self.gl.bind_buffer(gl::UNIFORM_BUFFER, None);
+    }
+
+    pub fn bind(&self) {
+        self.gl
+            .bind_buffer_base(gl::UNIFORM_BUFFER, self.binding, Some(&self.buffer));
+    }
+}
+
+impl Drop for UniformBuffer {
+    fn drop(&mut self) {
+        self.gl.delete_buffer(Some(&self.buffer));
+    }
+}
@@ -0,0 +1,16 @@
+use glm::Mat4x4;
+
+use super::input::InputEvent;
+use super::Camera;
+
+pub trait CameraController {
+    fn handle_event(&mut self, event: &InputEvent);
+
+    fn update(&mut self, _d_time: f64) {}
+
+    fn view(&self) -> Mat4x4;
+
+    fn apply(&self, camera: &mut Camera) {
+        camera.view = self.view();
+    }
+}
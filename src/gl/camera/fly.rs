@@ -0,0 +1,214 @@
+use std::collections::HashSet;
+use std::f32::consts::FRAC_PI_2;
+
+use glm::{Mat4x4, Vec3};
+
+use super::controller::CameraController;
+use super::input::{InputEvent, Key, MouseButton};
+
+const PITCH_LIMIT: f32 = FRAC_PI_2 - 0.01;
+
+#[derive(Debug, Clone)]
+pub struct FlyController {
+    pub position: Vec3,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub speed: f32,
+    pub boost: f32,
+    pub look_speed: f32,
+    pressed: HashSet<Key>,
+    pointer_locked: bool,
+    looking: bool,
+}
+
+impl FlyController {
+    pub fn new(position: Vec3) -> Self {
+        Self {
+            position,
+            yaw: 0.0,
+            pitch: 0.0,
+            speed: 0.002,
+            boost: 4.0,
+            look_speed: 0.003,
+            pressed: HashSet::new(),
+            pointer_locked: false,
+            looking: false,
+        }
+    }
+
+    pub fn forward(&self) -> Vec3 {
+        Vec3::new(
+            -self.pitch.cos() * self.yaw.sin(),
+            self.pitch.sin(),
+            -self.pitch.cos() * self.yaw.cos(),
+        )
+    }
+
+    pub fn right(&self) -> Vec3 {
+        Vec3::new(self.yaw.cos(), 0.0, -self.yaw.sin())
+    }
+
+    pub fn look(&mut self, d_yaw: f32, d_pitch: f32) {
+        self.yaw += d_yaw;
+        self.pitch = (self.pitch + d_pitch).clamp(-PITCH_LIMIT, PITCH_LIMIT);
+    }
+
+    fn axis(&self, positive: Key, negative: Key) -> f32 {
+        self.pressed.contains(&positive) as i32 as f32
+            - self.pressed.contains(&negative) as i32 as f32
+    }
+}
+
+impl CameraController for FlyController {
+    fn handle_event(&mut self, event: &InputEvent) {
+        match *event {
+            InputEvent::KeyDown(key) => {
+                self.pressed.insert(key);
+            }
+            InputEvent::KeyUp(key) => {
+                self.pressed.remove(&key);
+            }
+            InputEvent::PointerLock(locked) => self.pointer_locked = locked,
+            InputEvent::PointerDown {
+                button: MouseButton::Left,
+                ..
+            } => self.looking = true,
+            InputEvent::PointerUp {
+                button: MouseButton::Left,
+                ..
+            } => self.looking = false,
+            InputEvent::PointerMove { dx, dy, .. } if self.pointer_locked || self.looking => {
+                self.look(-dx * self.look_speed, -dy * self.look_speed);
+            }
+            _ => {}
+        }
+    }
+
+    fn update(&mut self, d_time: f64) {
+        let direction = self.forward() * self.axis(Key::Forward, Key::Backward)
+            + self.right() * self.axis(Key::Right, Key::Left)
+            + Vec3::y() * self.axis(Key::Up, Key::Down);
+        if direction.norm_squared() == 0.0 {
+            return;
+        }
+
+        let boost = if self.pressed.contains(&Key::Boost) {
+            self.boost
+        } else {
+            1.0
+        };
+        self.position += direction.normalize() * self.speed * boost * d_time as f32;
+    }
+
+    fn view(&self) -> Mat4x4 {
+        glm::look_at(
+            &self.position,
+            &(self.position + self.forward()),
+            &Vec3::y(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+
+    const TOLERANCE: f32 = 1e-5;
+    const FRAME_TIME: f64 = 100.0;
+
+    fn press(fly: &mut FlyController, keys: &[Key]) {
+        for &key in keys {
+            fly.handle_event(&InputEvent::KeyDown(key));
+        }
+    }
+
+    #[test]
+    fn basis_matches_view_matrix() {
+        let mut fly = FlyController::new(Vec3::new(1.0, 2.0, 3.0));
+        fly.look(0.8, 0.3);
+        let view = fly.view();
+
+        let forward = view.transform_vector(&fly.forward());
+        let right = view.transform_vector(&fly.right());
+        assert!((forward - Vec3::new(0.0, 0.0, -1.0)).norm() < TOLERANCE);
+        assert!((right - Vec3::new(1.0, 0.0, 0.0)).norm() < TOLERANCE);
+    }
+
+    #[test]
+    fn moves_along_camera_basis() {
+        let mut fly = FlyController::new(Vec3::zeros());
+        fly.yaw = FRAC_PI_2;
+        let step = fly.speed * FRAME_TIME as f32;
+
+        press(&mut fly, &[Key::Forward]);
+        fly.update(FRAME_TIME);
+        assert!((fly.position - Vec3::new(-step, 0.0, 0.0)).norm() < TOLERANCE);
+
+        fly.handle_event(&InputEvent::KeyUp(Key::Forward));
+        press(&mut fly, &[Key::Right]);
+        fly.update(FRAME_TIME);
+        assert!((fly.position - Vec3::new(-step, 0.0, -step)).norm() < TOLERANCE);
+    }
+
+    #[test]
+    fn diagonal_and_boosted_movement_keep_speed() {
+        let mut fly = FlyController::new(Vec3::zeros());
+        press(&mut fly, &[Key::Forward, Key::Left, Key::Up]);
+        fly.update(FRAME_TIME);
+        let step = fly.speed * FRAME_TIME as f32;
+        assert!((fly.position.norm() - step).abs() < TOLERANCE);
+
+        let start = fly.position;
+        press(&mut fly, &[Key::Boost]);
+        fly.update(FRAME_TIME);
+        assert!(((fly.position - start).norm() - step * fly.boost).abs() < TOLERANCE);
+
+        let start = fly.position;
+        press(&mut fly, &[Key::Backward, Key::Right, Key::Down]);
+        fly.update(FRAME_TIME);
+        assert_eq!(fly.position, start);
+    }
+
+    #[test]
+    fn looks_only_while_locked_or_dragging() {
+        let mut fly = FlyController::new(Vec3::zeros());
+        let look = InputEvent::PointerMove {
+            x: 0.0,
+            y: 0.0,
+            dx: 10.0,
+            dy: -10.0,
+        };
+
+        fly.handle_event(&look);
+        assert_eq!((fly.yaw, fly.pitch), (0.0, 0.0));
+
+        fly.handle_event(&InputEvent::PointerLock(true));
+        fly.handle_event(&look);
+        assert!(fly.yaw < 0.0 && fly.pitch > 0.0);
+
+        fly.handle_event(&InputEvent::PointerLock(false));
+        let (yaw, pitch) = (fly.yaw, fly.pitch);
+        fly.handle_event(&look);
+        assert_eq!((fly.yaw, fly.pitch), (yaw, pitch));
+
+        fly.handle_event(&InputEvent::PointerDown {
+            button: MouseButton::Left,
+            x: 0.0,
+            y: 0.0,
+        });
+        fly.handle_event(&look);
+        assert!(fly.yaw < yaw);
+    }
+
+    #[test]
+    fn clamps_pitch() {
+        let mut fly = FlyController::new(Vec3::zeros());
+        fly.look(0.0, 10.0);
+        assert_eq!(fly.pitch, PITCH_LIMIT);
+        fly.look(0.0, -20.0);
+        assert_eq!(fly.pitch, -PITCH_LIMIT);
+        assert!(fly.view().iter().all(|value| value.is_finite()));
+    }
+}
@@ -0,0 +1,191 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    Left,
+    Middle,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    Forward,
+    Backward,
+    Left,
+    Right,
+    Up,
+    Down,
+    Boost,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TouchPoint {
+    pub id: i32,
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputEvent {
+    PointerDown { button: MouseButton, x: f32, y: f32 },
+    PointerUp { button: MouseButton, x: f32, y: f32 },
+    PointerMove { x: f32, y: f32, dx: f32, dy: f32 },
+    PointerLock(bool),
+    Wheel { delta: f32, x: f32, y: f32 },
+    Pinch { scale: f32, x: f32, y: f32 },
+    Pan { dx: f32, dy: f32 },
+    KeyDown(Key),
+    KeyUp(Key),
+    Resize { width: f32, height: f32 },
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct InputQueue {
+    events: Rc<RefCell<Vec<InputEvent>>>,
+}
+
+impl InputQueue {
+    pub fn push(&self, event: InputEvent) {
+        self.events.borrow_mut().push(event);
+    }
+
+    pub fn take_events(&self) -> Vec<InputEvent> {
+        std::mem::take(&mut *self.events.borrow_mut())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TouchTracker {
+    touches: Vec<TouchPoint>,
+}
+
+impl TouchTracker {
+    pub fn update(&mut self, touches: &[TouchPoint]) -> Vec<InputEvent> {
+        let mut events = vec![];
+        match (self.touches.as_slice(), touches) {
+            ([prev], [curr]) if prev.id == curr.id => events.push(InputEvent::PointerMove {
+                x: curr.x,
+                y: curr.y,
+                dx: curr.x - prev.x,
+                dy: curr.y - prev.y,
+            }),
+            ([p0, p1], [c0, c1]) if p0.id == c0.id && p1.id == c1.id => {
+                let prev_center = midpoint(p0, p1);
+                let curr_center = midpoint(c0, c1);
+                let prev_spread = spread(p0, p1);
+                let curr_spread = spread(c0, c1);
+                if prev_spread > 0.0 && curr_spread > 0.0 {
+                    events.push(InputEvent::Pinch {
+                        scale: curr_spread / prev_spread,
+                        x: curr_center.0,
+                        y: curr_center.1,
+                    });
+                }
+                events.push(InputEvent::Pan {
+                    dx: curr_center.0 - prev_center.0,
+                    dy: curr_center.1 - prev_center.1,
+                });
+            }
+            (prev, curr) => {
+                if let [touch] = prev {
+                    events.push(InputEvent::PointerUp {
+                        button: MouseButton::Left,
+                        x: touch.x,
+                        y: touch.y,
+                    });
+                }
+                if let [touch] = curr {
+                    events.push(InputEvent::PointerDown {
+                        button: MouseButton::Left,
+                        x: touch.x,
+                        y: touch.y,
+                    });
+                }
+            }
+        }
+        self.touches = touches.to_vec();
+        events
+    }
+}
+
+fn midpoint(a: &TouchPoint, b: &TouchPoint) -> (f32, f32) {
+    ((a.x + b.x) * 0.5, (a.y + b.y) * 0.5)
+}
+
+fn spread(a: &TouchPoint, b: &TouchPoint) -> f32 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f32 = 1e-5;
+
+    fn touch(id: i32, x: f32, y: f32) -> TouchPoint {
+        TouchPoint { id, x, y }
+    }
+
+    #[test]
+    fn single_touch_acts_as_left_pointer() {
+        let mut tracker = TouchTracker::default();
+        assert_eq!(
+            tracker.update(&[touch(1, 10.0, 20.0)]),
+            [InputEvent::PointerDown {
+                button: MouseButton::Left,
+                x: 10.0,
+                y: 20.0,
+            }]
+        );
+        assert_eq!(
+            tracker.update(&[touch(1, 15.0, 18.0)]),
+            [InputEvent::PointerMove {
+                x: 15.0,
+                y: 18.0,
+                dx: 5.0,
+                dy: -2.0,
+            }]
+        );
+        assert_eq!(
+            tracker.update(&[]),
+            [InputEvent::PointerUp {
+                button: MouseButton::Left,
+                x: 15.0,
+                y: 18.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn pinch_scale_follows_touch_distance() {
+        let mut tracker = TouchTracker::default();
+        tracker.update(&[touch(1, 0.0, 0.0), touch(2, 30.0, 40.0)]);
+        let events = tracker.update(&[touch(1, -30.0, -40.0), touch(2, 60.0, 80.0)]);
+
+        match events.as_slice() {
+            [InputEvent::Pinch { scale, x, y }, InputEvent::Pan { dx, dy }] => {
+                assert!((scale - 3.0).abs() < TOLERANCE);
+                assert!((x - 15.0).abs() < TOLERANCE && (y - 20.0).abs() < TOLERANCE);
+                assert!(dx.abs() < TOLERANCE && dy.abs() < TOLERANCE);
+            }
+            events => panic!("unexpected events {:?}", events),
+        }
+    }
+
+    #[test]
+    fn coincident_touches_only_pan() {
+        let mut tracker = TouchTracker::default();
+        tracker.update(&[touch(1, 5.0, 5.0), touch(2, 5.0, 5.0)]);
+        let events = tracker.update(&[touch(1, 8.0, 9.0), touch(2, 8.0, 9.0)]);
+        assert_eq!(events, [InputEvent::Pan { dx: 3.0, dy: 4.0 }]);
+    }
+
+    #[test]
+    fn queue_hands_out_events_once() {
+        let queue = InputQueue::default();
+        queue.clone().push(InputEvent::KeyDown(Key::Forward));
+        assert_eq!(queue.take_events(), [InputEvent::KeyDown(Key::Forward)]);
+        assert!(queue.take_events().is_empty());
+    }
+}
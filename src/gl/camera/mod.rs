@@ -0,0 +1,328 @@
+pub mod controller;
+pub mod fly;
+pub mod input;
+pub mod orbit;
+pub mod pan_zoom;
+pub mod projection;
+pub mod web_input;
+
+use glm::{Mat4x4, Vec2, Vec3, Vec4};
+
+use crate::gl::shader::uniform_value::UniformValue;
+use crate::math::bounds::Sphere;
+use crate::math::frustum::Frustum;
+use crate::math::ray::Ray;
+
+use self::input::InputEvent;
+use self::projection::Projection;
+use super::{
+    core::instance::GL,
+    shader::{
+        locations::{SimpleUniformLocation, UniformLocation},
+        program::ShaderProgram,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct Camera {
+    pub model: Mat4x4,
+    pub view: Mat4x4,
+    projection: Projection,
+    projection_matrix: Mat4x4,
+    viewport: Vec2,
+}
+
+impl Camera {
+    pub fn new(projection: Projection) -> Self {
+        Self {
+            model: Mat4x4::identity(),
+            view: Mat4x4::identity(),
+            projection,
+            projection_matrix: projection.matrix(1.0),
+            viewport: Vec2::new(1.0, 1.0),
+        }
+    }
+
+    pub fn with_model(&self, model: Mat4x4) -> Self {
+        Self {
+            model,
+            ..self.clone()
+        }
+    }
+
+    pub fn projection(&self) -> &Projection {
+        &self.projection
+    }
+
+    pub fn set_projection(&mut self, projection: Projection) {
+        self.projection = projection;
+        self.projection_matrix = projection.matrix(self.aspect());
+    }
+
+    pub fn viewport(&self) -> Vec2 {
+        self.viewport
+    }
+
+    pub fn set_viewport(&mut self, width: i32, height: i32) {
+        let viewport = Vec2::new(width.max(1) as f32, height.max(1) as f32);
+        if viewport != self.viewport {
+            self.viewport = viewport;
+            self.projection_matrix = self.projection.matrix(self.aspect());
+        }
+    }
+
+    pub fn handle_event(&mut self, event: &InputEvent) {
+        if let InputEvent::Resize { width, height } = *event {
+            self.set_viewport(width as i32, height as i32);
+        }
+    }
+
+    pub fn aspect(&self) -> f32 {
+        self.viewport.x / self.viewport.y
+    }
+
+    pub fn projection_matrix(&self) -> &Mat4x4 {
+        &self.projection_matrix
+    }
+
+    pub fn view_projection(&self) -> Mat4x4 {
+        self.projection_matrix * self.view
+    }
+
+    pub fn mvp(&self) -> Mat4x4 {
+        self.projection_matrix * self.view * self.model
+    }
+
+    pub fn view_inverse(&self) -> Option<Mat4x4> {
+        self.view.try_inverse()
+    }
+
+    pub fn projection_inverse(&self) -> Option<Mat4x4> {
+        self.projection_matrix.try_inverse()
+    }
+
+    pub fn view_projection_inverse(&self) -> Option<Mat4x4> {
+        self.view_projection().try_inverse()
+    }
+
+    pub fn frustum(&self) -> Frustum {
+        Frustum::from_matrix(&self.view_projection())
+    }
+
+    pub fn model_frustum(&self) -> Frustum {
+        Frustum::from_matrix(&self.mvp())
+    }
+
+    pub fn frustum_corners(&self, near: f32, far: f32) -> Option<[Vec3; 8]> {
+        let view_inverse = self.view_inverse()?;
+        let corners = self.projection.view_space_corners(self.aspect(), near, far);
+        Some(corners.map(|corner| view_inverse.transform_point(&corner.into()).coords))
+    }
+
+    pub fn world_to_screen(&self, point: &Vec3) -> Option<Vec3> {
+        let clip = self.view_projection() * Vec4::new(point.x, point.y, point.z, 1.0);
+        if clip.w <= 0.0 {
+            return None;
+        }
+
+        let ndc = clip.xyz() / clip.w;
+        Some(Vec3::new(
+            (ndc.x + 1.0) * 0.5 * self.viewport.x,
+            (1.0 - ndc.y) * 0.5 * self.viewport.y,
+            ndc.z * 0.5 + 0.5,
+        ))
+    }
+
+    pub fn projected_diameter(&self, sphere: &Sphere) -> f32 {
+        let clip_w = match self.projection {
+            Projection::Orthographic { .. } => 1.0,
+            Projection::Perspective { .. } | Projection::InfiniteReversedZ { .. } => {
+                let depth = -(self.view * sphere.center.push(1.0)).z;
+                if depth <= sphere.radius {
+                    return f32::INFINITY;
+                }
+                depth
+            }
+        };
+        sphere.radius * self.projection_matrix[(1, 1)] / clip_w * self.viewport.y
+    }
+
+    pub fn screen_to_world(&self, x: f32, y: f32, depth: f32) -> Option<Vec3> {
+        let ndc = Vec4::new(
+            x / self.viewport.x * 2.0 - 1.0,
+            1.0 - y / self.viewport.y * 2.0,
+            depth * 2.0 - 1.0,
+            1.0,
+        );
+        let point = self.view_projection_inverse()? * ndc;
+        (point.w.abs() > f32::EPSILON).then(|| point.xyz() / point.w)
+    }
+
+    pub fn screen_ray(&self, x: f32, y: f32) -> Option<Ray> {
+        let ndc = Vec2::new(
+            x / self.viewport.x * 2.0 - 1.0,
+            1.0 - y / self.viewport.y * 2.0,
+        );
+        let (near_z, far_z) = self.projection.ndc_depth_range();
+        Ray::unproject(&self.view_projection_inverse()?, &ndc, near_z, far_z)
+    }
+
+    pub fn model_ray(&self, x: f32, y: f32) -> Option<Ray> {
+        let inverse_model = self.model.try_inverse()?;
+        Some(self.screen_ray(x, y)?.transform(&inverse_model))
+    }
+}
+
+pub struct CameraUniformLocation {
+    model: SimpleUniformLocation<Mat4x4>,
+    view: SimpleUniformLocation<Mat4x4>,
+    projection: SimpleUniformLocation<Mat4x4>,
+}
+
+impl UniformLocation<Camera> for CameraUniformLocation {
+    fn new(program: &ShaderProgram, name: &str) -> Self {
+        let model_name = format!("{name}.model");
+        let view_name = format!("{name}.view");
+        let projection_name = format!("{name}.projection");
+        Self {
+            model: SimpleUniformLocation::new(program, model_name.as_str()),
+            view: SimpleUniformLocation::new(program, view_name.as_str()),
+            projection: SimpleUniformLocation::new(program, projection_name.as_str()),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.model.is_valid() && self.view.is_valid() && self.projection.is_valid()
+    }
+}
+
+impl UniformValue for Camera {
+    type Location = CameraUniformLocation;
+
+    fn set_to(&self, gl: &GL, location: &Self::Location) {
+        self.model.set_to(gl, &location.model);
+        self.view.set_to(gl, &location.view);
+        self.projection_matrix.set_to(gl, &location.projection);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f32 = 1e-3;
+    const WIDTH: i32 = 800;
+    const HEIGHT: i32 = 600;
+
+    fn projections() -> [Projection; 3] {
+        [
+            Projection::Perspective {
+                fov_y: std::f32::consts::FRAC_PI_3,
+                near: 0.1,
+                far: 100.0,
+            },
+            Projection::Orthographic {
+                half_height: 5.0,
+                near: 0.1,
+                far: 100.0,
+            },
+            Projection::InfiniteReversedZ {
+                fov_y: std::f32::consts::FRAC_PI_3,
+                near: 0.1,
+            },
+        ]
+    }
+
+    fn eye() -> Vec3 {
+        Vec3::new(1.0, 2.0, 8.0)
+    }
+
+    fn forward() -> Vec3 {
+        (Vec3::new(0.0, 1.0, 0.0) - eye()).normalize()
+    }
+
+    fn camera(projection: Projection) -> Camera {
+        let mut camera = Camera::new(projection);
+        camera.set_viewport(WIDTH, HEIGHT);
+        camera.view = glm::look_at(&eye(), &(eye() + forward()), &Vec3::y());
+        camera
+    }
+
+    #[test]
+    fn world_screen_round_trip() {
+        let right = forward().cross(&Vec3::y()).normalize();
+        for projection in projections().iter() {
+            let camera = camera(*projection);
+            for &distance in [0.5, 2.0, 10.0, 60.0].iter() {
+                let point = eye() + forward() * distance + right * 0.1 * distance;
+                let screen = camera.world_to_screen(&point).unwrap();
+                assert!((0.0..=1.0).contains(&screen.z), "{:?}", projection);
+                let restored = camera
+                    .screen_to_world(screen.x, screen.y, screen.z)
+                    .unwrap();
+                assert!(
+                    (restored - point).norm() < TOLERANCE * distance,
+                    "{:?} at {}: {:?} vs {:?}",
+                    projection,
+                    distance,
+                    restored,
+                    point
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn center_ray_follows_view_direction() {
+        for projection in projections().iter() {
+            let camera = camera(*projection);
+            let ray = camera
+                .screen_ray(WIDTH as f32 * 0.5, HEIGHT as f32 * 0.5)
+                .unwrap();
+            assert!(
+                (ray.direction - forward()).norm() < TOLERANCE,
+                "{:?}",
+                projection
+            );
+            let near_point = eye() + forward() * projection.near();
+            assert!(
+                (ray.origin - near_point).norm() < TOLERANCE,
+                "{:?}",
+                projection
+            );
+        }
+    }
+
+    #[test]
+    fn corner_ray_passes_through_frustum_corner() {
+        let camera = camera(projections()[0]);
+        let corners = camera.frustum_corners(1.0, 10.0).unwrap();
+        let ray = camera.screen_ray(0.0, HEIGHT as f32).unwrap();
+        for corner in [corners[0], corners[4]].iter() {
+            let to_corner = corner - ray.origin;
+            assert!(to_corner.normalize().dot(&ray.direction) > 1.0 - 1e-5);
+        }
+    }
+
+    #[test]
+    fn frustum_corners_sit_on_near_and_far_planes() {
+        for projection in projections().iter() {
+            let camera = camera(*projection);
+            let corners = camera.frustum_corners(1.0, 10.0).unwrap();
+            for (index, corner) in corners.iter().enumerate() {
+                let depth = if index < 4 { 1.0 } else { 10.0 };
+                assert!(((corner - eye()).dot(&forward()) - depth).abs() < TOLERANCE);
+
+                let screen = camera.world_to_screen(corner).unwrap();
+                let expected_x = if index % 4 == 0 || index % 4 == 3 {
+                    0.0
+                } else {
+                    WIDTH as f32
+                };
+                let expected_y = if index % 4 < 2 { HEIGHT as f32 } else { 0.0 };
+                assert!((screen.x - expected_x).abs() < 0.1, "{:?}", projection);
+                assert!((screen.y - expected_y).abs() < 0.1, "{:?}", projection);
+            }
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use std::f32::consts::FRAC_PI_2;
+
+use glm::{Mat4x4, Vec3};
+
+use super::controller::CameraController;
+use super::input::{InputEvent, MouseButton};
+
+const PITCH_LIMIT: f32 = FRAC_PI_2 - 0.01;
+
+#[derive(Debug, Clone)]
+pub struct OrbitController {
+    pub target: Vec3,
+    pub distance: f32,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub min_distance: f32,
+    pub max_distance: f32,
+    pub rotate_speed: f32,
+    pub pan_speed: f32,
+    pub dolly_speed: f32,
+    rotating: bool,
+    panning: bool,
+}
+
+impl OrbitController {
+    pub fn new(target: Vec3, distance: f32) -> Self {
+        Self {
+            target,
+            distance,
+            yaw: 0.0,
+            pitch: 0.0,
+            min_distance: 0.1,
+            max_distance: 100.0,
+            rotate_speed: 0.005,
+            pan_speed: 0.001,
+            dolly_speed: 0.001,
+            rotating: false,
+            panning: false,
+        }
+    }
+
+    pub fn eye(&self) -> Vec3 {
+        let offset = Vec3::new(
+            self.pitch.cos() * self.yaw.sin(),
+            self.pitch.sin(),
+            self.pitch.cos() * self.yaw.cos(),
+        );
+        self.target + offset * self.distance
+    }
+
+    pub fn rotate(&mut self, d_yaw: f32, d_pitch: f32) {
+        self.yaw += d_yaw;
+        self.pitch = (self.pitch + d_pitch).clamp(-PITCH_LIMIT, PITCH_LIMIT);
+    }
+
+    pub fn pan(&mut self, dx: f32, dy: f32) {
+        let forward = (self.target - self.eye()).normalize();
+        let right = forward.cross(&Vec3::y()).normalize();
+        let up = right.cross(&forward);
+        let scale = self.pan_speed * self.distance;
+        self.target += (-right * dx + up * dy) * scale;
+    }
+
+    pub fn dolly(&mut self, factor: f32) {
+        self.distance = (self.distance * factor).clamp(self.min_distance, self.max_distance);
+    }
+}
+
+impl CameraController for OrbitController {
+    fn handle_event(&mut self, event: &InputEvent) {
+        match *event {
+            InputEvent::PointerDown { button, .. } | InputEvent::PointerUp { button, .. } => {
+                let pressed = matches!(event, InputEvent::PointerDown { .. });
+                match button {
+                    MouseButton::Left => self.rotating = pressed,
+                    MouseButton::Right | MouseButton::Middle => self.panning = pressed,
+                }
+            }
+            InputEvent::PointerMove { dx, dy, .. } => {
+                if self.rotating {
+                    self.rotate(-dx * self.rotate_speed, dy * self.rotate_speed);
+                } else if self.panning {
+                    self.pan(dx, dy);
+                }
+            }
+            InputEvent::Pan { dx, dy } => self.pan(dx, dy),
+            InputEvent::Wheel { delta, .. } => self.dolly((delta * self.dolly_speed).exp()),
+            InputEvent::Pinch { scale, .. } => self.dolly(1.0 / scale),
+            _ => {}
+        }
+    }
+
+    fn view(&self) -> Mat4x4 {
+        glm::look_at(&self.eye(), &self.target, &Vec3::y())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f32 = 1e-5;
+
+    #[test]
+    fn clamps_pitch_short_of_poles() {
+        let mut orbit = OrbitController::new(Vec3::zeros(), 2.0);
+        orbit.rotate(0.0, 10.0);
+        assert_eq!(orbit.pitch, PITCH_LIMIT);
+        assert!(orbit.view().iter().all(|value| value.is_finite()));
+
+        orbit.rotate(0.0, -20.0);
+        assert_eq!(orbit.pitch, -PITCH_LIMIT);
+        assert!(orbit.view().iter().all(|value| value.is_finite()));
+    }
+
+    #[test]
+    fn clamps_dolly_distance() {
+        let mut orbit = OrbitController::new(Vec3::zeros(), 2.0);
+        orbit.handle_event(&InputEvent::Wheel {
+            delta: 1e4,
+            x: 0.0,
+            y: 0.0,
+        });
+        assert_eq!(orbit.distance, orbit.max_distance);
+
+        orbit.handle_event(&InputEvent::Pinch {
+            scale: 1e4,
+            x: 0.0,
+            y: 0.0,
+        });
+        assert_eq!(orbit.distance, orbit.min_distance);
+    }
+
+    #[test]
+    fn eye_stays_at_distance_from_target() {
+        let mut orbit = OrbitController::new(Vec3::new(1.0, 2.0, 3.0), 5.0);
+        orbit.rotate(0.7, -0.4);
+        assert!(((orbit.eye() - orbit.target).norm() - 5.0).abs() < TOLERANCE);
+
+        let target = orbit.view().transform_point(&orbit.target.into());
+        assert!((target.coords - Vec3::new(0.0, 0.0, -5.0)).norm() < TOLERANCE);
+    }
+
+    #[test]
+    fn left_drag_rotates_and_right_drag_pans() {
+        let mut orbit = OrbitController::new(Vec3::zeros(), 2.0);
+        let drag = InputEvent::PointerMove {
+            x: 0.0,
+            y: 0.0,
+            dx: 100.0,
+            dy: 0.0,
+        };
+
+        orbit.handle_event(&drag);
+        assert_eq!(orbit.yaw, 0.0);
+
+        orbit.handle_event(&InputEvent::PointerDown {
+            button: MouseButton::Left,
+            x: 0.0,
+            y: 0.0,
+        });
+        orbit.handle_event(&drag);
+        assert!((orbit.yaw + 100.0 * orbit.rotate_speed).abs() < TOLERANCE);
+        orbit.handle_event(&InputEvent::PointerUp {
+            button: MouseButton::Left,
+            x: 0.0,
+            y: 0.0,
+        });
+
+        let yaw = orbit.yaw;
+        orbit.handle_event(&InputEvent::PointerDown {
+            button: MouseButton::Right,
+            x: 0.0,
+            y: 0.0,
+        });
+        orbit.handle_event(&drag);
+        assert_eq!(orbit.yaw, yaw);
+        assert!(orbit.target.norm() > 0.0);
+        assert!(orbit.target.y.abs() < TOLERANCE);
+    }
+}
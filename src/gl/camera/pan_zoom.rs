@@ -0,0 +1,186 @@
+use glm::{Mat4x4, Vec2};
+
+use super::controller::CameraController;
+use super::input::{InputEvent, MouseButton};
+use super::projection::Projection;
+use super::Camera;
+
+#[derive(Debug, Clone)]
+pub struct PanZoomController {
+    pub center: Vec2,
+    pub half_height: f32,
+    pub min_half_height: f32,
+    pub max_half_height: f32,
+    pub zoom_speed: f32,
+    pub near: f32,
+    pub far: f32,
+    viewport: Vec2,
+    dragging: bool,
+}
+
+impl PanZoomController {
+    pub fn new(center: Vec2, half_height: f32) -> Self {
+        Self {
+            center,
+            half_height,
+            min_half_height: 0.01,
+            max_half_height: 1000.0,
+            zoom_speed: 0.001,
+            near: -100.0,
+            far: 100.0,
+            viewport: Vec2::new(1.0, 1.0),
+            dragging: false,
+        }
+    }
+
+    pub fn world_per_pixel(&self) -> f32 {
+        2.0 * self.half_height / self.viewport.y.max(1.0)
+    }
+
+    pub fn screen_to_world(&self, x: f32, y: f32) -> Vec2 {
+        let offset = Vec2::new(x - self.viewport.x * 0.5, self.viewport.y * 0.5 - y);
+        self.center + offset * self.world_per_pixel()
+    }
+
+    pub fn pan(&mut self, dx: f32, dy: f32) {
+        self.center += Vec2::new(-dx, dy) * self.world_per_pixel();
+    }
+
+    pub fn zoom_at(&mut self, factor: f32, x: f32, y: f32) {
+        let anchor = self.screen_to_world(x, y);
+        let half_height =
+            (self.half_height * factor).clamp(self.min_half_height, self.max_half_height);
+        let ratio = half_height / self.half_height;
+        self.center = anchor + (self.center - anchor) * ratio;
+        self.half_height = half_height;
+    }
+
+    pub fn projection(&self) -> Projection {
+        Projection::Orthographic {
+            half_height: self.half_height,
+            near: self.near,
+            far: self.far,
+        }
+    }
+}
+
+impl CameraController for PanZoomController {
+    fn handle_event(&mut self, event: &InputEvent) {
+        match *event {
+            InputEvent::Resize { width, height } => self.viewport = Vec2::new(width, height),
+            InputEvent::PointerDown {
+                button: MouseButton::Left,
+                ..
+            } => self.dragging = true,
+            InputEvent::PointerUp {
+                button: MouseButton::Left,
+                ..
+            } => self.dragging = false,
+            InputEvent::PointerMove { dx, dy, .. } if self.dragging => self.pan(dx, dy),
+            InputEvent::Pan { dx, dy } => self.pan(dx, dy),
+            InputEvent::Wheel { delta, x, y } => {
+                self.zoom_at((delta * self.zoom_speed).exp(), x, y)
+            }
+            InputEvent::Pinch { scale, x, y } => self.zoom_at(1.0 / scale, x, y),
+            _ => {}
+        }
+    }
+
+    fn view(&self) -> Mat4x4 {
+        Mat4x4::new_translation(&glm::vec3(-self.center.x, -self.center.y, 0.0))
+    }
+
+    fn apply(&self, camera: &mut Camera) {
+        camera.view = self.view();
+        camera.set_projection(self.projection());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f32 = 1e-4;
+
+    fn controller() -> PanZoomController {
+        let mut controller = PanZoomController::new(Vec2::new(1.0, -2.0), 10.0);
+        controller.handle_event(&InputEvent::Resize {
+            width: 800.0,
+            height: 400.0,
+        });
+        controller
+    }
+
+    #[test]
+    fn maps_screen_center_to_view_center() {
+        let controller = controller();
+        assert!((controller.world_per_pixel() - 0.05).abs() < TOLERANCE);
+        assert!((controller.screen_to_world(400.0, 200.0) - controller.center).norm() < TOLERANCE);
+        assert!((controller.screen_to_world(800.0, 0.0) - Vec2::new(21.0, 8.0)).norm() < TOLERANCE);
+    }
+
+    #[test]
+    fn drag_pans_by_world_per_pixel() {
+        let mut controller = controller();
+        controller.handle_event(&InputEvent::PointerDown {
+            button: MouseButton::Left,
+            x: 0.0,
+            y: 0.0,
+        });
+        controller.handle_event(&InputEvent::PointerMove {
+            x: 0.0,
+            y: 0.0,
+            dx: 20.0,
+            dy: 10.0,
+        });
+        assert!((controller.center - Vec2::new(0.0, -1.5)).norm() < TOLERANCE);
+    }
+
+    #[test]
+    fn zoom_keeps_anchor_under_cursor() {
+        let mut controller = controller();
+        let anchor = controller.screen_to_world(600.0, 100.0);
+        controller.zoom_at(0.5, 600.0, 100.0);
+        assert!((controller.half_height - 5.0).abs() < TOLERANCE);
+        assert!((controller.screen_to_world(600.0, 100.0) - anchor).norm() < TOLERANCE);
+    }
+
+    #[test]
+    fn clamps_zoom_limits() {
+        let mut controller = controller();
+        controller.handle_event(&InputEvent::Wheel {
+            delta: 1e5,
+            x: 400.0,
+            y: 200.0,
+        });
+        assert_eq!(controller.half_height, controller.max_half_height);
+
+        controller.handle_event(&InputEvent::Pinch {
+            scale: 1e9,
+            x: 100.0,
+            y: 100.0,
+        });
+        assert_eq!(controller.half_height, controller.min_half_height);
+        assert!(controller.center.iter().all(|value| value.is_finite()));
+    }
+
+    #[test]
+    fn applies_orthographic_projection() {
+        let mut controller = controller();
+        controller.zoom_at(2.0, 400.0, 200.0);
+        let mut camera = Camera::new(Projection::Perspective {
+            fov_y: 1.0,
+            near: 0.1,
+            far: 10.0,
+        });
+        controller.apply(&mut camera);
+        assert_eq!(
+            *camera.projection(),
+            Projection::Orthographic {
+                half_height: 20.0,
+                near: controller.near,
+                far: controller.far,
+            }
+        );
+    }
+}
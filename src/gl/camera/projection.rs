@@ -0,0 +1,197 @@
+use glm::{Mat4x4, Vec3};
+
+use crate::gl;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Projection {
+    Perspective {
+        fov_y: f32,
+        near: f32,
+        far: f32,
+    },
+    Orthographic {
+        half_height: f32,
+        near: f32,
+        far: f32,
+    },
+    InfiniteReversedZ {
+        fov_y: f32,
+        near: f32,
+    },
+}
+
+impl Projection {
+    pub fn matrix(&self, aspect: f32) -> Mat4x4 {
+        match *self {
+            Projection::Perspective { fov_y, near, far } => {
+                glm::perspective(aspect, fov_y, near, far)
+            }
+            Projection::Orthographic {
+                half_height,
+                near,
+                far,
+            } => {
+                let half_width = half_height * aspect;
+                glm::ortho(
+                    -half_width,
+                    half_width,
+                    -half_height,
+                    half_height,
+                    near,
+                    far,
+                )
+            }
+            Projection::InfiniteReversedZ { fov_y, near } => {
+                let focal = 1.0 / (fov_y * 0.5).tan();
+                let mut matrix = Mat4x4::zeros();
+                matrix[(0, 0)] = focal / aspect;
+                matrix[(1, 1)] = focal;
+                matrix[(2, 2)] = 1.0;
+                matrix[(2, 3)] = 2.0 * near;
+                matrix[(3, 2)] = -1.0;
+                matrix
+            }
+        }
+    }
+
+    pub fn near(&self) -> f32 {
+        match *self {
+            Projection::Perspective { near, .. }
+            | Projection::Orthographic { near, .. }
+            | Projection::InfiniteReversedZ { near, .. } => near,
+        }
+    }
+
+    pub fn far(&self) -> f32 {
+        match *self {
+            Projection::Perspective { far, .. } | Projection::Orthographic { far, .. } => far,
+            Projection::InfiniteReversedZ { .. } => f32::INFINITY,
+        }
+    }
+
+    pub fn is_reversed_z(&self) -> bool {
+        matches!(self, Projection::InfiniteReversedZ { .. })
+    }
+
+    pub fn ndc_depth_range(&self) -> (f32, f32) {
+        if self.is_reversed_z() {
+            (1.0, 0.0)
+        } else {
+            (-1.0, 1.0)
+        }
+    }
+
+    pub fn depth_clear_value(&self) -> f32 {
+        if self.is_reversed_z() {
+            0.0
+        } else {
+            1.0
+        }
+    }
+
+    pub fn depth_func(&self) -> u32 {
+        self.map_depth_func(gl::LESS)
+    }
+
+    pub fn map_depth_func(&self, depth_func: u32) -> u32 {
+        if !self.is_reversed_z() {
+            return depth_func;
+        }
+        match depth_func {
+            gl::LESS => gl::GREATER,
+            gl::LEQUAL => gl::GEQUAL,
+            gl::GREATER => gl::LESS,
+            gl::GEQUAL => gl::LEQUAL,
+            other => other,
+        }
+    }
+
+    pub fn view_space_corners(&self, aspect: f32, near: f32, far: f32) -> [Vec3; 8] {
+        let half_sizes = |distance: f32| match *self {
+            Projection::Perspective { fov_y, .. } | Projection::InfiniteReversedZ { fov_y, .. } => {
+                let half_height = (fov_y * 0.5).tan() * distance;
+                (half_height * aspect, half_height)
+            }
+            Projection::Orthographic { half_height, .. } => (half_height * aspect, half_height),
+        };
+
+        let mut corners = [Vec3::zeros(); 8];
+        for (i, distance) in [near, far].iter().enumerate() {
+            let (half_width, half_height) = half_sizes(*distance);
+            corners[i * 4] = Vec3::new(-half_width, -half_height, -distance);
+            corners[i * 4 + 1] = Vec3::new(half_width, -half_height, -distance);
+            corners[i * 4 + 2] = Vec3::new(half_width, half_height, -distance);
+            corners[i * 4 + 3] = Vec3::new(-half_width, half_height, -distance);
+        }
+        corners
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glm::Vec4;
+
+    const TOLERANCE: f32 = 1e-5;
+    const NEAR: f32 = 0.5;
+
+    fn ndc_depth(projection: &Projection, distance: f32) -> f32 {
+        let clip = projection.matrix(1.5) * Vec4::new(0.0, 0.0, -distance, 1.0);
+        clip.z / clip.w
+    }
+
+    fn reversed_z() -> Projection {
+        Projection::InfiniteReversedZ {
+            fov_y: std::f32::consts::FRAC_PI_3,
+            near: NEAR,
+        }
+    }
+
+    #[test]
+    fn reversed_z_maps_near_to_one_and_far_toward_minus_one() {
+        let projection = reversed_z();
+        assert!((ndc_depth(&projection, NEAR) - 1.0).abs() < TOLERANCE);
+        assert_eq!(projection.ndc_depth_range().0, 1.0);
+
+        let mut previous = 1.0;
+        for &distance in [1.0, 10.0, 1e3, 1e6].iter() {
+            let depth = ndc_depth(&projection, distance);
+            assert!(depth < previous);
+            previous = depth;
+        }
+        assert!((previous + 1.0).abs() < 1e-4);
+        assert_eq!(projection.far(), f32::INFINITY);
+    }
+
+    #[test]
+    fn standard_projections_map_near_and_far_to_ndc_range() {
+        let projections = [
+            Projection::Perspective {
+                fov_y: std::f32::consts::FRAC_PI_3,
+                near: NEAR,
+                far: 20.0,
+            },
+            Projection::Orthographic {
+                half_height: 2.0,
+                near: NEAR,
+                far: 20.0,
+            },
+        ];
+        for projection in projections.iter() {
+            let (near, far) = projection.ndc_depth_range();
+            assert!((ndc_depth(projection, projection.near()) - near).abs() < TOLERANCE);
+            assert!((ndc_depth(projection, projection.far()) - far).abs() < TOLERANCE);
+            assert_eq!(projection.depth_clear_value(), 1.0);
+            assert_eq!(projection.depth_func(), gl::LESS);
+        }
+    }
+
+    #[test]
+    fn reversed_z_flips_depth_state() {
+        let projection = reversed_z();
+        assert_eq!(projection.depth_clear_value(), 0.0);
+        assert_eq!(projection.depth_func(), gl::GREATER);
+        assert_eq!(projection.map_depth_func(gl::LEQUAL), gl::GEQUAL);
+        assert_eq!(projection.map_depth_func(gl::ALWAYS), gl::ALWAYS);
+    }
+}
@@ -0,0 +1,149 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+use web_sys::{Element, KeyboardEvent, MouseEvent, TouchEvent, WheelEvent};
+
+use crate::utils::web;
+
+use super::input::{InputEvent, InputQueue, Key, MouseButton, TouchPoint, TouchTracker};
+
+const WHEEL_LINE_HEIGHT: f32 = 16.0;
+const WHEEL_PAGE_HEIGHT: f32 = 800.0;
+
+const POINTER_LOCK_CHANGE_EVENT: &str = "pointerlockchange";
+
+#[derive(Debug, Clone, Default)]
+pub struct WebInputForwarder {
+    queue: InputQueue,
+    touches: Rc<RefCell<TouchTracker>>,
+}
+
+impl WebInputForwarder {
+    pub fn queue(&self) -> &InputQueue {
+        &self.queue
+    }
+
+    pub fn mouse_down(&self, event: &MouseEvent) {
+        if let Some(button) = mouse_button(event) {
+            self.queue.push(InputEvent::PointerDown {
+                button,
+                x: event.offset_x() as f32,
+                y: event.offset_y() as f32,
+            });
+        }
+    }
+
+    pub fn mouse_up(&self, event: &MouseEvent) {
+        if let Some(button) = mouse_button(event) {
+            self.queue.push(InputEvent::PointerUp {
+                button,
+                x: event.offset_x() as f32,
+                y: event.offset_y() as f32,
+            });
+        }
+    }
+
+    pub fn mouse_move(&self, event: &MouseEvent) {
+        self.queue.push(InputEvent::PointerMove {
+            x: event.offset_x() as f32,
+            y: event.offset_y() as f32,
+            dx: event.movement_x() as f32,
+            dy: event.movement_y() as f32,
+        });
+    }
+
+    pub fn wheel(&self, event: &WheelEvent) {
+        event.prevent_default();
+        let scale = match event.delta_mode() {
+            WheelEvent::DOM_DELTA_LINE => WHEEL_LINE_HEIGHT,
+            WheelEvent::DOM_DELTA_PAGE => WHEEL_PAGE_HEIGHT,
+            _ => 1.0,
+        };
+        self.queue.push(InputEvent::Wheel {
+            delta: event.delta_y() as f32 * scale,
+            x: event.offset_x() as f32,
+            y: event.offset_y() as f32,
+        });
+    }
+
+    pub fn key_down(&self, event: &KeyboardEvent) {
+        if let Some(key) = key(event) {
+            self.queue.push(InputEvent::KeyDown(key));
+        }
+    }
+
+    pub fn key_up(&self, event: &KeyboardEvent) {
+        if let Some(key) = key(event) {
+            self.queue.push(InputEvent::KeyUp(key));
+        }
+    }
+
+    pub fn touch(&self, event: &TouchEvent) {
+        event.prevent_default();
+        let target = event.target().and_then(|t| t.dyn_into::<Element>().ok());
+        let (left, top) = target.map_or((0.0, 0.0), |element| {
+            let rect = element.get_bounding_client_rect();
+            (rect.left() as f32, rect.top() as f32)
+        });
+
+        let list = event.touches();
+        let touches: Vec<TouchPoint> = (0..list.length())
+            .filter_map(|i| list.get(i))
+            .map(|touch| TouchPoint {
+                id: touch.identifier(),
+                x: touch.client_x() as f32 - left,
+                y: touch.client_y() as f32 - top,
+            })
+            .collect();
+
+        for event in self.touches.borrow_mut().update(&touches) {
+            self.queue.push(event);
+        }
+    }
+
+    pub fn request_pointer_lock(&self, event: &MouseEvent) {
+        if let Some(element) = event.target().and_then(|t| t.dyn_into::<Element>().ok()) {
+            element.request_pointer_lock();
+        }
+    }
+
+    pub fn pointer_lock_changed(&self) {
+        let locked = web::document().pointer_lock_element().is_some();
+        self.queue.push(InputEvent::PointerLock(locked));
+    }
+
+    pub fn listen_pointer_lock(&self) -> Result<(), JsValue> {
+        let forwarder = self.clone();
+        let listener =
+            Closure::wrap(Box::new(move || forwarder.pointer_lock_changed()) as Box<dyn FnMut()>);
+        web::document().add_event_listener_with_callback(
+            POINTER_LOCK_CHANGE_EVENT,
+            listener.as_ref().unchecked_ref(),
+        )?;
+        listener.forget();
+        Ok(())
+    }
+}
+
+fn mouse_button(event: &MouseEvent) -> Option<MouseButton> {
+    match event.button() {
+        0 => Some(MouseButton::Left),
+        1 => Some(MouseButton::Middle),
+        2 => Some(MouseButton::Right),
+        _ => None,
+    }
+}
+
+fn key(event: &KeyboardEvent) -> Option<Key> {
+    match event.code().as_str() {
+        "KeyW" | "ArrowUp" => Some(Key::Forward),
+        "KeyS" | "ArrowDown" => Some(Key::Backward),
+        "KeyA" | "ArrowLeft" => Some(Key::Left),
+        "KeyD" | "ArrowRight" => Some(Key::Right),
+        "KeyE" | "Space" => Some(Key::Up),
+        "KeyQ" => Some(Key::Down),
+        "ShiftLeft" | "ShiftRight" => Some(Key::Boost),
+        _ => None,
+    }
+}
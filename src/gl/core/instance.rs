@@ -6,7 +6,7 @@ use web_sys::{HtmlCanvasElement, WebGl2RenderingContext};
 use crate::{gl::error::GLError, utils::logging::ExtUnwrapLog};
 
 #[repr(transparent)]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct GL {
     context: Rc<WebGl2RenderingContext>,
 }
@@ -26,6 +26,10 @@ impl GL {
             ),
         }
     }
+
+    pub fn enable_extension(&self, name: &str) -> bool {
+        matches!(self.context.get_extension(name), Ok(Some(_)))
+    }
 }
 
 impl Deref for GL {
@@ -0,0 +1,120 @@
+use glm::Mat4x4;
+
+use crate::math::bounds::{Aabb, Sphere};
+use crate::math::frustum::Frustum;
+
+use super::camera::Camera;
+use super::mesh::Mesh;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CullingStats {
+    pub drawn: usize,
+    pub culled: usize,
+}
+
+pub struct FrustumCuller {
+    frustum: Frustum,
+    stats: CullingStats,
+}
+
+impl FrustumCuller {
+    pub fn new(camera: &Camera) -> Self {
+        Self {
+            frustum: camera.frustum(),
+            stats: CullingStats::default(),
+        }
+    }
+
+    pub fn reset(&mut self, camera: &Camera) {
+        self.frustum = camera.frustum();
+        self.stats = CullingStats::default();
+    }
+
+    pub fn frustum(&self) -> &Frustum {
+        &self.frustum
+    }
+
+    pub fn stats(&self) -> CullingStats {
+        self.stats
+    }
+
+    pub fn is_aabb_visible(&mut self, aabb: &Aabb) -> bool {
+        self.count(self.frustum.intersects_aabb(aabb))
+    }
+
+    pub fn is_sphere_visible(&mut self, sphere: &Sphere) -> bool {
+        self.count(self.frustum.intersects_sphere(sphere))
+    }
+
+    pub fn is_mesh_visible(&mut self, mesh: &Mesh, model: &Mat4x4) -> bool {
+        let visible = self
+            .frustum
+            .intersects_sphere(&mesh.bounding_sphere().transform(model))
+            && self.frustum.intersects_aabb(&mesh.aabb().transform(model));
+        self.count(visible)
+    }
+
+    fn count(&mut self, visible: bool) -> bool {
+        if visible {
+            self.stats.drawn += 1;
+        } else {
+            self.stats.culled += 1;
+        }
+        visible
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gl::camera::projection::Projection;
+    use glm::Vec3;
+
+    fn camera() -> Camera {
+        let mut camera = Camera::new(Projection::Perspective {
+            fov_y: std::f32::consts::FRAC_PI_2,
+            near: 0.1,
+            far: 20.0,
+        });
+        camera.view = glm::look_at(&Vec3::new(0.0, 0.0, 5.0), &Vec3::zeros(), &Vec3::y());
+        camera
+    }
+
+    #[test]
+    fn counts_drawn_and_culled_objects() {
+        let mut culler = FrustumCuller::new(&camera());
+        assert!(culler.is_sphere_visible(&Sphere::new(Vec3::zeros(), 1.0)));
+        assert!(!culler.is_sphere_visible(&Sphere::new(Vec3::new(0.0, 0.0, 10.0), 1.0)));
+        assert!(culler.is_aabb_visible(&Aabb::new(Vec3::repeat(-1.0), Vec3::repeat(1.0))));
+        assert!(!culler.is_aabb_visible(&Aabb::new(
+            Vec3::new(-1.0, -1.0, -30.0),
+            Vec3::new(1.0, 1.0, -25.0)
+        )));
+        assert!(!culler.is_aabb_visible(&Aabb::empty()));
+        assert_eq!(
+            culler.stats(),
+            CullingStats {
+                drawn: 2,
+                culled: 3
+            }
+        );
+    }
+
+    #[test]
+    fn reset_follows_camera_and_clears_stats() {
+        let mut camera = camera();
+        let mut culler = FrustumCuller::new(&camera);
+        let behind = Sphere::new(Vec3::new(0.0, 0.0, 10.0), 1.0);
+        assert!(!culler.is_sphere_visible(&behind));
+
+        camera.view = glm::look_at(
+            &Vec3::new(0.0, 0.0, 5.0),
+            &Vec3::new(0.0, 0.0, 10.0),
+            &Vec3::y(),
+        );
+        culler.reset(&camera);
+        assert_eq!(culler.stats(), CullingStats::default());
+        assert!(culler.is_sphere_visible(&behind));
+        assert_eq!(culler.stats().drawn, 1);
+    }
+}
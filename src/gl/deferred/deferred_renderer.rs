@@ -0,0 +1,281 @@
+use std::rc::Rc;
+
+use glm::{Mat4x4, Vec3};
+
+use crate::gl;
+use crate::gl::camera::Camera;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::framebuffers::framebuffer::Framebuffer;
+use crate::gl::lighting::light::{Light, LightKind};
+use crate::gl::lighting::light_buffer::LightBuffer;
+use crate::gl::lighting::shaders::LIT_VERTEX_SHADER;
+use crate::gl::materials::material::Material;
+use crate::gl::materials::pipeline_state::{BlendMode, CullFace, PipelineState};
+use crate::gl::mesh::Mesh;
+use crate::gl::postprocess::post_pass::PostContext;
+use crate::gl::postprocess::render_target::RenderTarget;
+use crate::gl::postprocess::shaders::COPY_FRAGMENT_SHADER;
+use crate::gl::queue::render_queue::RenderQueue;
+use crate::gl::queue::render_stats::RenderStats;
+use crate::gl::screen_quad::SCREEN_QUAD_VERTEX_SHADER;
+use crate::gl::shader::locations::SimpleUniformLocation;
+use crate::gl::shader::program::ShaderProgram;
+use crate::gl::shader::shader_type::ShaderType;
+use crate::gl::ssao::ssao_pass::SsaoPass;
+use crate::gl::textures::texture::{Texture, TextureFormat};
+
+use super::error::DeferredError::MissingExtension;
+use super::g_buffer::GBuffer;
+use super::light_volume::build_light_volume;
+use super::shaders::{
+    global_lighting_fragment_shader, point_light_fragment_shader, GEOMETRY_FRAGMENT_SHADER,
+    LIGHT_VOLUME_VERTEX_SHADER,
+};
+
+const COLOR_BUFFER_FLOAT_EXTENSION: &str = "EXT_color_buffer_float";
+
+const EYE_POSITION_UNIFORM: &str = "u_eye_position";
+const OCCLUSION_UNIFORM: &str = "u_occlusion";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderPath {
+    Forward,
+    Deferred,
+}
+
+pub struct DeferredRenderer {
+    gbuffer: GBuffer,
+    accumulation: Framebuffer,
+    output: Rc<Texture>,
+    geometry_program: Rc<ShaderProgram>,
+    global: Material,
+    point: Material,
+    u_light_position: SimpleUniformLocation<Vec3>,
+    u_light_color: SimpleUniformLocation<Vec3>,
+    u_light_range: SimpleUniformLocation<f32>,
+    volume: Mesh,
+    lights: LightBuffer,
+    ssao: SsaoPass,
+    ssao_enabled: bool,
+    no_occlusion: Rc<Texture>,
+    context: PostContext,
+    copy: Material,
+    gl: Rc<GL>,
+}
+
+fn build_program(
+    gl: &Rc<GL>,
+    vertex: &str,
+    fragment: String,
+) -> Result<Rc<ShaderProgram>, GLError> {
+    Ok(Rc::new(
+        ShaderProgram::builder(gl)
+            .add_source(ShaderType::Vertex, vertex.into())
+            .add_source(ShaderType::Fragment, fragment)
+            .build()?,
+    ))
+}
+
+fn upload_lights(buffer: &mut LightBuffer, lights: &[Light]) {
+    let count = lights.len().min(buffer.max_lights());
+    buffer.upload(&lights[..count]);
+}
+
+impl DeferredRenderer {
+    pub fn try_new(gl: &Rc<GL>, max_lights: usize) -> Result<Self, GLError> {
+        if !gl.enable_extension(COLOR_BUFFER_FLOAT_EXTENSION) {
+            return Err(MissingExtension(COLOR_BUFFER_FLOAT_EXTENSION).into());
+        }
+        let size = (gl.drawing_buffer_width(), gl.drawing_buffer_height());
+        let lights = LightBuffer::try_new(gl, max_lights)?;
+
+        let geometry_program =
+            build_program(gl, LIT_VERTEX_SHADER, GEOMETRY_FRAGMENT_SHADER.into())?;
+        let global_program = build_program(
+            gl,
+            SCREEN_QUAD_VERTEX_SHADER,
+            global_lighting_fragment_shader(max_lights),
+        )?;
+        lights.attach(&global_program);
+        let point_program = build_program(
+            gl,
+            LIGHT_VOLUME_VERTEX_SHADER,
+            point_light_fragment_shader(),
+        )?;
+
+        let point_state = PipelineState {
+            depth_test: true,
+            depth_write: false,
+            depth_func: gl::GEQUAL,
+            cull: Some(CullFace::Front),
+            blend: Some(BlendMode::Additive),
+        };
+        let context = PostContext::try_new(gl)?;
+        let mut renderer = Self {
+            gbuffer: GBuffer::try_new(gl, size)?,
+            accumulation: Framebuffer::try_new(gl)?,
+            output: Rc::new(Texture::new_2d(gl, TextureFormat::RGBA16F, 1, 1)?),
+            geometry_program,
+            global: Material::new(gl, &global_program).with_state(PipelineState::fullscreen()),
+            u_light_position: point_program.get_uniform_location::<Vec3>("u_light_position"),
+            u_light_color: point_program.get_uniform_location::<Vec3>("u_light_color"),
+            u_light_range: point_program.get_uniform_location::<f32>("u_light_range"),
+            point: Material::new(gl, &point_program).with_state(point_state),
+            volume: build_light_volume(gl)?,
+            lights,
+            ssao: SsaoPass::try_new(&context, size)?,
+            ssao_enabled: true,
+            no_occlusion: Rc::new(Texture::solid_color(gl, [255; 4])?),
+            copy: context.material(COPY_FRAGMENT_SHADER)?,
+            context,
+            gl: Rc::clone(gl),
+        };
+        renderer.attach_outputs()?;
+        Ok(renderer)
+    }
+
+    fn attach_outputs(&mut self) -> Result<(), GLError> {
+        let (width, height) = self.gbuffer.size();
+        self.output = Rc::new(Texture::new_2d(
+            &self.gl,
+            TextureFormat::RGBA16F,
+            width,
+            height,
+        )?);
+
+        let binded = self.accumulation.bind();
+        binded.attach_texture(gl::COLOR_ATTACHMENT0, &self.output, gl::TEXTURE_2D, 0);
+        binded.attach_renderbuffer(gl::DEPTH_ATTACHMENT, self.gbuffer.depth());
+        binded.check_status()?;
+
+        for material in [&mut self.global, &mut self.point] {
+            material.set("u_gbuffer_position", Rc::clone(self.gbuffer.position()));
+            material.set("u_gbuffer_normal", Rc::clone(self.gbuffer.normal()));
+            material.set("u_gbuffer_albedo", Rc::clone(self.gbuffer.albedo()));
+            material.set("u_gbuffer_ambient", Rc::clone(self.gbuffer.ambient()));
+        }
+        Ok(())
+    }
+
+    pub fn gbuffer(&self) -> &GBuffer {
+        &self.gbuffer
+    }
+
+    pub fn output(&self) -> &Rc<Texture> {
+        &self.output
+    }
+
+    pub fn light_buffer(&self) -> &LightBuffer {
+        &self.lights
+    }
+
+    pub fn geometry_program(&self) -> &Rc<ShaderProgram> {
+        &self.geometry_program
+    }
+
+    pub fn ssao(&self) -> &SsaoPass {
+        &self.ssao
+    }
+
+    pub fn ssao_mut(&mut self) -> &mut SsaoPass {
+        &mut self.ssao
+    }
+
+    pub fn is_ssao_enabled(&self) -> bool {
+        self.ssao_enabled
+    }
+
+    pub fn set_ssao_enabled(&mut self, enabled: bool) {
+        self.ssao_enabled = enabled;
+    }
+
+    pub fn material(&self, forward: &Material) -> Material {
+        forward.with_program(&self.geometry_program)
+    }
+
+    pub fn resize(&mut self, width: i32, height: i32) -> Result<(), GLError> {
+        if self.gbuffer.size() == (width.max(1), height.max(1)) {
+            return Ok(());
+        }
+        self.gbuffer.resize(width, height)?;
+        self.ssao.resize(width, height)?;
+        self.attach_outputs()
+    }
+
+    pub fn render(
+        &mut self,
+        camera: &Camera,
+        lights: &[Light],
+        queue: &mut RenderQueue,
+        output: Option<&RenderTarget>,
+    ) -> Result<RenderStats, GLError> {
+        let width = self.gl.drawing_buffer_width();
+        let height = self.gl.drawing_buffer_height();
+        self.resize(width, height)?;
+
+        let geometry_stats = {
+            let binded = self.gbuffer.bind();
+            for draw_buffer in 0..4 {
+                binded.clear_color(draw_buffer, [0.0; 4]);
+            }
+            binded.clear_depth(camera.projection().depth_clear_value());
+            queue.submit_opaque(camera)
+        };
+
+        let occlusion = if self.ssao_enabled {
+            self.ssao.render(
+                &self.context,
+                camera,
+                self.gbuffer.position(),
+                self.gbuffer.normal(),
+            );
+            Rc::clone(self.ssao.occlusion())
+        } else {
+            Rc::clone(&self.no_occlusion)
+        };
+        self.global.set(OCCLUSION_UNIFORM, occlusion);
+
+        let eye_position = camera
+            .view_inverse()
+            .map_or_else(Vec3::zeros, |inverse| inverse.column(3).xyz());
+        self.global.set(EYE_POSITION_UNIFORM, eye_position);
+        self.point.set(EYE_POSITION_UNIFORM, eye_position);
+
+        let (point_lights, global_lights): (Vec<Light>, Vec<Light>) = lights
+            .iter()
+            .copied()
+            .partition(|light| matches!(light.kind, LightKind::Point { .. }));
+        upload_lights(&mut self.lights, &global_lights);
+        self.lights.bind();
+
+        let transparent_stats = {
+            let _binded = self.accumulation.bind();
+            self.gl.viewport(0, 0, width, height);
+            self.gl.clear(gl::COLOR_BUFFER_BIT);
+            self.context.draw_quad(&self.global);
+
+            self.render_point_lights(camera, &point_lights);
+
+            upload_lights(&mut self.lights, lights);
+            queue.submit_transparent(camera)
+        };
+
+        PostContext::set_input(&mut self.copy, &self.output);
+        self.context.draw(&self.copy, output);
+        Ok(geometry_stats.merge(&transparent_stats))
+    }
+
+    fn render_point_lights(&self, camera: &Camera, point_lights: &[Light]) {
+        let material_use = self.point.bind_for(camera);
+        let shader_use = material_use.shader_use();
+        let camera = camera.with_model(Mat4x4::identity());
+        for light in point_lights {
+            let range = light.range().unwrap_or_default();
+            material_use.set_uniform(&self.u_light_position, &light.position);
+            material_use.set_uniform(&self.u_light_color, &(light.color * light.intensity));
+            material_use.set_uniform(&self.u_light_range, &range);
+            self.volume.draw(shader_use, &camera);
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::rc::Rc;
+
+use crate::gl;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::framebuffers::binded_framebuffer_ctx::BindedFramebufferCtx;
+use crate::gl::framebuffers::framebuffer::Framebuffer;
+use crate::gl::framebuffers::renderbuffer::Renderbuffer;
+use crate::gl::textures::texture::{Texture, TextureFormat};
+
+pub const GBUFFER_ATTACHMENTS: [u32; 4] = [
+    gl::COLOR_ATTACHMENT0,
+    gl::COLOR_ATTACHMENT1,
+    gl::COLOR_ATTACHMENT2,
+    gl::COLOR_ATTACHMENT3,
+];
+
+const GBUFFER_FORMATS: [TextureFormat; 4] = [
+    TextureFormat::RGBA32F,
+    TextureFormat::RGBA16F,
+    TextureFormat::RGBA8,
+    TextureFormat::RGBA8,
+];
+
+pub struct GBuffer {
+    framebuffer: Framebuffer,
+    textures: Vec<Rc<Texture>>,
+    depth: Renderbuffer,
+    size: (i32, i32),
+    gl: Rc<GL>,
+}
+
+impl GBuffer {
+    pub fn try_new(gl: &Rc<GL>, size: (i32, i32)) -> Result<Self, GLError> {
+        let (width, height) = (size.0.max(1), size.1.max(1));
+        let mut gbuffer = Self {
+            framebuffer: Framebuffer::try_new(gl)?,
+            textures: vec![],
+            depth: Renderbuffer::try_new(gl, gl::DEPTH_COMPONENT24, width, height)?,
+            size: (0, 0),
+            gl: Rc::clone(gl),
+        };
+        gbuffer.resize(width, height)?;
+        Ok(gbuffer)
+    }
+
+    pub fn size(&self) -> (i32, i32) {
+        self.size
+    }
+
+    pub fn position(&self) -> &Rc<Texture> {
+        &self.textures[0]
+    }
+
+    pub fn normal(&self) -> &Rc<Texture> {
+        &self.textures[1]
+    }
+
+    pub fn albedo(&self) -> &Rc<Texture> {
+        &self.textures[2]
+    }
+
+    pub fn ambient(&self) -> &Rc<Texture> {
+        &self.textures[3]
+    }
+
+    pub fn depth(&self) -> &Renderbuffer {
+        &self.depth
+    }
+
+    pub fn resize(&mut self, width: i32, height: i32) -> Result<(), GLError> {
+        let (width, height) = (width.max(1), height.max(1));
+        if self.size == (width, height) {
+            return Ok(());
+        }
+        self.size = (width, height);
+
+        self.textures = GBUFFER_FORMATS
+            .iter()
+            .map(|&format| {
+                let texture = Texture::new_2d(&self.gl, format, width, height)?;
+                texture.set_filter(gl::NEAREST, gl::NEAREST);
+                Ok(Rc::new(texture))
+            })
+            .collect::<Result<_, GLError>>()?;
+        self.depth.resize(width, height);
+
+        let binded = self.framebuffer.bind();
+        for (&attachment, texture) in GBUFFER_ATTACHMENTS.iter().zip(self.textures.iter()) {
+            binded.attach_texture(attachment, texture, gl::TEXTURE_2D, 0);
+        }
+        binded.attach_renderbuffer(gl::DEPTH_ATTACHMENT, &self.depth);
+        binded.check_status()?;
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn bind(&self) -> BindedFramebufferCtx<'_> {
+        let binded = self.framebuffer.bind();
+        binded.set_draw_buffers(&GBUFFER_ATTACHMENTS);
+        self.gl.viewport(0, 0, self.size.0, self.size.1);
+        binded
+    }
+}
@@ -0,0 +1,47 @@
+use std::f32::consts::PI;
+use std::rc::Rc;
+
+use na::Matrix3xX;
+
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::mesh::Mesh;
+
+const VOLUME_SEGMENTS: usize = 16;
+const VOLUME_RINGS: usize = 8;
+
+pub fn build_light_volume(gl: &Rc<GL>) -> Result<Mesh, GLError> {
+    let scale =
+        1.0 / ((PI / VOLUME_SEGMENTS as f32).cos() * (PI / (2 * VOLUME_RINGS) as f32).cos());
+
+    let mut vertices = Vec::with_capacity((VOLUME_RINGS + 1) * (VOLUME_SEGMENTS + 1) * 3);
+    for ring in 0..=VOLUME_RINGS {
+        let theta = PI * ring as f32 / VOLUME_RINGS as f32;
+        for segment in 0..=VOLUME_SEGMENTS {
+            let phi = 2.0 * PI * segment as f32 / VOLUME_SEGMENTS as f32;
+            vertices.extend_from_slice(&[
+                theta.sin() * phi.cos() * scale,
+                theta.cos() * scale,
+                theta.sin() * phi.sin() * scale,
+            ]);
+        }
+    }
+
+    let columns = VOLUME_SEGMENTS + 1;
+    let mut faces = Vec::with_capacity(VOLUME_RINGS * VOLUME_SEGMENTS * 6);
+    for ring in 0..VOLUME_RINGS {
+        for segment in 0..VOLUME_SEGMENTS {
+            let a = (ring * columns + segment) as i32;
+            let b = a + columns as i32;
+            let c = b + 1;
+            let d = a + 1;
+            faces.extend_from_slice(&[a, c, b, a, d, c]);
+        }
+    }
+
+    Mesh::new_builder(
+        Matrix3xX::from_column_slice(&vertices),
+        Matrix3xX::from_column_slice(&faces),
+    )
+    .build(gl)
+}
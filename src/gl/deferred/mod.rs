@@ -0,0 +1,5 @@
+pub mod deferred_renderer;
+pub mod error;
+pub mod g_buffer;
+pub mod light_volume;
+pub mod shaders;
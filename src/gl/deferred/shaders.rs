@@ -0,0 +1,165 @@
+use crate::gl::lighting::shaders::{lights_block, RANGE_ATTENUATION};
+
+pub const GEOMETRY_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in vec3 v_position;
+    in vec3 v_normal;
+
+    struct material {
+        vec3 diffuse_color;
+        vec3 specular_color;
+        vec3 ambient_color;
+        float shininess;
+    };
+
+    uniform material u_material;
+
+    layout(location = 0) out vec4 out_position;
+    layout(location = 1) out vec4 out_normal;
+    layout(location = 2) out vec4 out_albedo;
+    layout(location = 3) out vec4 out_ambient;
+
+    void main() {
+        vec3 normal = normalize(v_normal);
+        if (!gl_FrontFacing) {
+            normal = -normal;
+        }
+        vec3 specular = u_material.specular_color;
+
+        out_position = vec4(v_position, 1.0);
+        out_normal = vec4(normal, u_material.shininess);
+        out_albedo = vec4(u_material.diffuse_color, max(specular.r, max(specular.g, specular.b)));
+        out_ambient = vec4(u_material.ambient_color * u_material.diffuse_color, 1.0);
+    }
+"##;
+
+pub const LIGHT_VOLUME_VERTEX_SHADER: &str = r##"#version 300 es
+    in vec3 a_position;
+
+    struct camera {
+        mat4 model;
+        mat4 view;
+        mat4 projection;
+    };
+
+    uniform camera u_camera;
+    uniform vec3 u_light_position;
+    uniform float u_light_range;
+
+    void main() {
+        vec3 world_position = u_light_position + a_position * u_light_range;
+        gl_Position = u_camera.projection * u_camera.view * u_camera.model * vec4(world_position, 1.0);
+    }
+"##;
+
+const GBUFFER_BLOCK: &str = r##"
+    uniform highp sampler2D u_gbuffer_position;
+    uniform highp sampler2D u_gbuffer_normal;
+    uniform highp sampler2D u_gbuffer_albedo;
+    uniform highp sampler2D u_gbuffer_ambient;
+    uniform vec3 u_eye_position;
+
+    struct surface {
+        vec3 position;
+        vec3 normal;
+        float shininess;
+        vec3 diffuse;
+        float specular;
+        vec3 ambient;
+        bool covered;
+    };
+
+    surface read_surface() {
+        ivec2 coords = ivec2(gl_FragCoord.xy);
+        vec4 position = texelFetch(u_gbuffer_position, coords, 0);
+        vec4 normal = texelFetch(u_gbuffer_normal, coords, 0);
+        vec4 albedo = texelFetch(u_gbuffer_albedo, coords, 0);
+        vec4 ambient = texelFetch(u_gbuffer_ambient, coords, 0);
+        return surface(
+            position.xyz,
+            normalize(normal.xyz),
+            normal.w,
+            albedo.rgb,
+            albedo.a,
+            ambient.rgb,
+            position.w > 0.5
+        );
+    }
+
+    vec3 shade(surface s, vec3 light_dir, vec3 radiance) {
+        vec3 view_dir = normalize(u_eye_position - s.position);
+        float diffuse = max(dot(s.normal, light_dir), 0.0);
+        vec3 half_dir = normalize(light_dir + view_dir);
+        float specular = diffuse > 0.0
+            ? pow(max(dot(s.normal, half_dir), 0.0), s.shininess)
+            : 0.0;
+        return radiance * (s.diffuse * diffuse + vec3(s.specular * specular));
+    }
+"##;
+
+const GLOBAL_LIGHTING_BODY: &str = r##"
+    uniform sampler2D u_occlusion;
+
+    out vec4 out_color;
+
+    void main() {
+        surface s = read_surface();
+        if (!s.covered) {
+            discard;
+        }
+
+        vec2 uv = gl_FragCoord.xy / vec2(textureSize(u_gbuffer_position, 0));
+        vec3 color = s.ambient * texture(u_occlusion, uv).r;
+        for (int i = 0; i < light_count(); ++i) {
+            light_data light = u_lights[i];
+
+            float attenuation;
+            vec3 light_dir = light_direction(light, s.position, attenuation);
+            vec3 radiance = light.color.rgb * light.color.w * attenuation;
+            color += shade(s, light_dir, radiance);
+        }
+        out_color = vec4(color, 1.0);
+    }
+"##;
+
+const POINT_LIGHT_BODY: &str = r##"
+    uniform vec3 u_light_position;
+    uniform vec3 u_light_color;
+    uniform float u_light_range;
+
+    out vec4 out_color;
+
+    void main() {
+        surface s = read_surface();
+        if (!s.covered) {
+            discard;
+        }
+
+        vec3 to_light = u_light_position - s.position;
+        float distance = length(to_light);
+        if (distance > u_light_range) {
+            discard;
+        }
+
+        vec3 light_dir = to_light / max(distance, 0.0001);
+        vec3 radiance = u_light_color * range_attenuation(distance, u_light_range);
+        out_color = vec4(shade(s, light_dir, radiance), 1.0);
+    }
+"##;
+
+pub fn global_lighting_fragment_shader(max_lights: usize) -> String {
+    format!(
+        "#version 300 es\nprecision highp float;\n{}{}{}",
+        lights_block(max_lights, None),
+        GBUFFER_BLOCK,
+        GLOBAL_LIGHTING_BODY
+    )
+}
+
+pub fn point_light_fragment_shader() -> String {
+    format!(
+        "#version 300 es\nprecision highp float;\n{}{}{}",
+        RANGE_ATTENUATION, GBUFFER_BLOCK, POINT_LIGHT_BODY
+    )
+}
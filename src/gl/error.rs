@@ -3,10 +3,32 @@ use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum GLError {
+    #[error(transparent)]
+    AnimationError(#[from] crate::animation::error::AnimationError),
     #[error(transparent)]
     GLObjectError(#[from] super::buffers::error::GLObjectError),
     #[error(transparent)]
+    CaptureError(#[from] crate::capture::error::CaptureError),
+    #[error(transparent)]
+    DeferredError(#[from] super::deferred::error::DeferredError),
+    #[error(transparent)]
+    FramebufferError(#[from] super::framebuffers::error::FramebufferError),
+    #[error(transparent)]
+    IblError(#[from] super::ibl::error::IblError),
+    #[error(transparent)]
+    MeshProcessingError(#[from] crate::math::mesh_processing::error::MeshProcessingError),
+    #[error(transparent)]
+    MorphError(#[from] super::morph::error::MorphError),
+    #[error(transparent)]
+    PostProcessError(#[from] super::postprocess::error::PostProcessError),
+    #[error(transparent)]
     ShaderError(#[from] super::shader::error::ShaderError),
+    #[error(transparent)]
+    SkinningError(#[from] super::skinning::error::SkinningError),
+    #[error(transparent)]
+    SkyboxError(#[from] super::skybox::error::SkyboxError),
+    #[error(transparent)]
+    TextureError(#[from] super::textures::error::TextureError),
     #[error("Cant't get webgl2 context. Value = {:?}", .0)]
     GL2ContextError(Object),
 }
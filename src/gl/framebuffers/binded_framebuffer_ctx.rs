@@ -0,0 +1,104 @@
+use crate::gl;
+use crate::gl::core::instance::GL;
+
+use super::error::FramebufferError;
+use super::framebuffer::Framebuffer;
+use super::renderbuffer::Renderbuffer;
+use crate::gl::textures::texture::Texture;
+
+pub struct BindedFramebufferCtx<'a> {
+    gl: &'a GL,
+}
+
+impl<'a> BindedFramebufferCtx<'a> {
+    pub fn new(gl: &'a GL, framebuffer: &'a Framebuffer) -> Self {
+        gl.bind_framebuffer(gl::FRAMEBUFFER, Some(framebuffer.as_gl_framebuffer()));
+        Self { gl }
+    }
+
+    pub fn attach_renderbuffer(&self, attachment: u32, renderbuffer: &Renderbuffer) {
+        self.gl.framebuffer_renderbuffer(
+            gl::FRAMEBUFFER,
+            attachment,
+            gl::RENDERBUFFER,
+            Some(renderbuffer.as_gl_renderbuffer()),
+        );
+    }
+
+    pub fn attach_texture(&self, attachment: u32, texture: &Texture, face: u32, level: i32) {
+        self.gl.framebuffer_texture_2d(
+            gl::FRAMEBUFFER,
+            attachment,
+            face,
+            Some(texture.as_gl_texture()),
+            level,
+        );
+    }
+
+    pub fn attach_texture_layer(&self, attachment: u32, texture: &Texture, layer: i32) {
+        self.gl.framebuffer_texture_layer(
+            gl::FRAMEBUFFER,
+            attachment,
+            Some(texture.as_gl_texture()),
+            0,
+            layer,
+        );
+    }
+
+    pub fn detach(&self, attachment: u32) {
+        self.gl
+            .framebuffer_texture_2d(gl::FRAMEBUFFER, attachment, gl::TEXTURE_2D, None, 0);
+    }
+
+    pub fn set_draw_buffers(&self, attachments: &[u32]) {
+        let buffers = attachments
+            .iter()
+            .map(|&attachment| wasm_bindgen::JsValue::from(attachment))
+            .collect::<js_sys::Array>();
+        self.gl.draw_buffers(&buffers);
+    }
+
+    pub fn check_status(&self) -> Result<(), FramebufferError> {
+        match self.gl.check_framebuffer_status(gl::FRAMEBUFFER) {
+            gl::FRAMEBUFFER_COMPLETE => Ok(()),
+            status => Err(FramebufferError::IncompleteError(status)),
+        }
+    }
+
+    pub fn clear_color_uint(&self, draw_buffer: i32, value: u32) {
+        self.gl
+            .clear_bufferuiv_with_u32_array(gl::COLOR, draw_buffer, &[value, 0, 0, 0]);
+    }
+
+    pub fn clear_color(&self, draw_buffer: i32, value: [f32; 4]) {
+        self.gl
+            .clear_bufferfv_with_f32_array(gl::COLOR, draw_buffer, &value);
+    }
+
+    pub fn clear_depth(&self, value: f32) {
+        self.gl
+            .clear_bufferfv_with_f32_array(gl::DEPTH, 0, &[value]);
+    }
+
+    pub fn read_pixel_uint(&self, x: i32, y: i32) -> Result<u32, FramebufferError> {
+        let pixel = js_sys::Uint32Array::new_with_length(4);
+        self.gl
+            .read_pixels_with_opt_array_buffer_view(
+                x,
+                y,
+                1,
+                1,
+                gl::RGBA_INTEGER,
+                gl::UNSIGNED_INT,
+                Some(&pixel),
+            )
+            .map_err(FramebufferError::ReadPixelsError)?;
+        Ok(pixel.get_index(0))
+    }
+}
+
+impl Drop for BindedFramebufferCtx<'_> {
+    fn drop(&mut self) {
+        self.gl.bind_framebuffer(gl::FRAMEBUFFER, None);
+    }
+}
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum FramebufferError {
+    #[error("Can't create framebuffer")]
+    CreateFramebufferError,
+    #[error("Can't create renderbuffer")]
+    CreateRenderbufferError,
+    #[error("Framebuffer is incomplete. Status = {0:#x}")]
+    IncompleteError(u32),
+    #[error("Can't read pixels from framebuffer. Value = {:?}", .0)]
+    ReadPixelsError(wasm_bindgen::JsValue),
+}
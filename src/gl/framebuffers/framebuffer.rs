@@ -0,0 +1,38 @@
+use std::rc::Rc;
+
+use web_sys::WebGlFramebuffer;
+
+use crate::gl::core::instance::GL;
+
+use super::binded_framebuffer_ctx::BindedFramebufferCtx;
+use super::error::FramebufferError::{self, CreateFramebufferError};
+
+pub struct Framebuffer {
+    framebuffer: WebGlFramebuffer,
+    gl: Rc<GL>,
+}
+
+impl Framebuffer {
+    pub fn try_new(gl: &Rc<GL>) -> Result<Self, FramebufferError> {
+        let framebuffer = gl.create_framebuffer().ok_or(CreateFramebufferError)?;
+        Ok(Self {
+            framebuffer,
+            gl: Rc::clone(gl),
+        })
+    }
+
+    pub fn as_gl_framebuffer(&self) -> &WebGlFramebuffer {
+        &self.framebuffer
+    }
+
+    #[must_use]
+    pub fn bind(&self) -> BindedFramebufferCtx<'_> {
+        BindedFramebufferCtx::new(self.gl.as_ref(), self)
+    }
+}
+
+impl Drop for Framebuffer {
+    fn drop(&mut self) {
+        self.gl.delete_framebuffer(Some(&self.framebuffer));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod binded_framebuffer_ctx;
+pub mod error;
+pub mod framebuffer;
+pub mod renderbuffer;
@@ -0,0 +1,68 @@
+use std::rc::Rc;
+
+use web_sys::WebGlRenderbuffer;
+
+use crate::gl;
+use crate::gl::core::instance::GL;
+
+use super::error::FramebufferError::{self, CreateRenderbufferError};
+
+pub struct Renderbuffer {
+    renderbuffer: WebGlRenderbuffer,
+    internal_format: u32,
+    width: i32,
+    height: i32,
+    gl: Rc<GL>,
+}
+
+impl Renderbuffer {
+    pub fn try_new(
+        gl: &Rc<GL>,
+        internal_format: u32,
+        width: i32,
+        height: i32,
+    ) -> Result<Self, FramebufferError> {
+        let renderbuffer = gl.create_renderbuffer().ok_or(CreateRenderbufferError)?;
+        let mut result = Self {
+            renderbuffer,
+            internal_format,
+            width: 0,
+            height: 0,
+            gl: Rc::clone(gl),
+        };
+        result.resize(width, height);
+        Ok(result)
+    }
+
+    pub fn as_gl_renderbuffer(&self) -> &WebGlRenderbuffer {
+        &self.renderbuffer
+    }
+
+    pub fn size(&self) -> (i32, i32) {
+        (self.width, self.height)
+    }
+
+    pub fn resize(&mut self, width: i32, height: i32) {
+        if (self.width, self.height) == (width, height) {
+            return;
+        }
+        self.width = width;
+        self.height = height;
+
+        self.gl
+            .bind_renderbuffer(gl::RENDERBUFFER, Some(&self.renderbuffer));
+        self.gl.renderbuffer_storage(
+            gl::RENDERBUFFER,
+            self.internal_format,
+            width.max(1),
+            height.max(1),
+        );
+        self.gl.bind_renderbuffer(gl::RENDERBUFFER, None);
+    }
+}
+
+impl Drop for Renderbuffer {
+    fn drop(&mut self) {
+        self.gl.delete_renderbuffer(Some(&self.renderbuffer));
+    }
+}
@@ -0,0 +1,83 @@
+use std::rc::Rc;
+
+use glm::Vec3;
+
+use crate::gl;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::framebuffers::framebuffer::Framebuffer;
+use crate::gl::materials::material::Material;
+use crate::gl::mesh::Mesh;
+use crate::gl::screen_quad::{build_screen_quad, build_unit_cube};
+use crate::gl::shader::program::ShaderProgram;
+use crate::gl::shader::shader_type::ShaderType;
+use crate::gl::textures::texture::Texture;
+
+use super::cube_faces::{cube_face_camera, cube_face_target, CUBE_FACES_COUNT};
+use super::error::IblError::MissingExtension;
+
+const COLOR_BUFFER_FLOAT_EXTENSION: &str = "EXT_color_buffer_float";
+
+pub struct EnvironmentBaker {
+    framebuffer: Framebuffer,
+    cube: Mesh,
+    quad: Mesh,
+    gl: Rc<GL>,
+}
+
+impl EnvironmentBaker {
+    pub fn try_new(gl: &Rc<GL>) -> Result<Self, GLError> {
+        if !gl.enable_extension(COLOR_BUFFER_FLOAT_EXTENSION) {
+            return Err(MissingExtension(COLOR_BUFFER_FLOAT_EXTENSION).into());
+        }
+        Ok(Self {
+            framebuffer: Framebuffer::try_new(gl)?,
+            cube: build_unit_cube(gl)?,
+            quad: build_screen_quad(gl)?,
+            gl: Rc::clone(gl),
+        })
+    }
+
+    pub fn program(&self, vertex: &str, fragment: String) -> Result<Rc<ShaderProgram>, GLError> {
+        Ok(Rc::new(
+            ShaderProgram::builder(&self.gl)
+                .add_source(ShaderType::Vertex, vertex.into())
+                .add_source(ShaderType::Fragment, fragment)
+                .build()?,
+        ))
+    }
+
+    pub fn render_cube(
+        &self,
+        target: &Texture,
+        level: i32,
+        material: &Material,
+    ) -> Result<(), GLError> {
+        let (width, height) = target.level_size(level);
+        let binded = self.framebuffer.bind();
+        self.gl.viewport(0, 0, width, height);
+
+        let material_use = material.bind();
+        for face in 0..CUBE_FACES_COUNT {
+            binded.attach_texture(gl::COLOR_ATTACHMENT0, target, cube_face_target(face), level);
+            binded.check_status()?;
+            let camera = cube_face_camera(face, &Vec3::zeros(), 0.1, 10.0);
+            self.cube.draw(material_use.shader_use(), &camera);
+        }
+        binded.detach(gl::COLOR_ATTACHMENT0);
+        Ok(())
+    }
+
+    pub fn render_quad(&self, target: &Texture, material: &Material) -> Result<(), GLError> {
+        let (width, height) = target.size();
+        let binded = self.framebuffer.bind();
+        self.gl.viewport(0, 0, width, height);
+
+        binded.attach_texture(gl::COLOR_ATTACHMENT0, target, gl::TEXTURE_2D, 0);
+        binded.check_status()?;
+        let material_use = material.bind();
+        self.quad.draw_geometry(material_use.shader_use());
+        binded.detach(gl::COLOR_ATTACHMENT0);
+        Ok(())
+    }
+}
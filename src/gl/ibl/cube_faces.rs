@@ -0,0 +1,34 @@
+use std::f32::consts::FRAC_PI_2;
+
+use glm::Vec3;
+
+use crate::gl;
+use crate::gl::camera::projection::Projection;
+use crate::gl::camera::Camera;
+
+pub const CUBE_FACES_COUNT: u32 = 6;
+
+const CUBE_FACE_DIRECTIONS: [([f32; 3], [f32; 3]); CUBE_FACES_COUNT as usize] = [
+    ([1.0, 0.0, 0.0], [0.0, -1.0, 0.0]),
+    ([-1.0, 0.0, 0.0], [0.0, -1.0, 0.0]),
+    ([0.0, 1.0, 0.0], [0.0, 0.0, 1.0]),
+    ([0.0, -1.0, 0.0], [0.0, 0.0, -1.0]),
+    ([0.0, 0.0, 1.0], [0.0, -1.0, 0.0]),
+    ([0.0, 0.0, -1.0], [0.0, -1.0, 0.0]),
+];
+
+pub fn cube_face_target(face: u32) -> u32 {
+    gl::TEXTURE_CUBE_MAP_POSITIVE_X + face
+}
+
+pub fn cube_face_camera(face: u32, position: &Vec3, near: f32, far: f32) -> Camera {
+    let (forward, up) = CUBE_FACE_DIRECTIONS[face as usize];
+    let mut camera = Camera::new(Projection::Perspective {
+        fov_y: FRAC_PI_2,
+        near,
+        far,
+    });
+    camera.set_viewport(1, 1);
+    camera.view = glm::look_at(position, &(position + Vec3::from(forward)), &Vec3::from(up));
+    camera
+}
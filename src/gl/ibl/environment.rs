@@ -0,0 +1,182 @@
+use std::rc::Rc;
+
+use crate::gl;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::materials::material::Material;
+use crate::gl::materials::pipeline_state::PipelineState;
+use crate::gl::screen_quad::SCREEN_QUAD_VERTEX_SHADER;
+use crate::gl::textures::texture::{Texture, TextureFormat};
+
+use super::baker::EnvironmentBaker;
+use super::hdr_image::HdrImage;
+use super::shaders::{
+    brdf_lut_fragment_shader, prefilter_fragment_shader, CUBE_CAPTURE_VERTEX_SHADER,
+    EQUIRECT_TO_CUBE_FRAGMENT_SHADER, IRRADIANCE_FRAGMENT_SHADER,
+};
+
+const HDR_UPLOAD_FORMAT: TextureFormat = TextureFormat::new(gl::RGBA16F, gl::RGBA, gl::FLOAT);
+const IRRADIANCE_SOURCE_SIZE: f32 = 64.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnvironmentSettings {
+    pub cube_size: i32,
+    pub irradiance_size: i32,
+    pub prefiltered_size: i32,
+    pub prefiltered_levels: i32,
+    pub brdf_lut_size: i32,
+}
+
+impl Default for EnvironmentSettings {
+    fn default() -> Self {
+        Self {
+            cube_size: 512,
+            irradiance_size: 32,
+            prefiltered_size: 128,
+            prefiltered_levels: 5,
+            brdf_lut_size: 256,
+        }
+    }
+}
+
+pub struct Environment {
+    cubemap: Rc<Texture>,
+    irradiance: Rc<Texture>,
+    prefiltered: Rc<Texture>,
+    brdf_lut: Rc<Texture>,
+}
+
+impl Environment {
+    pub fn bake(
+        gl: &Rc<GL>,
+        image: &HdrImage,
+        settings: &EnvironmentSettings,
+    ) -> Result<Self, GLError> {
+        let baker = EnvironmentBaker::try_new(gl)?;
+
+        let cubemap = Rc::new(bake_cubemap(gl, &baker, image, settings.cube_size)?);
+        let irradiance = Rc::new(bake_irradiance(gl, &baker, &cubemap, settings)?);
+        let prefiltered = Rc::new(bake_prefiltered(gl, &baker, &cubemap, settings)?);
+        let brdf_lut = Rc::new(bake_brdf_lut(gl, &baker, settings.brdf_lut_size)?);
+
+        Ok(Self {
+            cubemap,
+            irradiance,
+            prefiltered,
+            brdf_lut,
+        })
+    }
+
+    pub fn cubemap(&self) -> &Rc<Texture> {
+        &self.cubemap
+    }
+
+    pub fn irradiance(&self) -> &Rc<Texture> {
+        &self.irradiance
+    }
+
+    pub fn prefiltered(&self) -> &Rc<Texture> {
+        &self.prefiltered
+    }
+
+    pub fn brdf_lut(&self) -> &Rc<Texture> {
+        &self.brdf_lut
+    }
+
+    pub fn max_reflection_lod(&self) -> f32 {
+        (self.prefiltered.levels() - 1) as f32
+    }
+}
+
+fn cube_texture(gl: &Rc<GL>, size: i32, levels: i32) -> Result<Texture, GLError> {
+    Ok(Texture::with_storage(
+        gl,
+        gl::TEXTURE_CUBE_MAP,
+        TextureFormat::RGBA16F,
+        (size, size),
+        levels,
+    )?)
+}
+
+pub fn bake_cubemap(
+    gl: &Rc<GL>,
+    baker: &EnvironmentBaker,
+    image: &HdrImage,
+    size: i32,
+) -> Result<Texture, GLError> {
+    let (width, height) = (image.width() as i32, image.height() as i32);
+    let equirect =
+        Texture::with_storage(gl, gl::TEXTURE_2D, HDR_UPLOAD_FORMAT, (width, height), 1)?;
+    equirect.sub_upload_f32(gl::TEXTURE_2D, 0, width, height, image.pixels())?;
+    equirect.set_wrap(gl::REPEAT, gl::CLAMP_TO_EDGE);
+
+    let program = baker.program(
+        CUBE_CAPTURE_VERTEX_SHADER,
+        EQUIRECT_TO_CUBE_FRAGMENT_SHADER.into(),
+    )?;
+    let material = Material::new(gl, &program)
+        .with_param("u_equirect", Rc::new(equirect))
+        .with_state(PipelineState::fullscreen());
+
+    let cubemap = cube_texture(gl, size, Texture::mip_levels_for(size))?;
+    baker.render_cube(&cubemap, 0, &material)?;
+    cubemap.generate_mipmaps();
+    Ok(cubemap)
+}
+
+fn bake_irradiance(
+    gl: &Rc<GL>,
+    baker: &EnvironmentBaker,
+    cubemap: &Rc<Texture>,
+    settings: &EnvironmentSettings,
+) -> Result<Texture, GLError> {
+    let program = baker.program(
+        CUBE_CAPTURE_VERTEX_SHADER,
+        IRRADIANCE_FRAGMENT_SHADER.into(),
+    )?;
+    let source_lod = (settings.cube_size as f32 / IRRADIANCE_SOURCE_SIZE)
+        .log2()
+        .max(0.0);
+    let material = Material::new(gl, &program)
+        .with_param("u_environment", Rc::clone(cubemap))
+        .with_param("u_source_lod", source_lod)
+        .with_state(PipelineState::fullscreen());
+
+    let irradiance = cube_texture(gl, settings.irradiance_size, 1)?;
+    baker.render_cube(&irradiance, 0, &material)?;
+    Ok(irradiance)
+}
+
+fn bake_prefiltered(
+    gl: &Rc<GL>,
+    baker: &EnvironmentBaker,
+    cubemap: &Rc<Texture>,
+    settings: &EnvironmentSettings,
+) -> Result<Texture, GLError> {
+    let program = baker.program(CUBE_CAPTURE_VERTEX_SHADER, prefilter_fragment_shader())?;
+    let mut material = Material::new(gl, &program)
+        .with_param("u_environment", Rc::clone(cubemap))
+        .with_param("u_source_size", settings.cube_size as f32)
+        .with_state(PipelineState::fullscreen());
+
+    let levels = settings
+        .prefiltered_levels
+        .min(Texture::mip_levels_for(settings.prefiltered_size));
+    let prefiltered = cube_texture(gl, settings.prefiltered_size, levels)?;
+    for level in 0..levels {
+        let roughness = level as f32 / (levels - 1).max(1) as f32;
+        material.set("u_roughness", roughness);
+        baker.render_cube(&prefiltered, level, &material)?;
+    }
+    Ok(prefiltered)
+}
+
+fn bake_brdf_lut(gl: &Rc<GL>, baker: &EnvironmentBaker, size: i32) -> Result<Texture, GLError> {
+    let program = baker.program(SCREEN_QUAD_VERTEX_SHADER, brdf_lut_fragment_shader())?;
+    let material = Material::new(gl, &program).with_state(PipelineState::fullscreen());
+
+    let brdf_lut =
+        Texture::with_storage(gl, gl::TEXTURE_2D, TextureFormat::RG16F, (size, size), 1)?;
+    baker.render_quad(&brdf_lut, &material)?;
+    Ok(brdf_lut)
+}
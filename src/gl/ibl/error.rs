@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum IblError {
+    #[error("WebGL extension is not supported: {}", .0)]
+    MissingExtension(&'static str),
+    #[error("Invalid HDR image: {}", .0)]
+    InvalidHdrImage(String),
+}
@@ -0,0 +1,200 @@
+use glm::Vec3;
+
+use super::error::IblError::{self, InvalidHdrImage};
+
+const RADIANCE_FORMAT: &str = "FORMAT=32-bit_rle_rgbe";
+const RGBE_EXPONENT_BIAS: i32 = 128 + 8;
+const MIN_RLE_WIDTH: usize = 8;
+const MAX_RLE_WIDTH: usize = 0x7FFF;
+
+#[derive(Debug, Clone)]
+pub struct HdrImage {
+    width: usize,
+    height: usize,
+    pixels: Vec<f32>,
+}
+
+impl HdrImage {
+    pub fn new(width: usize, height: usize, pixels: Vec<f32>) -> Self {
+        assert_eq!(
+            pixels.len(),
+            width * height * 4,
+            "Pixel buffer doesn't match image size"
+        );
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[f32] {
+        &self.pixels
+    }
+
+    pub fn from_radiance(bytes: &[u8]) -> Result<Self, IblError> {
+        let mut cursor = 0;
+        let mut has_format = false;
+        loop {
+            let line = read_line(bytes, &mut cursor)?;
+            if line.is_empty() {
+                break;
+            }
+            if line.starts_with("FORMAT=") {
+                if line != RADIANCE_FORMAT {
+                    return Err(InvalidHdrImage(format!("unsupported {}", line)));
+                }
+                has_format = true;
+            }
+        }
+        if !has_format {
+            log::warn!("Radiance header has no FORMAT line, assuming RGBE");
+        }
+
+        let resolution = read_line(bytes, &mut cursor)?;
+        let (width, height) = parse_resolution(&resolution)?;
+
+        let mut pixels = Vec::with_capacity(width * height * 4);
+        let mut scanline = vec![0u8; width * 4];
+        for _ in 0..height {
+            read_scanline(bytes, &mut cursor, &mut scanline)?;
+            for rgbe in scanline.chunks_exact(4) {
+                let [r, g, b] = rgbe_to_rgb([rgbe[0], rgbe[1], rgbe[2], rgbe[3]]);
+                pixels.extend_from_slice(&[r, g, b, 1.0]);
+            }
+        }
+        Ok(Self::new(width, height, pixels))
+    }
+
+    pub fn gradient_sky(width: usize, height: usize, sun_direction: &Vec3) -> Self {
+        let sun_direction = sun_direction.normalize();
+        let zenith = Vec3::new(0.15, 0.35, 0.9);
+        let horizon = Vec3::new(0.9, 0.85, 0.8);
+        let ground = Vec3::new(0.25, 0.22, 0.2);
+        let sun = Vec3::new(40.0, 36.0, 30.0);
+
+        let mut pixels = Vec::with_capacity(width * height * 4);
+        for row in 0..height {
+            let latitude = (0.5 - (row as f32 + 0.5) / height as f32) * std::f32::consts::PI;
+            for column in 0..width {
+                let longitude =
+                    ((column as f32 + 0.5) / width as f32 - 0.5) * std::f32::consts::TAU;
+                let direction = Vec3::new(
+                    latitude.cos() * longitude.cos(),
+                    latitude.sin(),
+                    latitude.cos() * longitude.sin(),
+                );
+
+                let color = if direction.y >= 0.0 {
+                    horizon.lerp(&zenith, direction.y.sqrt())
+                } else {
+                    horizon.lerp(&ground, (-direction.y * 4.0).min(1.0))
+                };
+                let sun_amount = direction.dot(&sun_direction).max(0.0).powf(512.0);
+                let color = color + sun * sun_amount;
+                pixels.extend_from_slice(&[color.x, color.y, color.z, 1.0]);
+            }
+        }
+        Self::new(width, height, pixels)
+    }
+}
+
+fn rgbe_to_rgb(rgbe: [u8; 4]) -> [f32; 3] {
+    if rgbe[3] == 0 {
+        return [0.0; 3];
+    }
+    let scale = 2f32.powi(rgbe[3] as i32 - RGBE_EXPONENT_BIAS);
+    [
+        (rgbe[0] as f32 + 0.5) * scale,
+        (rgbe[1] as f32 + 0.5) * scale,
+        (rgbe[2] as f32 + 0.5) * scale,
+    ]
+}
+
+fn read_line(bytes: &[u8], cursor: &mut usize) -> Result<String, IblError> {
+    let rest = &bytes[*cursor..];
+    let end = rest
+        .iter()
+        .position(|&byte| byte == b'\n')
+        .ok_or_else(|| InvalidHdrImage("unexpected end of header".into()))?;
+    *cursor += end + 1;
+    Ok(String::from_utf8_lossy(&rest[..end]).trim().to_string())
+}
+
+fn parse_resolution(line: &str) -> Result<(usize, usize), IblError> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    match parts.as_slice() {
+        ["-Y", height, "+X", width] => {
+            let parse = |value: &str| {
+                value
+                    .parse::<usize>()
+                    .map_err(|_| InvalidHdrImage(format!("bad resolution {}", line)))
+            };
+            Ok((parse(width)?, parse(height)?))
+        }
+        _ => Err(InvalidHdrImage(format!("unsupported orientation {}", line))),
+    }
+}
+
+fn take<'a>(bytes: &'a [u8], cursor: &mut usize, count: usize) -> Result<&'a [u8], IblError> {
+    let end = *cursor + count;
+    let slice = bytes
+        .get(*cursor..end)
+        .ok_or_else(|| InvalidHdrImage("unexpected end of pixel data".into()))?;
+    *cursor = end;
+    Ok(slice)
+}
+
+fn read_scanline(bytes: &[u8], cursor: &mut usize, scanline: &mut [u8]) -> Result<(), IblError> {
+    let width = scanline.len() / 4;
+    let start = *cursor;
+    let header = take(bytes, cursor, 4)?;
+    let is_rle = (MIN_RLE_WIDTH..=MAX_RLE_WIDTH).contains(&width)
+        && header[0] == 2
+        && header[1] == 2
+        && header[2] & 0x80 == 0;
+    if !is_rle {
+        *cursor = start;
+        scanline.copy_from_slice(take(bytes, cursor, width * 4)?);
+        return Ok(());
+    }
+    if ((header[2] as usize) << 8 | header[3] as usize) != width {
+        return Err(InvalidHdrImage("scanline width mismatch".into()));
+    }
+
+    for channel in 0..4 {
+        let mut x = 0;
+        while x < width {
+            let count = take(bytes, cursor, 1)?[0] as usize;
+            if count > 128 {
+                let run = count - 128;
+                let value = take(bytes, cursor, 1)?[0];
+                if x + run > width {
+                    return Err(InvalidHdrImage("run overflows scanline".into()));
+                }
+                for offset in 0..run {
+                    scanline[(x + offset) * 4 + channel] = value;
+                }
+                x += run;
+            } else {
+                if count == 0 || x + count > width {
+                    return Err(InvalidHdrImage("bad scanline run".into()));
+                }
+                let values = take(bytes, cursor, count)?;
+                for (offset, &value) in values.iter().enumerate() {
+                    scanline[(x + offset) * 4 + channel] = value;
+                }
+                x += count;
+            }
+        }
+    }
+    Ok(())
+}
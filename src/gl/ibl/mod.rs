@@ -0,0 +1,6 @@
+pub mod baker;
+pub mod cube_faces;
+pub mod environment;
+pub mod error;
+pub mod hdr_image;
+pub mod shaders;
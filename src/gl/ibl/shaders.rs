@@ -0,0 +1,217 @@
+pub const CUBE_CAPTURE_VERTEX_SHADER: &str = r##"#version 300 es
+    in vec3 a_position;
+
+    struct camera {
+        mat4 model;
+        mat4 view;
+        mat4 projection;
+    };
+
+    uniform camera u_camera;
+
+    out vec3 v_direction;
+
+    void main() {
+        v_direction = a_position;
+        mat4 rotation = mat4(mat3(u_camera.view * u_camera.model));
+        gl_Position = u_camera.projection * rotation * vec4(a_position, 1.0);
+    }
+"##;
+
+pub const EQUIRECT_TO_CUBE_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    const float PI = 3.14159265359;
+
+    in vec3 v_direction;
+
+    uniform sampler2D u_equirect;
+
+    out vec4 out_color;
+
+    vec2 equirect_uv(vec3 direction) {
+        float u = atan(direction.z, direction.x) / (2.0 * PI) + 0.5;
+        float v = 0.5 - asin(clamp(direction.y, -1.0, 1.0)) / PI;
+        return vec2(u, v);
+    }
+
+    void main() {
+        vec3 direction = normalize(v_direction);
+        out_color = vec4(texture(u_equirect, equirect_uv(direction)).rgb, 1.0);
+    }
+"##;
+
+pub const IRRADIANCE_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    const float PI = 3.14159265359;
+    const float SAMPLE_DELTA = 0.05;
+
+    in vec3 v_direction;
+
+    uniform samplerCube u_environment;
+    uniform float u_source_lod;
+
+    out vec4 out_color;
+
+    void main() {
+        vec3 normal = normalize(v_direction);
+        vec3 up = abs(normal.y) < 0.999 ? vec3(0.0, 1.0, 0.0) : vec3(1.0, 0.0, 0.0);
+        vec3 right = normalize(cross(up, normal));
+        up = cross(normal, right);
+
+        vec3 irradiance = vec3(0.0);
+        float samples = 0.0;
+        for (float phi = 0.0; phi < 2.0 * PI; phi += SAMPLE_DELTA) {
+            for (float theta = 0.0; theta < 0.5 * PI; theta += SAMPLE_DELTA) {
+                vec3 tangent = vec3(sin(theta) * cos(phi), sin(theta) * sin(phi), cos(theta));
+                vec3 direction = tangent.x * right + tangent.y * up + tangent.z * normal;
+                vec3 radiance = textureLod(u_environment, direction, u_source_lod).rgb;
+                irradiance += radiance * cos(theta) * sin(theta);
+                samples += 1.0;
+            }
+        }
+
+        out_color = vec4(PI * irradiance / samples, 1.0);
+    }
+"##;
+
+const IMPORTANCE_SAMPLING: &str = r##"
+    const float PI = 3.14159265359;
+
+    float radical_inverse(uint bits) {
+        bits = (bits << 16u) | (bits >> 16u);
+        bits = ((bits & 0x55555555u) << 1u) | ((bits & 0xAAAAAAAAu) >> 1u);
+        bits = ((bits & 0x33333333u) << 2u) | ((bits & 0xCCCCCCCCu) >> 2u);
+        bits = ((bits & 0x0F0F0F0Fu) << 4u) | ((bits & 0xF0F0F0F0u) >> 4u);
+        bits = ((bits & 0x00FF00FFu) << 8u) | ((bits & 0xFF00FF00u) >> 8u);
+        return float(bits) * 2.3283064365386963e-10;
+    }
+
+    vec2 hammersley(uint i, uint count) {
+        return vec2(float(i) / float(count), radical_inverse(i));
+    }
+
+    vec3 importance_sample_ggx(vec2 xi, vec3 normal, float roughness) {
+        float a = roughness * roughness;
+        float phi = 2.0 * PI * xi.x;
+        float cos_theta = sqrt((1.0 - xi.y) / (1.0 + (a * a - 1.0) * xi.y));
+        float sin_theta = sqrt(1.0 - cos_theta * cos_theta);
+        vec3 half_vector = vec3(cos(phi) * sin_theta, sin(phi) * sin_theta, cos_theta);
+
+        vec3 up = abs(normal.z) < 0.999 ? vec3(0.0, 0.0, 1.0) : vec3(1.0, 0.0, 0.0);
+        vec3 tangent = normalize(cross(up, normal));
+        vec3 bitangent = cross(normal, tangent);
+        return normalize(tangent * half_vector.x + bitangent * half_vector.y + normal * half_vector.z);
+    }
+
+    float distribution_ggx(float n_dot_h, float roughness) {
+        float a = roughness * roughness;
+        float a2 = a * a;
+        float denominator = n_dot_h * n_dot_h * (a2 - 1.0) + 1.0;
+        return a2 / (PI * denominator * denominator);
+    }
+"##;
+
+const PREFILTER_FRAGMENT_BODY: &str = r##"
+    const uint SAMPLE_COUNT = 256u;
+
+    in vec3 v_direction;
+
+    uniform samplerCube u_environment;
+    uniform float u_roughness;
+    uniform float u_source_size;
+
+    out vec4 out_color;
+
+    void main() {
+        vec3 normal = normalize(v_direction);
+        vec3 view = normal;
+
+        vec3 color = vec3(0.0);
+        float total_weight = 0.0;
+        for (uint i = 0u; i < SAMPLE_COUNT; ++i) {
+            vec2 xi = hammersley(i, SAMPLE_COUNT);
+            vec3 half_vector = importance_sample_ggx(xi, normal, u_roughness);
+            vec3 light = normalize(2.0 * dot(view, half_vector) * half_vector - view);
+
+            float n_dot_l = dot(normal, light);
+            if (n_dot_l > 0.0) {
+                float n_dot_h = max(dot(normal, half_vector), 0.0);
+                float h_dot_v = max(dot(half_vector, view), 0.0);
+                float pdf = distribution_ggx(n_dot_h, u_roughness) * n_dot_h / (4.0 * h_dot_v) + 0.0001;
+
+                float texel_solid_angle = 4.0 * PI / (6.0 * u_source_size * u_source_size);
+                float sample_solid_angle = 1.0 / (float(SAMPLE_COUNT) * pdf + 0.0001);
+                float lod = u_roughness == 0.0 ? 0.0 : 0.5 * log2(sample_solid_angle / texel_solid_angle);
+
+                color += textureLod(u_environment, light, lod).rgb * n_dot_l;
+                total_weight += n_dot_l;
+            }
+        }
+
+        out_color = vec4(color / max(total_weight, 0.0001), 1.0);
+    }
+"##;
+
+const BRDF_LUT_FRAGMENT_BODY: &str = r##"
+    const uint SAMPLE_COUNT = 512u;
+
+    in vec2 v_uv;
+
+    out vec2 out_color;
+
+    float geometry_schlick_ggx(float n_dot_v, float roughness) {
+        float k = roughness * roughness / 2.0;
+        return n_dot_v / (n_dot_v * (1.0 - k) + k);
+    }
+
+    float geometry_smith(float n_dot_v, float n_dot_l, float roughness) {
+        return geometry_schlick_ggx(n_dot_v, roughness) * geometry_schlick_ggx(n_dot_l, roughness);
+    }
+
+    void main() {
+        float n_dot_v = max(v_uv.x, 0.0001);
+        float roughness = v_uv.y;
+
+        vec3 view = vec3(sqrt(1.0 - n_dot_v * n_dot_v), 0.0, n_dot_v);
+        vec3 normal = vec3(0.0, 0.0, 1.0);
+
+        float scale = 0.0;
+        float bias = 0.0;
+        for (uint i = 0u; i < SAMPLE_COUNT; ++i) {
+            vec2 xi = hammersley(i, SAMPLE_COUNT);
+            vec3 half_vector = importance_sample_ggx(xi, normal, roughness);
+            vec3 light = normalize(2.0 * dot(view, half_vector) * half_vector - view);
+
+            float n_dot_l = max(light.z, 0.0);
+            float n_dot_h = max(half_vector.z, 0.0);
+            float v_dot_h = max(dot(view, half_vector), 0.0);
+
+            if (n_dot_l > 0.0) {
+                float g = geometry_smith(n_dot_v, n_dot_l, roughness);
+                float g_vis = g * v_dot_h / (n_dot_h * n_dot_v);
+                float fresnel = pow(1.0 - v_dot_h, 5.0);
+                scale += (1.0 - fresnel) * g_vis;
+                bias += fresnel * g_vis;
+            }
+        }
+
+        out_color = vec2(scale, bias) / float(SAMPLE_COUNT);
+    }
+"##;
+
+fn with_importance_sampling(body: &str) -> String {
+    format!(
+        "#version 300 es\nprecision highp float;\n{}{}",
+        IMPORTANCE_SAMPLING, body
+    )
+}
+
+pub fn prefilter_fragment_shader() -> String {
+    with_importance_sampling(PREFILTER_FRAGMENT_BODY)
+}
+
+pub fn brdf_lut_fragment_shader() -> String {
+    with_importance_sampling(BRDF_LUT_FRAGMENT_BODY)
+}
@@ -0,0 +1,129 @@
+use glm::{Mat4x4, Vec3};
+
+pub const LIGHT_STD140_FLOATS: usize = 16;
+
+const DIRECTIONAL_LIGHT_TYPE: f32 = 0.0;
+const POINT_LIGHT_TYPE: f32 = 1.0;
+const SPOT_LIGHT_TYPE: f32 = 2.0;
+
+const NO_SHADOW_LAYER: f32 = -1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LightKind {
+    Directional,
+    Point {
+        range: f32,
+    },
+    Spot {
+        range: f32,
+        inner_angle: f32,
+        outer_angle: f32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Light {
+    pub kind: LightKind,
+    pub color: Vec3,
+    pub intensity: f32,
+    pub position: Vec3,
+    pub direction: Vec3,
+    pub cast_shadows: bool,
+}
+
+impl Light {
+    pub fn directional(direction: Vec3, color: Vec3, intensity: f32) -> Self {
+        Self {
+            kind: LightKind::Directional,
+            color,
+            intensity,
+            position: Vec3::zeros(),
+            direction: direction.normalize(),
+            cast_shadows: false,
+        }
+    }
+
+    pub fn point(position: Vec3, color: Vec3, intensity: f32, range: f32) -> Self {
+        Self {
+            kind: LightKind::Point { range },
+            color,
+            intensity,
+            position,
+            direction: -Vec3::z(),
+            cast_shadows: false,
+        }
+    }
+
+    pub fn spot(
+        position: Vec3,
+        direction: Vec3,
+        color: Vec3,
+        intensity: f32,
+        range: f32,
+        inner_angle: f32,
+        outer_angle: f32,
+    ) -> Self {
+        Self {
+            kind: LightKind::Spot {
+                range,
+                inner_angle,
+                outer_angle: outer_angle.max(inner_angle),
+            },
+            color,
+            intensity,
+            position,
+            direction: direction.normalize(),
+            cast_shadows: false,
+        }
+    }
+
+    pub fn with_shadows(self) -> Self {
+        Self {
+            cast_shadows: true,
+            ..self
+        }
+    }
+
+    pub fn supports_shadows(&self) -> bool {
+        !matches!(self.kind, LightKind::Point { .. })
+    }
+
+    pub fn range(&self) -> Option<f32> {
+        match self.kind {
+            LightKind::Directional => None,
+            LightKind::Point { range } | LightKind::Spot { range, .. } => Some(range),
+        }
+    }
+
+    pub fn transform(&self, world: &Mat4x4) -> Self {
+        let position = world * self.position.push(1.0);
+        let direction = world * self.direction.push(0.0);
+        Self {
+            position: position.xyz(),
+            direction: direction.xyz().normalize(),
+            ..*self
+        }
+    }
+
+    pub fn write_std140(&self, shadow_layer: Option<usize>, out: &mut Vec<f32>) {
+        let (light_type, range, cos_inner, cos_outer) = match self.kind {
+            LightKind::Directional => (DIRECTIONAL_LIGHT_TYPE, 0.0, 1.0, 1.0),
+            LightKind::Point { range } => (POINT_LIGHT_TYPE, range, -1.0, -1.0),
+            LightKind::Spot {
+                range,
+                inner_angle,
+                outer_angle,
+            } => (SPOT_LIGHT_TYPE, range, inner_angle.cos(), outer_angle.cos()),
+        };
+        out.extend_from_slice(&[
+            self.position.x,
+            self.position.y,
+            self.position.z,
+            light_type,
+        ]);
+        out.extend_from_slice(&[self.direction.x, self.direction.y, self.direction.z, range]);
+        out.extend_from_slice(&[self.color.x, self.color.y, self.color.z, self.intensity]);
+        let shadow_layer = shadow_layer.map_or(NO_SHADOW_LAYER, |layer| layer as f32);
+        out.extend_from_slice(&[cos_inner, cos_outer, shadow_layer, 0.0]);
+    }
+}
@@ -0,0 +1,75 @@
+use std::rc::Rc;
+
+use crate::gl::buffers::uniform_buffer::UniformBuffer;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::shader::program::ShaderProgram;
+
+use super::light::{Light, LIGHT_STD140_FLOATS};
+
+pub const LIGHTS_BLOCK_NAME: &str = "Lights";
+pub const LIGHTS_BINDING: u32 = 0;
+
+const HEADER_STD140_FLOATS: usize = 4;
+
+pub struct LightBuffer {
+    buffer: UniformBuffer,
+    max_lights: usize,
+    count: usize,
+    data: Vec<f32>,
+}
+
+impl LightBuffer {
+    pub fn try_new(gl: &Rc<GL>, max_lights: usize) -> Result<Self, GLError> {
+        let max_lights = max_lights.max(1);
+        let floats = HEADER_STD140_FLOATS + max_lights * LIGHT_STD140_FLOATS;
+        let buffer =
+            UniformBuffer::try_new(gl, floats * std::mem::size_of::<f32>(), LIGHTS_BINDING)?;
+        Ok(Self {
+            buffer,
+            max_lights,
+            count: 0,
+            data: Vec::with_capacity(floats),
+        })
+    }
+
+    pub fn max_lights(&self) -> usize {
+        self.max_lights
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn attach(&self, program: &ShaderProgram) -> bool {
+        self.buffer.attach(program, LIGHTS_BLOCK_NAME)
+    }
+
+    pub fn upload(&mut self, lights: &[Light]) {
+        self.upload_with_shadows(lights, &[]);
+    }
+
+    pub fn upload_with_shadows(&mut self, lights: &[Light], shadow_layers: &[Option<usize>]) {
+        if lights.len() > self.max_lights {
+            log::warn!(
+                "Too many lights: {}, only {} are used",
+                lights.len(),
+                self.max_lights
+            );
+        }
+        self.count = lights.len().min(self.max_lights);
+
+        self.data.clear();
+        self.data
+            .extend_from_slice(&[self.count as f32, 0.0, 0.0, 0.0]);
+        for (i, light) in lights.iter().take(self.count).enumerate() {
+            let shadow_layer = shadow_layers.get(i).copied().flatten();
+            light.write_std140(shadow_layer, &mut self.data);
+        }
+        self.buffer.upload(&self.data);
+    }
+
+    pub fn bind(&self) {
+        self.buffer.bind();
+    }
+}
@@ -0,0 +1,3 @@
+pub mod light;
+pub mod light_buffer;
+pub mod shaders;
@@ -0,0 +1,199 @@
+use crate::gl::shadows::shaders::shadows_block;
+
+pub const LIT_VERTEX_SHADER: &str = r##"#version 300 es
+    in vec3 a_position;
+    in vec3 a_normal;
+    in vec2 a_tex_coords;
+    in vec4 a_tangent;
+    in vec3 a_color;
+
+    struct camera {
+        mat4 model;
+        mat4 view;
+        mat4 projection;
+    };
+
+    uniform camera u_camera;
+
+    out vec3 v_position;
+    out vec3 v_normal;
+    out vec2 v_tex_coords;
+    out vec4 v_tangent;
+    out vec3 v_color;
+    out vec3 v_eye_position;
+    out float v_view_depth;
+
+    void main() {
+        vec4 world_position = u_camera.model * vec4(a_position, 1.0);
+
+        v_position = world_position.xyz;
+        v_normal = normalize(transpose(inverse(mat3(u_camera.model))) * a_normal);
+        v_tex_coords = a_tex_coords;
+        v_tangent = vec4(mat3(u_camera.model) * a_tangent.xyz, a_tangent.w);
+        v_color = a_color;
+        v_eye_position = inverse(u_camera.view)[3].xyz;
+        v_view_depth = -(u_camera.view * world_position).z;
+
+        gl_Position = u_camera.projection * u_camera.view * world_position;
+    }
+"##;
+
+pub const RANGE_ATTENUATION: &str = r##"
+    float range_attenuation(float distance, float range) {
+        float ratio = distance / max(range, 0.0001);
+        float window = clamp(1.0 - ratio * ratio * ratio * ratio, 0.0, 1.0);
+        return window * window / (distance * distance + 1.0);
+    }
+"##;
+
+const LIGHTS_BLOCK: &str = r##"
+    struct light_data {
+        vec4 position;
+        vec4 direction;
+        vec4 color;
+        vec4 cone;
+    };
+
+    layout(std140) uniform Lights {
+        vec4 u_light_info;
+        light_data u_lights[MAX_LIGHTS];
+    };
+
+    int light_count() {
+        return min(int(u_light_info.x), MAX_LIGHTS);
+    }
+
+    vec3 light_direction(light_data light, vec3 position, out float attenuation) {
+        if (light.position.w < 0.5) {
+            attenuation = 1.0;
+            return -normalize(light.direction.xyz);
+        }
+
+        vec3 to_light = light.position.xyz - position;
+        float distance = length(to_light);
+        vec3 direction = to_light / max(distance, 0.0001);
+        attenuation = range_attenuation(distance, light.direction.w);
+
+        if (light.position.w > 1.5) {
+            float cos_angle = dot(-direction, normalize(light.direction.xyz));
+            attenuation *= smoothstep(light.cone.y, light.cone.x, cos_angle);
+        }
+        return direction;
+    }
+"##;
+
+const BLINN_PHONG_FRAGMENT_BODY: &str = r##"
+    in vec3 v_position;
+    in vec3 v_normal;
+    in vec2 v_tex_coords;
+    in vec3 v_eye_position;
+    in float v_view_depth;
+
+    struct material {
+        vec3 diffuse_color;
+        vec3 specular_color;
+        vec3 ambient_color;
+        float shininess;
+    };
+
+    uniform material u_material;
+
+    out vec4 out_color;
+
+    void main() {
+        vec3 normal = normalize(v_normal);
+        vec3 view_dir = normalize(v_eye_position - v_position);
+        if (!gl_FrontFacing) {
+            normal = -normal;
+        }
+
+        vec3 color = u_material.ambient_color * u_material.diffuse_color;
+        for (int i = 0; i < light_count(); ++i) {
+            light_data light = u_lights[i];
+
+            float attenuation;
+            vec3 light_dir = light_direction(light, v_position, attenuation);
+            float shadow = light_shadow(light, v_position, normal, light_dir, v_view_depth);
+            vec3 radiance = light.color.rgb * light.color.w * attenuation * shadow;
+
+            float diffuse = max(dot(normal, light_dir), 0.0);
+            vec3 half_dir = normalize(light_dir + view_dir);
+            float specular = diffuse > 0.0
+                ? pow(max(dot(normal, half_dir), 0.0), u_material.shininess)
+                : 0.0;
+
+            color += radiance * (u_material.diffuse_color * diffuse
+                + u_material.specular_color * specular);
+        }
+
+        out_color = vec4(color, 1.0);
+    }
+"##;
+
+const VERTEX_COLOR_FRAGMENT_BODY: &str = r##"
+    in vec3 v_position;
+    in vec3 v_normal;
+    in vec3 v_color;
+    in vec3 v_eye_position;
+    in float v_view_depth;
+
+    uniform float u_ambient;
+
+    out vec4 out_color;
+
+    void main() {
+        vec3 normal = normalize(v_normal);
+        if (!gl_FrontFacing) {
+            normal = -normal;
+        }
+
+        vec3 lighting = vec3(u_ambient);
+        for (int i = 0; i < light_count(); ++i) {
+            light_data light = u_lights[i];
+
+            float attenuation;
+            vec3 light_dir = light_direction(light, v_position, attenuation);
+            float shadow = light_shadow(light, v_position, normal, light_dir, v_view_depth);
+            vec3 radiance = light.color.rgb * light.color.w * attenuation * shadow;
+            lighting += radiance * max(dot(normal, light_dir), 0.0);
+        }
+
+        out_color = vec4(v_color * lighting, 1.0);
+    }
+"##;
+
+const NO_SHADOWS_BLOCK: &str = r##"
+    float light_shadow(light_data light, vec3 position, vec3 normal, vec3 light_dir, float view_depth) {
+        return 1.0;
+    }
+"##;
+
+pub fn lights_block(max_lights: usize, max_shadow_maps: Option<usize>) -> String {
+    let shadows = match max_shadow_maps {
+        Some(max_maps) => shadows_block(max_maps),
+        None => NO_SHADOWS_BLOCK.into(),
+    };
+    format!(
+        "#define MAX_LIGHTS {}\n{}{}{}",
+        max_lights.max(1),
+        RANGE_ATTENUATION,
+        LIGHTS_BLOCK,
+        shadows
+    )
+}
+
+pub fn blinn_phong_fragment_shader(max_lights: usize, max_shadow_maps: Option<usize>) -> String {
+    format!(
+        "#version 300 es\nprecision highp float;\n{}{}",
+        lights_block(max_lights, max_shadow_maps),
+        BLINN_PHONG_FRAGMENT_BODY
+    )
+}
+
+pub fn vertex_color_fragment_shader(max_lights: usize) -> String {
+    format!(
+        "#version 300 es\nprecision highp float;\n{}{}",
+        lights_block(max_lights, None),
+        VERTEX_COLOR_FRAGMENT_BODY
+    )
+}
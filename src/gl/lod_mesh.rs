@@ -0,0 +1,159 @@
+use std::rc::Rc;
+
+use crate::math::bounds::Sphere;
+
+use super::camera::Camera;
+use super::core::instance::GL;
+use super::error::GLError;
+use super::materials::material::Material;
+use super::mesh::{Mesh, MeshBuilder};
+
+pub struct LodLevel {
+    mesh: Mesh,
+    max_screen_size: f32,
+}
+
+impl LodLevel {
+    pub fn max_screen_size(&self) -> f32 {
+        self.max_screen_size
+    }
+}
+
+pub struct LodMeshBuilder {
+    builder: MeshBuilder,
+    levels: Vec<(f32, f32)>,
+}
+
+impl LodMeshBuilder {
+    pub fn new(builder: MeshBuilder) -> Self {
+        Self {
+            builder,
+            levels: vec![],
+        }
+    }
+
+    pub fn add_level(mut self, face_ratio: f32, max_screen_size: f32) -> Self {
+        self.levels
+            .push((face_ratio.clamp(0.0, 1.0), max_screen_size.max(0.0)));
+        self
+    }
+
+    pub fn build(self, gl: &Rc<GL>) -> Result<LodMesh, GLError> {
+        let targets = level_targets(&self.levels, self.builder.faces().ncols());
+        let mut levels = Vec::with_capacity(targets.len() + 1);
+        for (target_faces, max_screen_size) in targets {
+            levels.push(LodLevel {
+                mesh: self.builder.simplify(target_faces).build(gl)?,
+                max_screen_size,
+            });
+        }
+        levels.insert(
+            0,
+            LodLevel {
+                mesh: self.builder.build(gl)?,
+                max_screen_size: f32::INFINITY,
+            },
+        );
+
+        Ok(LodMesh {
+            bounding_sphere: *levels[0].mesh.bounding_sphere(),
+            levels,
+        })
+    }
+}
+
+fn level_targets(levels: &[(f32, f32)], faces_count: usize) -> Vec<(usize, f32)> {
+    let mut targets: Vec<(usize, f32)> = levels
+        .iter()
+        .map(|&(face_ratio, max_screen_size)| {
+            let target_faces = ((faces_count as f32 * face_ratio).ceil() as usize).max(1);
+            (target_faces, max_screen_size)
+        })
+        .collect();
+    targets.sort_by(|a, b| b.1.total_cmp(&a.1));
+    targets
+}
+
+fn level_for_screen_size<I>(mut max_screen_sizes: I, screen_size: f32) -> usize
+where
+    I: DoubleEndedIterator<Item = f32> + ExactSizeIterator,
+{
+    max_screen_sizes
+        .rposition(|max_screen_size| screen_size < max_screen_size)
+        .unwrap_or(0)
+}
+
+pub struct LodMesh {
+    levels: Vec<LodLevel>,
+    bounding_sphere: Sphere,
+}
+
+impl LodMesh {
+    pub fn builder(builder: MeshBuilder) -> LodMeshBuilder {
+        LodMeshBuilder::new(builder)
+    }
+
+    pub fn screen_size(&self, camera: &Camera) -> f32 {
+        camera.projected_diameter(&self.bounding_sphere.transform(&camera.model))
+    }
+
+    pub fn select_level(&self, camera: &Camera) -> usize {
+        level_for_screen_size(
+            self.levels.iter().map(LodLevel::max_screen_size),
+            self.screen_size(camera),
+        )
+    }
+
+    pub fn level_mesh(&self, camera: &Camera) -> &Mesh {
+        &self.levels[self.select_level(camera)].mesh
+    }
+
+    pub fn render(&self, material: &Material, camera: &Camera) {
+        self.level_mesh(camera).render(material, camera);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THRESHOLDS: [f32; 4] = [f32::INFINITY, 200.0, 50.0, 10.0];
+
+    #[test]
+    fn targets_are_ordered_by_screen_size() {
+        let levels = [(0.1, 10.0), (0.5, 200.0), (0.25, 50.0), (0.0, 0.0)];
+        assert_eq!(
+            level_targets(&levels, 1001),
+            vec![(501, 200.0), (251, 50.0), (101, 10.0), (1, 0.0)]
+        );
+        assert!(level_targets(&[], 100).is_empty());
+    }
+
+    #[test]
+    fn selects_level_by_screen_size() {
+        let select = |screen_size| level_for_screen_size(THRESHOLDS.iter().copied(), screen_size);
+        assert_eq!(select(1000.0), 0);
+        assert_eq!(select(200.0), 0);
+        assert_eq!(select(199.0), 1);
+        assert_eq!(select(50.0), 1);
+        assert_eq!(select(49.0), 2);
+        assert_eq!(select(10.0), 2);
+        assert_eq!(select(9.0), 3);
+        assert_eq!(select(0.0), 3);
+    }
+
+    #[test]
+    fn coarser_levels_never_selected_for_larger_screen_sizes() {
+        let mut previous = 0;
+        for step in (0..=300).rev() {
+            let level = level_for_screen_size(THRESHOLDS.iter().copied(), step as f32);
+            assert!(level >= previous);
+            previous = level;
+        }
+        assert_eq!(previous, THRESHOLDS.len() - 1);
+        assert_eq!(
+            level_for_screen_size(std::iter::once(f32::INFINITY), 0.0),
+            0
+        );
+    }
+}
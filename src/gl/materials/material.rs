@@ -0,0 +1,135 @@
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use web_sys::WebGlUniformLocation;
+
+use crate::gl::camera::Camera;
+use crate::gl::core::instance::GL;
+use crate::gl::shader::program::ShaderProgram;
+
+use super::material_use_ctx::MaterialUseCtx;
+use super::param::MaterialParam;
+use super::pipeline_state::PipelineState;
+
+struct MaterialSlot {
+    name: String,
+    value: MaterialParam,
+    location: Option<WebGlUniformLocation>,
+}
+
+pub struct Material {
+    program: Rc<ShaderProgram>,
+    params: Vec<MaterialSlot>,
+    state: PipelineState,
+    uid: u32,
+    gl: Rc<GL>,
+}
+
+impl Material {
+    pub fn new(gl: &Rc<GL>, program: &Rc<ShaderProgram>) -> Self {
+        static NEXT_UID: AtomicU32 = AtomicU32::new(0);
+
+        Self {
+            program: Rc::clone(program),
+            params: vec![],
+            state: PipelineState::default(),
+            uid: NEXT_UID.fetch_add(1, Ordering::SeqCst),
+            gl: Rc::clone(gl),
+        }
+    }
+
+    pub fn with_param(mut self, name: &str, value: impl Into<MaterialParam>) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    pub fn with_state(mut self, state: PipelineState) -> Self {
+        self.state = state;
+        self
+    }
+
+    pub fn uid(&self) -> u32 {
+        self.uid
+    }
+
+    pub fn program(&self) -> &Rc<ShaderProgram> {
+        &self.program
+    }
+
+    pub fn state(&self) -> &PipelineState {
+        &self.state
+    }
+
+    pub fn set_state(&mut self, state: PipelineState) {
+        self.state = state;
+    }
+
+    pub fn param(&self, name: &str) -> Option<&MaterialParam> {
+        self.params
+            .iter()
+            .find(|slot| slot.name == name)
+            .map(|slot| &slot.value)
+    }
+
+    pub fn set(&mut self, name: &str, value: impl Into<MaterialParam>) {
+        let value = value.into();
+        if let Some(slot) = self.params.iter_mut().find(|slot| slot.name == name) {
+            slot.value = value;
+            return;
+        }
+
+        let location = self.program.get_raw_gl_uniform_location(name);
+        if location.is_none() {
+            log::warn!("Material parameter is not used by the program: {}", name);
+        }
+        self.params.push(MaterialSlot {
+            name: name.into(),
+            value,
+            location,
+        });
+    }
+
+    pub fn with_program(&self, program: &Rc<ShaderProgram>) -> Self {
+        let mut material = Self::new(&self.gl, program).with_state(self.state);
+        for slot in self.params.iter() {
+            material.set(&slot.name, slot.value.clone());
+        }
+        material
+    }
+
+    pub fn upload_params(&self) {
+        let mut texture_unit = 0;
+        for slot in self.params.iter() {
+            slot.value
+                .upload(&self.gl, slot.location.as_ref(), texture_unit as i32);
+            if let Some(texture) = slot.value.as_texture() {
+                texture.bind_to_unit(texture_unit);
+                texture_unit += 1;
+            }
+        }
+    }
+
+    pub fn unbind_textures(&self) {
+        let textures = self
+            .params
+            .iter()
+            .filter_map(|slot| slot.value.as_texture());
+        for (unit, texture) in textures.enumerate() {
+            texture.unbind_from_unit(unit as u32);
+        }
+    }
+
+    #[must_use]
+    pub fn bind(&self) -> MaterialUseCtx<'_> {
+        MaterialUseCtx::new(&self.gl, self, self.state)
+    }
+
+    #[must_use]
+    pub fn bind_for(&self, camera: &Camera) -> MaterialUseCtx<'_> {
+        MaterialUseCtx::new(
+            &self.gl,
+            self,
+            self.state.for_projection(camera.projection()),
+        )
+    }
+}
@@ -0,0 +1,45 @@
+use crate::gl::core::instance::GL;
+use crate::gl::shader::shader_use_ctx::ShaderUseCtx;
+use crate::gl::shader::uniform_value::UniformValue;
+
+use super::material::Material;
+use super::pipeline_state::PipelineState;
+
+pub struct MaterialUseCtx<'a> {
+    shader_use: ShaderUseCtx<'a>,
+    material: &'a Material,
+    gl: &'a GL,
+}
+
+impl<'a> MaterialUseCtx<'a> {
+    pub fn new(gl: &'a GL, material: &'a Material, state: PipelineState) -> Self {
+        let shader_use = material.program().use_program();
+        state.apply(gl);
+        material.upload_params();
+
+        Self {
+            shader_use,
+            material,
+            gl,
+        }
+    }
+
+    pub fn shader_use(&self) -> &ShaderUseCtx<'a> {
+        &self.shader_use
+    }
+
+    pub fn material(&self) -> &Material {
+        self.material
+    }
+
+    pub fn set_uniform<V: UniformValue>(&self, location: &V::Location, value: &V) {
+        self.shader_use.set_uniform(location, value)
+    }
+}
+
+impl Drop for MaterialUseCtx<'_> {
+    fn drop(&mut self) {
+        self.material.unbind_textures();
+        PipelineState::reset(self.gl);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod material;
+pub mod material_use_ctx;
+pub mod param;
+pub mod pipeline_state;
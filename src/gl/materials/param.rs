@@ -0,0 +1,106 @@
+use std::rc::Rc;
+
+use glm::{Mat4x4, Vec2, Vec3, Vec4};
+use web_sys::WebGlUniformLocation;
+
+use crate::gl::core::instance::GL;
+use crate::gl::shader::uniform_value::SimpleUniformValue;
+use crate::gl::textures::texture::Texture;
+
+#[derive(Clone)]
+pub enum MaterialParam {
+    Float(f32),
+    FloatArray(Vec<f32>),
+    Int(i32),
+    UInt(u32),
+    Vec2(Vec2),
+    Vec3(Vec3),
+    Vec4(Vec4),
+    Mat4(Mat4x4),
+    Mat4Array(Vec<Mat4x4>),
+    Texture(Rc<Texture>),
+}
+
+impl MaterialParam {
+    pub fn upload(&self, gl: &GL, location: Option<&WebGlUniformLocation>, texture_unit: i32) {
+        match self {
+            MaterialParam::Float(value) => value.simple_set_to(gl, location),
+            MaterialParam::FloatArray(value) => value.simple_set_to(gl, location),
+            MaterialParam::Int(value) => value.simple_set_to(gl, location),
+            MaterialParam::UInt(value) => value.simple_set_to(gl, location),
+            MaterialParam::Vec2(value) => value.simple_set_to(gl, location),
+            MaterialParam::Vec3(value) => value.simple_set_to(gl, location),
+            MaterialParam::Vec4(value) => value.simple_set_to(gl, location),
+            MaterialParam::Mat4(value) => value.simple_set_to(gl, location),
+            MaterialParam::Mat4Array(value) => value.simple_set_to(gl, location),
+            MaterialParam::Texture(_) => texture_unit.simple_set_to(gl, location),
+        }
+    }
+
+    pub fn as_texture(&self) -> Option<&Rc<Texture>> {
+        match self {
+            MaterialParam::Texture(texture) => Some(texture),
+            _ => None,
+        }
+    }
+}
+
+impl From<f32> for MaterialParam {
+    fn from(value: f32) -> Self {
+        MaterialParam::Float(value)
+    }
+}
+
+impl From<Vec<f32>> for MaterialParam {
+    fn from(value: Vec<f32>) -> Self {
+        MaterialParam::FloatArray(value)
+    }
+}
+
+impl From<i32> for MaterialParam {
+    fn from(value: i32) -> Self {
+        MaterialParam::Int(value)
+    }
+}
+
+impl From<u32> for MaterialParam {
+    fn from(value: u32) -> Self {
+        MaterialParam::UInt(value)
+    }
+}
+
+impl From<Vec2> for MaterialParam {
+    fn from(value: Vec2) -> Self {
+        MaterialParam::Vec2(value)
+    }
+}
+
+impl From<Vec3> for MaterialParam {
+    fn from(value: Vec3) -> Self {
+        MaterialParam::Vec3(value)
+    }
+}
+
+impl From<Vec4> for MaterialParam {
+    fn from(value: Vec4) -> Self {
+        MaterialParam::Vec4(value)
+    }
+}
+
+impl From<Mat4x4> for MaterialParam {
+    fn from(value: Mat4x4) -> Self {
+        MaterialParam::Mat4(value)
+    }
+}
+
+impl From<Vec<Mat4x4>> for MaterialParam {
+    fn from(value: Vec<Mat4x4>) -> Self {
+        MaterialParam::Mat4Array(value)
+    }
+}
+
+impl From<Rc<Texture>> for MaterialParam {
+    fn from(value: Rc<Texture>) -> Self {
+        MaterialParam::Texture(value)
+    }
+}
@@ -0,0 +1,131 @@
+use crate::gl;
+use crate::gl::camera::projection::Projection;
+use crate::gl::core::instance::GL;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    Alpha,
+    Premultiplied,
+    Additive,
+    Multiply,
+}
+
+impl BlendMode {
+    fn factors(&self) -> (u32, u32) {
+        match self {
+            BlendMode::Alpha => (gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA),
+            BlendMode::Premultiplied => (gl::ONE, gl::ONE_MINUS_SRC_ALPHA),
+            BlendMode::Additive => (gl::SRC_ALPHA, gl::ONE),
+            BlendMode::Multiply => (gl::DST_COLOR, gl::ZERO),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CullFace {
+    Back,
+    Front,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PipelineState {
+    pub depth_test: bool,
+    pub depth_write: bool,
+    pub depth_func: u32,
+    pub cull: Option<CullFace>,
+    pub blend: Option<BlendMode>,
+}
+
+impl Default for PipelineState {
+    fn default() -> Self {
+        Self {
+            depth_test: true,
+            depth_write: true,
+            depth_func: gl::LESS,
+            cull: None,
+            blend: None,
+        }
+    }
+}
+
+impl PipelineState {
+    pub fn opaque() -> Self {
+        Self::default()
+    }
+
+    pub fn fullscreen() -> Self {
+        Self {
+            depth_test: false,
+            depth_write: false,
+            ..Self::default()
+        }
+    }
+
+    pub fn transparent(blend: BlendMode) -> Self {
+        Self {
+            depth_write: false,
+            blend: Some(blend),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_depth_func(self, depth_func: u32) -> Self {
+        Self { depth_func, ..self }
+    }
+
+    pub fn for_projection(self, projection: &Projection) -> Self {
+        Self {
+            depth_func: projection.map_depth_func(self.depth_func),
+            ..self
+        }
+    }
+
+    pub fn with_cull(self, cull: Option<CullFace>) -> Self {
+        Self { cull, ..self }
+    }
+
+    pub fn with_blend(self, blend: Option<BlendMode>) -> Self {
+        Self { blend, ..self }
+    }
+
+    pub fn is_transparent(&self) -> bool {
+        self.blend.is_some()
+    }
+
+    pub fn apply(&self, gl: &GL) {
+        if self.depth_test {
+            gl.enable(gl::DEPTH_TEST);
+            gl.depth_func(self.depth_func);
+        } else {
+            gl.disable(gl::DEPTH_TEST);
+        }
+        gl.depth_mask(self.depth_write);
+
+        match self.cull {
+            Some(face) => {
+                gl.enable(gl::CULL_FACE);
+                gl.cull_face(match face {
+                    CullFace::Back => gl::BACK,
+                    CullFace::Front => gl::FRONT,
+                });
+            }
+            None => gl.disable(gl::CULL_FACE),
+        }
+
+        match self.blend {
+            Some(blend) => {
+                let (src, dst) = blend.factors();
+                gl.enable(gl::BLEND);
+                gl.blend_func(src, dst);
+            }
+            None => gl.disable(gl::BLEND),
+        }
+    }
+
+    pub fn reset(gl: &GL) {
+        gl.disable(gl::DEPTH_TEST);
+        gl.depth_mask(true);
+        gl.disable(gl::CULL_FACE);
+        gl.disable(gl::BLEND);
+    }
+}
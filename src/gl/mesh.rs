@@ -1,26 +1,48 @@
 use std::rc::Rc;
+use std::sync::atomic::{AtomicU32, Ordering};
 
-use na::{Matrix2xX, Matrix3xX};
+use na::allocator::Allocator;
+use na::{DefaultAllocator, Dim, Dynamic, Matrix2xX, Matrix3xX, Matrix4xX, OMatrix};
 
-use crate::gl;
 use crate::gl::core::utils::SizeInBytes;
-use crate::gl::shader::program::ShaderProgram;
-use crate::math::compute_normals::compute_normals;
+use crate::gl::materials::material::Material;
+use crate::gl::shader::shader_use_ctx::ShaderUseCtx;
+use crate::math::bounds::{Aabb, Sphere};
+use crate::math::bvh::Bvh;
+use crate::math::compute_normals::{
+    auto_smooth, compute_normals, compute_weighted_normals, NormalWeighting,
+};
+use crate::math::compute_tangents::compute_tangents;
+use crate::math::mesh_processing::decimation::decimate;
+use crate::math::mesh_processing::weld::remove_unreferenced;
 
+use super::buffers::binded_obj_ctx::BindedObjCtx;
 use super::buffers::object::GLObject;
 use super::camera::Camera;
 use super::core::instance::GL;
 use super::error::GLError;
+use super::morph::morph_targets::{MorphTarget, MorphTargets};
 
 pub type Vertices = Matrix3xX<f32>;
 pub type Normals = Matrix3xX<f32>;
 pub type TexCoords = Matrix2xX<f32>;
+pub type Tangents = Matrix4xX<f32>;
+pub type Colors = Matrix3xX<f32>;
 pub type Faces = Matrix3xX<i32>;
+pub type JointIndices = Matrix4xX<f32>;
+pub type JointWeights = Matrix4xX<f32>;
+
+const ATTRIBUTES_COUNT: usize = 7;
 
 pub struct MeshBuilder {
     vertices: Vertices,
     normals: Normals,
     tex_coords: TexCoords,
+    tangents: Tangents,
+    colors: Colors,
+    joints: JointIndices,
+    weights: JointWeights,
+    morph_targets: Vec<MorphTarget>,
     faces: Faces,
 }
 
@@ -30,10 +52,39 @@ impl MeshBuilder {
             vertices,
             normals: Normals::zeros(0),
             tex_coords: TexCoords::zeros(0),
+            tangents: Tangents::zeros(0),
+            colors: Colors::zeros(0),
+            joints: JointIndices::zeros(0),
+            weights: JointWeights::zeros(0),
+            morph_targets: vec![],
             faces,
         }
     }
 
+    pub fn vertices(&self) -> &Vertices {
+        &self.vertices
+    }
+
+    pub fn normals(&self) -> &Normals {
+        &self.normals
+    }
+
+    pub fn tex_coords(&self) -> &TexCoords {
+        &self.tex_coords
+    }
+
+    pub fn tangents(&self) -> &Tangents {
+        &self.tangents
+    }
+
+    pub fn colors(&self) -> &Colors {
+        &self.colors
+    }
+
+    pub fn faces(&self) -> &Faces {
+        &self.faces
+    }
+
     pub fn add_normals(mut self, normals: Normals) -> Self {
         self.normals = normals;
         self
@@ -44,13 +95,112 @@ impl MeshBuilder {
         self.add_normals(normals)
     }
 
+    pub fn build_weighted_normals(self, weighting: NormalWeighting) -> Self {
+        let normals = compute_weighted_normals(&self.vertices, &self.faces, weighting);
+        self.add_normals(normals)
+    }
+
+    pub fn build_smooth_normals(self, crease_angle: f32, weighting: NormalWeighting) -> Self {
+        let smoothed = auto_smooth(&self.vertices, &self.faces, crease_angle, weighting);
+        Self {
+            normals: smoothed.normals,
+            tangents: Tangents::zeros(0),
+            ..self.select_vertices(&smoothed.source_vertices, smoothed.faces)
+        }
+    }
+
+    pub fn simplify(&self, target_faces: usize) -> Self {
+        let faces = decimate(&self.vertices, &self.faces, target_faces);
+        let compacted = remove_unreferenced(&self.vertices, &faces);
+        self.select_vertices(&compacted.sources, compacted.faces)
+    }
+
+    fn select_vertices(&self, sources: &[usize], faces: Faces) -> Self {
+        Self {
+            vertices: select_columns(&self.vertices, sources),
+            normals: select_columns(&self.normals, sources),
+            tex_coords: select_columns(&self.tex_coords, sources),
+            tangents: select_columns(&self.tangents, sources),
+            colors: select_columns(&self.colors, sources),
+            joints: select_columns(&self.joints, sources),
+            weights: select_columns(&self.weights, sources),
+            morph_targets: self
+                .morph_targets
+                .iter()
+                .map(|target| MorphTarget {
+                    positions: select_columns(&target.positions, sources),
+                    normals: select_columns(&target.normals, sources),
+                })
+                .collect(),
+            faces,
+        }
+    }
+
     pub fn add_tex_coords(mut self, tex_coolds: TexCoords) -> Self {
         self.tex_coords = tex_coolds;
         self
     }
 
+    pub fn add_tangents(mut self, tangents: Tangents) -> Self {
+        self.tangents = tangents;
+        self
+    }
+
+    pub fn build_tangents(self) -> Self {
+        if self.tex_coords.ncols() != self.vertices.ncols() {
+            log::warn!("Cannot build tangents for a mesh without texture coordinates");
+            return self;
+        }
+
+        let builder = if self.normals.ncols() == self.vertices.ncols() {
+            self
+        } else {
+            self.build_normals()
+        };
+        let geometry = compute_tangents(
+            &builder.vertices,
+            &builder.normals,
+            &builder.tex_coords,
+            &builder.faces,
+        );
+        Self {
+            tangents: geometry.tangents,
+            ..builder.select_vertices(&geometry.source_vertices, geometry.faces)
+        }
+    }
+
+    pub fn add_colors(mut self, colors: Colors) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    pub fn add_skin(mut self, joints: JointIndices, weights: JointWeights) -> Self {
+        self.joints = joints;
+        self.weights = weights;
+        self
+    }
+
+    pub fn add_morph_targets(mut self, targets: Vec<MorphTarget>) -> Self {
+        self.morph_targets = targets;
+        self
+    }
+
     pub fn build(self, gl: &Rc<GL>) -> Result<Mesh, GLError> {
-        Mesh::try_new(gl, self.vertices, self.normals, self.tex_coords, self.faces)
+        Mesh::from_builder(gl, self)
+    }
+}
+
+fn select_columns<R: Dim>(
+    attribute: &OMatrix<f32, R, Dynamic>,
+    sources: &[usize],
+) -> OMatrix<f32, R, Dynamic>
+where
+    DefaultAllocator: Allocator<f32, R, Dynamic>,
+{
+    if attribute.ncols() == 0 {
+        attribute.clone()
+    } else {
+        attribute.select_columns(sources)
     }
 }
 
@@ -58,11 +208,30 @@ pub struct Mesh {
     vertices: Vertices,
     normals: Normals,
     tex_coords: TexCoords,
+    tangents: Tangents,
+    colors: Colors,
+    joints: JointIndices,
+    weights: JointWeights,
+    morph_targets: Option<MorphTargets>,
     faces: Faces,
+    aabb: Aabb,
+    bounding_sphere: Sphere,
     object: GLObject,
+    uid: u32,
     gl: Rc<GL>,
 }
 
+pub struct BindedMeshCtx<'a> {
+    binded: BindedObjCtx<'a>,
+    faces_count: usize,
+}
+
+impl BindedMeshCtx<'_> {
+    pub fn draw(&self, shader_use: &ShaderUseCtx) {
+        self.binded.draw_triangles(shader_use, self.faces_count);
+    }
+}
+
 impl Mesh {
     pub fn new_builder(vertices: Vertices, faces: Faces) -> MeshBuilder {
         MeshBuilder::new(vertices, faces)
@@ -75,70 +244,210 @@ impl Mesh {
         tex_coords: TexCoords,
         faces: Faces,
     ) -> Result<Self, GLError> {
-        let object = GLObject::try_new(gl)?;
-
-        if let binded = object.bind() {
-            let v_size = vertices.size_in_bytes();
-            let n_size = vertices.size_in_bytes();
-            let t_size = tex_coords.size_in_bytes();
-
-            binded.init_dyn_array_buffer(v_size + n_size + t_size);
-            binded.upload_sub_array(&vertices, 0);
-            binded.upload_sub_array(&normals, v_size);
-            binded.upload_sub_array(&tex_coords, v_size + n_size);
-            binded.upload_static_elem_buffer(&faces);
-        }
+        Self::new_builder(vertices, faces)
+            .add_normals(normals)
+            .add_tex_coords(tex_coords)
+            .build(gl)
+    }
 
-        Ok(Self {
+    fn from_builder(gl: &Rc<GL>, builder: MeshBuilder) -> Result<Self, GLError> {
+        static NEXT_UID: AtomicU32 = AtomicU32::new(0);
+
+        let MeshBuilder {
+            vertices,
+            normals,
+            tex_coords,
+            tangents,
+            colors,
+            joints,
+            weights,
+            morph_targets,
+            faces,
+        } = builder;
+        let morph_targets = if morph_targets.is_empty() {
+            None
+        } else {
+            Some(MorphTargets::try_new(gl, vertices.ncols(), morph_targets)?)
+        };
+        let mesh = Self {
+            aabb: Aabb::from_columns(&vertices),
+            bounding_sphere: Sphere::from_columns(&vertices),
             vertices,
             faces,
             normals,
             tex_coords,
+            tangents,
+            colors,
+            joints,
+            weights,
+            morph_targets,
             gl: Rc::clone(gl),
-            object,
-        })
+            object: GLObject::try_new(gl)?,
+            uid: NEXT_UID.fetch_add(1, Ordering::SeqCst),
+        };
+
+        let binded = mesh.object.bind();
+        let offsets = mesh.attribute_offsets();
+        binded.init_dyn_array_buffer(offsets[ATTRIBUTES_COUNT]);
+        mesh.upload_attributes(&binded);
+        binded.upload_static_elem_buffer(&mesh.faces);
+        drop(binded);
+        Ok(mesh)
+    }
+
+    fn attribute_offsets(&self) -> [usize; ATTRIBUTES_COUNT + 1] {
+        let sizes = [
+            self.vertices.size_in_bytes(),
+            self.normals.size_in_bytes(),
+            self.tex_coords.size_in_bytes(),
+            self.joints.size_in_bytes(),
+            self.weights.size_in_bytes(),
+            self.tangents.size_in_bytes(),
+            self.colors.size_in_bytes(),
+        ];
+        let mut offsets = [0; ATTRIBUTES_COUNT + 1];
+        for (index, size) in sizes.iter().enumerate() {
+            offsets[index + 1] = offsets[index] + size;
+        }
+        offsets
+    }
+
+    fn upload_attributes(&self, binded: &BindedObjCtx) {
+        let offsets = self.attribute_offsets();
+        binded.upload_sub_array(&self.vertices, offsets[0]);
+        binded.upload_sub_array(&self.normals, offsets[1]);
+        binded.upload_sub_array(&self.tex_coords, offsets[2]);
+        binded.upload_sub_array(&self.joints, offsets[3]);
+        binded.upload_sub_array(&self.weights, offsets[4]);
+        binded.upload_sub_array(&self.tangents, offsets[5]);
+        binded.upload_sub_array(&self.colors, offsets[6]);
+    }
+
+    pub fn uid(&self) -> u32 {
+        self.uid
+    }
+
+    pub fn vertices(&self) -> &Vertices {
+        &self.vertices
+    }
+
+    pub fn normals(&self) -> &Normals {
+        &self.normals
+    }
+
+    pub fn tex_coords(&self) -> &TexCoords {
+        &self.tex_coords
+    }
+
+    pub fn tangents(&self) -> &Tangents {
+        &self.tangents
+    }
+
+    pub fn colors(&self) -> &Colors {
+        &self.colors
+    }
+
+    pub fn joints(&self) -> &JointIndices {
+        &self.joints
+    }
+
+    pub fn weights(&self) -> &JointWeights {
+        &self.weights
+    }
+
+    pub fn is_skinned(&self) -> bool {
+        self.joints.ncols() > 0 && self.weights.ncols() > 0
+    }
+
+    pub fn morph_targets(&self) -> Option<&MorphTargets> {
+        self.morph_targets.as_ref()
+    }
+
+    pub fn faces(&self) -> &Faces {
+        &self.faces
+    }
+
+    pub fn aabb(&self) -> &Aabb {
+        &self.aabb
+    }
+
+    pub fn bounding_sphere(&self) -> &Sphere {
+        &self.bounding_sphere
+    }
+
+    pub fn build_bvh(&self) -> Bvh {
+        Bvh::new(&self.vertices, &self.faces)
     }
 
     pub fn update(&mut self, update: impl FnOnce(&mut Vertices, &mut Normals, &mut TexCoords)) {
         update(&mut self.vertices, &mut self.normals, &mut self.tex_coords);
-
-        let v_size = self.vertices.size_in_bytes();
-        let n_size = self.normals.size_in_bytes();
+        self.aabb = Aabb::from_columns(&self.vertices);
+        self.bounding_sphere = Sphere::from_columns(&self.vertices);
 
         let binded = self.object.bind();
-        binded.upload_sub_array(&self.vertices, 0);
-        binded.upload_sub_array(&self.normals, v_size);
-        binded.upload_sub_array(&self.tex_coords, v_size + n_size);
+        self.upload_attributes(&binded);
     }
 
-    pub fn render(&self, shader: &ShaderProgram, camera: &Camera) {
-        self.gl.enable(gl::DEPTH_TEST);
+    pub fn update_colors(&mut self, update: impl FnOnce(&mut Colors)) {
+        update(&mut self.colors);
 
-        let shader_use = shader.use_program();
+        let binded = self.object.bind();
+        self.upload_attributes(&binded);
+    }
+
+    pub fn render(&self, material: &Material, camera: &Camera) {
+        let material_use = material.bind_for(camera);
+        self.draw(material_use.shader_use(), camera);
+    }
 
-        let u_camera = shader.get_uniform_location::<Camera>("u_camera");
+    pub fn draw(&self, shader_use: &ShaderUseCtx, camera: &Camera) {
+        let u_camera = shader_use
+            .as_program()
+            .get_uniform_location::<Camera>("u_camera");
         shader_use.set_uniform(&u_camera, camera);
 
+        self.draw_geometry(shader_use);
+    }
+
+    pub fn draw_geometry(&self, shader_use: &ShaderUseCtx) {
+        self.bind(shader_use).draw(shader_use);
+    }
+
+    #[must_use]
+    pub fn bind(&self, shader_use: &ShaderUseCtx) -> BindedMeshCtx<'_> {
+        let shader = shader_use.as_program();
         let a_position = shader.get_attrib_location("a_position");
         let a_normal = shader.get_attrib_location("a_normal");
         let a_tex_coords = shader.get_attrib_location("a_tex_coords");
+        let a_joints = shader.get_attrib_location("a_joints");
+        let a_weights = shader.get_attrib_location("a_weights");
+        let a_tangent = shader.get_attrib_location("a_tangent");
+        let a_color = shader.get_attrib_location("a_color");
 
-        if let mut binded = self.object.bind() {
-            let v_size = self.vertices.size_in_bytes();
-            let n_size = self.normals.size_in_bytes();
-            let t_size = self.tex_coords.size_in_bytes();
+        let mut binded = self.object.bind();
+        let offsets = self.attribute_offsets();
 
-            binded.vertex_attrib_pointer(&shader_use, a_position, 3, 0, 0);
-            if n_size > 0 {
-                binded.vertex_attrib_pointer(&shader_use, a_normal, 3, 0, v_size);
-            }
-            if t_size > 0 {
-                binded.vertex_attrib_pointer(&shader_use, a_tex_coords, 2, 0, v_size + n_size);
-            }
-
-            binded.draw_triangles(&shader_use, self.faces.ncols());
+        binded.vertex_attrib_pointer(shader_use, a_position, 3, 0, offsets[0]);
+        if self.normals.ncols() > 0 {
+            binded.vertex_attrib_pointer(shader_use, a_normal, 3, 0, offsets[1]);
+        }
+        if self.tex_coords.ncols() > 0 {
+            binded.vertex_attrib_pointer(shader_use, a_tex_coords, 2, 0, offsets[2]);
+        }
+        if self.is_skinned() {
+            binded.vertex_attrib_pointer(shader_use, a_joints, 4, 0, offsets[3]);
+            binded.vertex_attrib_pointer(shader_use, a_weights, 4, 0, offsets[4]);
+        }
+        if self.tangents.ncols() > 0 {
+            binded.vertex_attrib_pointer(shader_use, a_tangent, 4, 0, offsets[5]);
+        }
+        if self.colors.ncols() > 0 {
+            binded.vertex_attrib_pointer(shader_use, a_color, 3, 0, offsets[6]);
         }
 
-        self.gl.disable(gl::DEPTH_TEST);
+        BindedMeshCtx {
+            binded,
+            faces_count: self.faces.ncols(),
+        }
     }
 }
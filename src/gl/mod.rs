@@ -3,9 +3,29 @@
 pub mod buffers;
 pub mod camera;
 pub mod core;
+pub mod culling;
+pub mod deferred;
 pub mod error;
+pub mod framebuffers;
+pub mod ibl;
+pub mod lighting;
+pub mod lod_mesh;
+pub mod materials;
 pub mod mesh;
+pub mod morph;
+pub mod particles;
+pub mod pbr;
+pub mod picking;
+pub mod postprocess;
+pub mod primitives;
+pub mod queue;
+pub mod screen_quad;
 pub mod shader;
+pub mod shadows;
+pub mod skinning;
+pub mod skybox;
+pub mod ssao;
+pub mod textures;
 
 use web_sys::WebGl2RenderingContext;
 
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum MorphError {
+    #[error("Mesh has {count} morph targets, but at most {max} are supported")]
+    TooManyTargets { count: usize, max: usize },
+    #[error("Morph target {target} has {actual} deltas, expected {expected}")]
+    VertexCountMismatch {
+        target: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
@@ -0,0 +1,3 @@
+pub mod error;
+pub mod morph_targets;
+pub mod shaders;
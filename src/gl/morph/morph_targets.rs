@@ -0,0 +1,163 @@
+use std::rc::Rc;
+
+use glm::Vec3;
+
+use crate::gl;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::materials::material::Material;
+use crate::gl::mesh::{Normals, Vertices};
+use crate::gl::textures::texture::{Texture, TextureFormat};
+
+use super::error::MorphError;
+
+pub const MAX_MORPH_TARGETS: usize = 8;
+pub const MORPH_TEXTURE_WIDTH: usize = 2048;
+
+const MORPH_TEXTURE_UNIFORM: &str = "u_morph_targets";
+const MORPH_INFO_UNIFORM: &str = "u_morph_info";
+const MORPH_WEIGHTS_UNIFORM: &str = "u_morph_weights";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MorphTarget {
+    pub positions: Vertices,
+    pub normals: Normals,
+}
+
+impl MorphTarget {
+    pub fn new(positions: Vertices) -> Self {
+        Self {
+            positions,
+            normals: Normals::zeros(0),
+        }
+    }
+
+    pub fn with_normals(mut self, normals: Normals) -> Self {
+        self.normals = normals;
+        self
+    }
+}
+
+pub struct MorphTargets {
+    targets: Vec<MorphTarget>,
+    texture: Rc<Texture>,
+    width: usize,
+    rows_per_layer: usize,
+}
+
+impl MorphTargets {
+    pub fn try_new(
+        gl: &Rc<GL>,
+        vertices_count: usize,
+        targets: Vec<MorphTarget>,
+    ) -> Result<Self, GLError> {
+        if targets.len() > MAX_MORPH_TARGETS {
+            return Err(MorphError::TooManyTargets {
+                count: targets.len(),
+                max: MAX_MORPH_TARGETS,
+            }
+            .into());
+        }
+        for (index, target) in targets.iter().enumerate() {
+            let normals = target.normals.ncols();
+            let actual = target.positions.ncols();
+            if actual != vertices_count || (normals != 0 && normals != vertices_count) {
+                return Err(MorphError::VertexCountMismatch {
+                    target: index,
+                    expected: vertices_count,
+                    actual: if actual != vertices_count {
+                        actual
+                    } else {
+                        normals
+                    },
+                }
+                .into());
+            }
+        }
+
+        let width = vertices_count.clamp(1, MORPH_TEXTURE_WIDTH);
+        let rows_per_layer = vertices_count.max(1).div_ceil(width);
+        let layers = targets.len().max(1) * 2;
+        let height = rows_per_layer * layers;
+
+        let mut pixels = vec![0.0; width * height * 4];
+        for (index, target) in targets.iter().enumerate() {
+            let layers = [
+                (index * 2, &target.positions),
+                (index * 2 + 1, &target.normals),
+            ];
+            for (layer, deltas) in layers.iter() {
+                let offset = layer * rows_per_layer * width;
+                for (vertex, delta) in deltas.column_iter().enumerate() {
+                    let texel = (offset + vertex) * 4;
+                    pixels[texel..texel + 3].copy_from_slice(&[delta[0], delta[1], delta[2]]);
+                }
+            }
+        }
+
+        let (width_px, height_px) = (width as i32, height as i32);
+        let texture = Texture::try_new(
+            gl,
+            gl::TEXTURE_2D,
+            TextureFormat::RGBA32F,
+            width_px,
+            height_px,
+        )?;
+        texture.set_filter(gl::NEAREST, gl::NEAREST);
+        texture.upload_f32(gl::TEXTURE_2D, 0, width_px, height_px, &pixels)?;
+
+        Ok(Self {
+            targets,
+            texture: Rc::new(texture),
+            width,
+            rows_per_layer,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    pub fn targets(&self) -> &[MorphTarget] {
+        &self.targets
+    }
+
+    pub fn texture(&self) -> &Rc<Texture> {
+        &self.texture
+    }
+
+    pub fn attach(&self, material: &mut Material) {
+        material.set(MORPH_TEXTURE_UNIFORM, Rc::clone(&self.texture));
+        material.set(
+            MORPH_INFO_UNIFORM,
+            Vec3::new(
+                self.width as f32,
+                self.rows_per_layer as f32,
+                self.targets.len() as f32,
+            ),
+        );
+        self.set_weights(material, &[]);
+    }
+
+    pub fn set_weights(&self, material: &mut Material, weights: &[f32]) {
+        let mut padded = vec![0.0; MAX_MORPH_TARGETS];
+        for (slot, weight) in padded.iter_mut().zip(weights.iter()) {
+            *slot = *weight;
+        }
+        material.set(MORPH_WEIGHTS_UNIFORM, padded);
+    }
+
+    pub fn blend_positions(&self, base: &Vertices, weights: &[f32]) -> Vertices {
+        let mut result = base.clone();
+        for (target, &weight) in self.targets.iter().zip(weights.iter()) {
+            if weight != 0.0 {
+                result += &target.positions * weight;
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,75 @@
+use super::morph_targets::MAX_MORPH_TARGETS;
+
+const MORPH_BLOCK: &str = r##"
+    uniform highp sampler2D u_morph_targets;
+    uniform vec3 u_morph_info;
+    uniform float u_morph_weights[MAX_MORPH_TARGETS];
+
+    vec3 morph_delta(int layer) {
+        int width = int(u_morph_info.x);
+        int row = layer * int(u_morph_info.y) + gl_VertexID / width;
+        return texelFetch(u_morph_targets, ivec2(gl_VertexID % width, row), 0).xyz;
+    }
+
+    vec3 morph_position(vec3 position) {
+        int count = min(int(u_morph_info.z), MAX_MORPH_TARGETS);
+        for (int index = 0; index < count; index++) {
+            position += u_morph_weights[index] * morph_delta(index * 2);
+        }
+        return position;
+    }
+
+    vec3 morph_normal(vec3 normal) {
+        int count = min(int(u_morph_info.z), MAX_MORPH_TARGETS);
+        for (int index = 0; index < count; index++) {
+            normal += u_morph_weights[index] * morph_delta(index * 2 + 1);
+        }
+        return normalize(normal);
+    }
+"##;
+
+const MORPH_VERTEX_MAIN: &str = r##"
+    in vec3 a_position;
+    in vec3 a_normal;
+    in vec2 a_tex_coords;
+    in vec4 a_tangent;
+
+    struct camera {
+        mat4 model;
+        mat4 view;
+        mat4 projection;
+    };
+
+    uniform camera u_camera;
+
+    out vec3 v_position;
+    out vec3 v_normal;
+    out vec2 v_tex_coords;
+    out vec4 v_tangent;
+    out vec3 v_eye_position;
+    out float v_view_depth;
+
+    void main() {
+        vec4 world_position = u_camera.model * vec4(morph_position(a_position), 1.0);
+
+        v_position = world_position.xyz;
+        v_normal = normalize(transpose(inverse(mat3(u_camera.model))) * morph_normal(a_normal));
+        v_tex_coords = a_tex_coords;
+        v_tangent = vec4(mat3(u_camera.model) * a_tangent.xyz, a_tangent.w);
+        v_eye_position = inverse(u_camera.view)[3].xyz;
+        v_view_depth = -(u_camera.view * world_position).z;
+
+        gl_Position = u_camera.projection * u_camera.view * world_position;
+    }
+"##;
+
+pub fn morph_block() -> String {
+    format!(
+        "#define MAX_MORPH_TARGETS {}\n{}",
+        MAX_MORPH_TARGETS, MORPH_BLOCK
+    )
+}
+
+pub fn morph_vertex_shader() -> String {
+    format!("#version 300 es\n{}{}", morph_block(), MORPH_VERTEX_MAIN)
+}
@@ -0,0 +1,58 @@
+use glm::Vec4;
+
+use crate::gl::materials::pipeline_state::BlendMode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParticleRenderMode {
+    PointSprites,
+    Quads,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParticleAppearance {
+    pub mode: ParticleRenderMode,
+    pub blend: BlendMode,
+    pub start_size: f32,
+    pub end_size: f32,
+    pub start_color: Vec4,
+    pub end_color: Vec4,
+}
+
+impl Default for ParticleAppearance {
+    fn default() -> Self {
+        Self {
+            mode: ParticleRenderMode::PointSprites,
+            blend: BlendMode::Additive,
+            start_size: 0.1,
+            end_size: 0.02,
+            start_color: Vec4::new(1.0, 0.8, 0.4, 1.0),
+            end_color: Vec4::new(1.0, 0.2, 0.05, 0.0),
+        }
+    }
+}
+
+impl ParticleAppearance {
+    pub fn with_mode(self, mode: ParticleRenderMode) -> Self {
+        Self { mode, ..self }
+    }
+
+    pub fn with_blend(self, blend: BlendMode) -> Self {
+        Self { blend, ..self }
+    }
+
+    pub fn with_size(self, start_size: f32, end_size: f32) -> Self {
+        Self {
+            start_size,
+            end_size,
+            ..self
+        }
+    }
+
+    pub fn with_colors(self, start_color: Vec4, end_color: Vec4) -> Self {
+        Self {
+            start_color,
+            end_color,
+            ..self
+        }
+    }
+}
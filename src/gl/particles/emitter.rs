@@ -0,0 +1,93 @@
+use glm::Vec3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EmitterShape {
+    Point,
+    Sphere { radius: f32 },
+    Cone { angle: f32, radius: f32 },
+}
+
+impl EmitterShape {
+    pub fn id(&self) -> i32 {
+        match self {
+            EmitterShape::Point => 0,
+            EmitterShape::Sphere { .. } => 1,
+            EmitterShape::Cone { .. } => 2,
+        }
+    }
+
+    pub fn radius(&self) -> f32 {
+        match *self {
+            EmitterShape::Point => 0.0,
+            EmitterShape::Sphere { radius } | EmitterShape::Cone { radius, .. } => radius,
+        }
+    }
+
+    pub fn angle(&self) -> f32 {
+        match *self {
+            EmitterShape::Cone { angle, .. } => angle,
+            _ => 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Emitter {
+    pub shape: EmitterShape,
+    pub position: Vec3,
+    pub direction: Vec3,
+    pub rate: f32,
+    pub min_lifetime: f32,
+    pub max_lifetime: f32,
+    pub min_speed: f32,
+    pub max_speed: f32,
+}
+
+impl Default for Emitter {
+    fn default() -> Self {
+        Self {
+            shape: EmitterShape::Point,
+            position: Vec3::zeros(),
+            direction: Vec3::y(),
+            rate: 200.0,
+            min_lifetime: 1.0,
+            max_lifetime: 2.0,
+            min_speed: 0.5,
+            max_speed: 1.0,
+        }
+    }
+}
+
+impl Emitter {
+    pub fn new(shape: EmitterShape, position: Vec3) -> Self {
+        Self {
+            shape,
+            position,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_direction(self, direction: Vec3) -> Self {
+        Self { direction, ..self }
+    }
+
+    pub fn with_rate(self, rate: f32) -> Self {
+        Self { rate, ..self }
+    }
+
+    pub fn with_lifetime(self, min_lifetime: f32, max_lifetime: f32) -> Self {
+        Self {
+            min_lifetime,
+            max_lifetime,
+            ..self
+        }
+    }
+
+    pub fn with_speed(self, min_speed: f32, max_speed: f32) -> Self {
+        Self {
+            min_speed,
+            max_speed,
+            ..self
+        }
+    }
+}
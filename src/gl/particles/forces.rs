@@ -0,0 +1,47 @@
+use glm::Vec3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParticleForces {
+    pub gravity: Vec3,
+    pub drag: f32,
+    pub curl_strength: f32,
+    pub curl_scale: f32,
+}
+
+impl Default for ParticleForces {
+    fn default() -> Self {
+        Self {
+            gravity: Vec3::new(0.0, -9.81, 0.0),
+            drag: 0.1,
+            curl_strength: 0.0,
+            curl_scale: 1.0,
+        }
+    }
+}
+
+impl ParticleForces {
+    pub fn none() -> Self {
+        Self {
+            gravity: Vec3::zeros(),
+            drag: 0.0,
+            curl_strength: 0.0,
+            curl_scale: 1.0,
+        }
+    }
+
+    pub fn with_gravity(self, gravity: Vec3) -> Self {
+        Self { gravity, ..self }
+    }
+
+    pub fn with_drag(self, drag: f32) -> Self {
+        Self { drag, ..self }
+    }
+
+    pub fn with_curl_noise(self, curl_strength: f32, curl_scale: f32) -> Self {
+        Self {
+            curl_strength,
+            curl_scale,
+            ..self
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod appearance;
+pub mod emitter;
+pub mod forces;
+pub mod particle_system;
+pub mod shaders;
@@ -0,0 +1,367 @@
+use std::rc::Rc;
+
+use glm::Vec2;
+use web_sys::{WebGlBuffer, WebGlVertexArrayObject};
+
+use crate::gl;
+use crate::gl::buffers::error::GLObjectError::{CreateBufferError, CreateVAOError};
+use crate::gl::buffers::transform_feedback::TransformFeedback;
+use crate::gl::camera::Camera;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::materials::material::Material;
+use crate::gl::materials::pipeline_state::PipelineState;
+use crate::gl::shader::program::ShaderProgram;
+use crate::gl::shader::shader_type::ShaderType;
+
+use super::appearance::{ParticleAppearance, ParticleRenderMode};
+use super::emitter::Emitter;
+use super::forces::ParticleForces;
+use super::shaders::{
+    PARTICLE_POINT_FRAGMENT_SHADER, PARTICLE_POINT_VERTEX_SHADER, PARTICLE_QUAD_FRAGMENT_SHADER,
+    PARTICLE_QUAD_VERTEX_SHADER, PARTICLE_UPDATE_FRAGMENT_SHADER, PARTICLE_UPDATE_VERTEX_SHADER,
+};
+
+const PARTICLE_FLOATS: usize = 8;
+const PARTICLE_STRIDE: i32 = (PARTICLE_FLOATS * std::mem::size_of::<f32>()) as i32;
+const PARTICLE_ATTRIBUTES: [(&str, i32); 2] = [("a_position_age", 0), ("a_velocity_life", 16)];
+const FEEDBACK_VARYINGS: [&str; 2] = ["v_position_age", "v_velocity_life"];
+
+const QUAD_CORNERS: [f32; 12] = [
+    -1.0, -1.0, 1.0, -1.0, 1.0, 1.0, 1.0, 1.0, -1.0, 1.0, -1.0, -1.0,
+];
+const QUAD_VERTICES: i32 = 6;
+
+const MAX_DELTA_TIME: f32 = 0.1;
+
+struct ParticleState {
+    buffer: WebGlBuffer,
+    update_vao: WebGlVertexArrayObject,
+    points_vao: WebGlVertexArrayObject,
+    quads_vao: WebGlVertexArrayObject,
+    gl: Rc<GL>,
+}
+
+impl ParticleState {
+    fn try_new(
+        gl: &Rc<GL>,
+        capacity: usize,
+        programs: [&ShaderProgram; 3],
+        quad: &WebGlBuffer,
+    ) -> Result<Self, GLError> {
+        let buffer = gl.create_buffer().ok_or(CreateBufferError)?;
+        upload_dead_particles(gl, &buffer, capacity);
+        let [update, points, quads] = programs;
+        Ok(Self {
+            update_vao: particle_vao(gl, update, &buffer, None)?,
+            points_vao: particle_vao(gl, points, &buffer, None)?,
+            quads_vao: particle_vao(gl, quads, &buffer, Some(quad))?,
+            buffer,
+            gl: Rc::clone(gl),
+        })
+    }
+}
+
+impl Drop for ParticleState {
+    fn drop(&mut self) {
+        self.gl.delete_vertex_array(Some(&self.update_vao));
+        self.gl.delete_vertex_array(Some(&self.points_vao));
+        self.gl.delete_vertex_array(Some(&self.quads_vao));
+        self.gl.delete_buffer(Some(&self.buffer));
+    }
+}
+
+fn upload_dead_particles(gl: &GL, buffer: &WebGlBuffer, capacity: usize) {
+    let mut data = vec![0.0f32; capacity * PARTICLE_FLOATS];
+    for particle in data.chunks_exact_mut(PARTICLE_FLOATS) {
+        particle[3] = 1.0;
+    }
+    gl.bind_buffer(gl::ARRAY_BUFFER, Some(buffer));
+    unsafe {
+        gl.buffer_data_with_array_buffer_view(
+            gl::ARRAY_BUFFER,
+            &js_sys::Float32Array::view(&data),
+            gl::DYNAMIC_COPY,
+        );
+    }
+    gl.bind_buffer(gl::ARRAY_BUFFER, None);
+}
+
+fn particle_vao(
+    gl: &GL,
+    program: &ShaderProgram,
+    particles: &WebGlBuffer,
+    quad: Option<&WebGlBuffer>,
+) -> Result<WebGlVertexArrayObject, GLError> {
+    let vao = gl.create_vertex_array().ok_or(CreateVAOError)?;
+    gl.bind_vertex_array(Some(&vao));
+
+    let divisor = if quad.is_some() { 1 } else { 0 };
+    gl.bind_buffer(gl::ARRAY_BUFFER, Some(particles));
+    for &(name, offset) in PARTICLE_ATTRIBUTES.iter() {
+        let location = gl.get_attrib_location(program.as_gl_program(), name);
+        if location < 0 {
+            continue;
+        }
+        let location = location as u32;
+        gl.enable_vertex_attrib_array(location);
+        gl.vertex_attrib_pointer_with_i32(location, 4, gl::FLOAT, false, PARTICLE_STRIDE, offset);
+        gl.vertex_attrib_divisor(location, divisor);
+    }
+
+    if let Some(quad) = quad {
+        let location = gl.get_attrib_location(program.as_gl_program(), "a_corner");
+        if location >= 0 {
+            gl.bind_buffer(gl::ARRAY_BUFFER, Some(quad));
+            gl.enable_vertex_attrib_array(location as u32);
+            gl.vertex_attrib_pointer_with_i32(location as u32, 2, gl::FLOAT, false, 0, 0);
+        }
+    }
+
+    gl.bind_vertex_array(None);
+    gl.bind_buffer(gl::ARRAY_BUFFER, None);
+    Ok(vao)
+}
+
+fn build_program(
+    gl: &Rc<GL>,
+    vertex: &str,
+    fragment: &str,
+    feedback_varyings: &[&str],
+) -> Result<Rc<ShaderProgram>, GLError> {
+    Ok(Rc::new(
+        ShaderProgram::builder(gl)
+            .add_source(ShaderType::Vertex, vertex.into())
+            .add_source(ShaderType::Fragment, fragment.into())
+            .with_feedback_varyings(feedback_varyings)
+            .build()?,
+    ))
+}
+
+pub struct ParticleSystem {
+    capacity: usize,
+    emitter: Emitter,
+    forces: ParticleForces,
+    appearance: ParticleAppearance,
+    states: [ParticleState; 2],
+    current: usize,
+    quad: WebGlBuffer,
+    feedback: TransformFeedback,
+    update: Material,
+    points: Material,
+    quads: Material,
+    spawn_cursor: usize,
+    spawn_accumulator: f32,
+    time: f32,
+    frame: u32,
+    gl: Rc<GL>,
+}
+
+impl ParticleSystem {
+    pub fn try_new(gl: &Rc<GL>, capacity: usize, emitter: Emitter) -> Result<Self, GLError> {
+        let capacity = capacity.max(1);
+        let update = build_program(
+            gl,
+            PARTICLE_UPDATE_VERTEX_SHADER,
+            PARTICLE_UPDATE_FRAGMENT_SHADER,
+            &FEEDBACK_VARYINGS,
+        )?;
+        let points = build_program(
+            gl,
+            PARTICLE_POINT_VERTEX_SHADER,
+            PARTICLE_POINT_FRAGMENT_SHADER,
+            &[],
+        )?;
+        let quads = build_program(
+            gl,
+            PARTICLE_QUAD_VERTEX_SHADER,
+            PARTICLE_QUAD_FRAGMENT_SHADER,
+            &[],
+        )?;
+
+        let quad = gl.create_buffer().ok_or(CreateBufferError)?;
+        gl.bind_buffer(gl::ARRAY_BUFFER, Some(&quad));
+        unsafe {
+            gl.buffer_data_with_array_buffer_view(
+                gl::ARRAY_BUFFER,
+                &js_sys::Float32Array::view(&QUAD_CORNERS),
+                gl::STATIC_DRAW,
+            );
+        }
+        gl.bind_buffer(gl::ARRAY_BUFFER, None);
+
+        let programs = [update.as_ref(), points.as_ref(), quads.as_ref()];
+        let states = [
+            ParticleState::try_new(gl, capacity, programs, &quad)?,
+            ParticleState::try_new(gl, capacity, programs, &quad)?,
+        ];
+
+        let appearance = ParticleAppearance::default();
+        let mut system = Self {
+            capacity,
+            emitter,
+            forces: ParticleForces::default(),
+            appearance,
+            states,
+            current: 0,
+            quad,
+            feedback: TransformFeedback::try_new(gl)?,
+            update: Material::new(gl, &update).with_state(PipelineState::fullscreen()),
+            points: Material::new(gl, &points),
+            quads: Material::new(gl, &quads),
+            spawn_cursor: 0,
+            spawn_accumulator: 0.0,
+            time: 0.0,
+            frame: 0,
+            gl: Rc::clone(gl),
+        };
+        system.set_appearance(appearance);
+        Ok(system)
+    }
+
+    pub fn with_forces(mut self, forces: ParticleForces) -> Self {
+        self.forces = forces;
+        self
+    }
+
+    pub fn with_appearance(mut self, appearance: ParticleAppearance) -> Self {
+        self.set_appearance(appearance);
+        self
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn emitter(&self) -> &Emitter {
+        &self.emitter
+    }
+
+    pub fn emitter_mut(&mut self) -> &mut Emitter {
+        &mut self.emitter
+    }
+
+    pub fn forces(&self) -> &ParticleForces {
+        &self.forces
+    }
+
+    pub fn forces_mut(&mut self) -> &mut ParticleForces {
+        &mut self.forces
+    }
+
+    pub fn appearance(&self) -> &ParticleAppearance {
+        &self.appearance
+    }
+
+    pub fn set_appearance(&mut self, appearance: ParticleAppearance) {
+        self.appearance = appearance;
+        let state = PipelineState::transparent(appearance.blend);
+        for material in [&mut self.points, &mut self.quads] {
+            material.set_state(state);
+            material.set(
+                "u_size",
+                Vec2::new(appearance.start_size, appearance.end_size),
+            );
+            material.set("u_start_color", appearance.start_color);
+            material.set("u_end_color", appearance.end_color);
+        }
+    }
+
+    pub fn reset(&mut self) {
+        for state in self.states.iter() {
+            upload_dead_particles(&self.gl, &state.buffer, self.capacity);
+        }
+        self.spawn_cursor = 0;
+        self.spawn_accumulator = 0.0;
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        let delta_time = delta_time.clamp(0.0, MAX_DELTA_TIME);
+        self.time += delta_time;
+        self.frame = self.frame.wrapping_add(1);
+
+        self.spawn_accumulator += self.emitter.rate.max(0.0) * delta_time;
+        let spawn_count = (self.spawn_accumulator.floor() as usize).min(self.capacity);
+        self.spawn_accumulator -= spawn_count as f32;
+
+        self.set_update_params(delta_time, spawn_count);
+        let source = &self.states[self.current];
+        let target = &self.states[1 - self.current];
+        {
+            let _material_use = self.update.bind();
+            self.gl.bind_vertex_array(Some(&source.update_vao));
+            let _feedback = self.feedback.begin(&target.buffer, gl::POINTS);
+            self.gl.draw_arrays(gl::POINTS, 0, self.capacity as i32);
+        }
+        self.gl.bind_vertex_array(None);
+
+        self.spawn_cursor = (self.spawn_cursor + spawn_count) % self.capacity;
+        self.current = 1 - self.current;
+    }
+
+    fn set_update_params(&mut self, delta_time: f32, spawn_count: usize) {
+        let Emitter {
+            shape,
+            position,
+            direction,
+            min_lifetime,
+            max_lifetime,
+            min_speed,
+            max_speed,
+            ..
+        } = self.emitter;
+        let material = &mut self.update;
+        material.set("u_delta_time", delta_time);
+        material.set("u_time", self.time);
+        material.set("u_frame", self.frame);
+        material.set("u_capacity", self.capacity as i32);
+        material.set("u_spawn_start", self.spawn_cursor as i32);
+        material.set("u_spawn_count", spawn_count as i32);
+
+        material.set("u_shape", shape.id());
+        material.set("u_shape_radius", shape.radius());
+        material.set("u_shape_angle", shape.angle());
+        material.set("u_emitter_position", position);
+        material.set("u_emitter_direction", direction);
+        material.set("u_lifetime", Vec2::new(min_lifetime, max_lifetime));
+        material.set("u_speed", Vec2::new(min_speed, max_speed));
+
+        material.set("u_gravity", self.forces.gravity);
+        material.set("u_drag", self.forces.drag);
+        material.set("u_curl_strength", self.forces.curl_strength);
+        material.set("u_curl_scale", self.forces.curl_scale);
+    }
+
+    pub fn render(&mut self, camera: &Camera) {
+        let state = &self.states[self.current];
+        match self.appearance.mode {
+            ParticleRenderMode::PointSprites => {
+                self.points.set("u_view", camera.view);
+                self.points.set("u_projection", *camera.projection_matrix());
+                self.points.set("u_viewport_height", camera.viewport().y);
+                let _material_use = self.points.bind_for(camera);
+                self.gl.bind_vertex_array(Some(&state.points_vao));
+                self.gl.draw_arrays(gl::POINTS, 0, self.capacity as i32);
+            }
+            ParticleRenderMode::Quads => {
+                self.quads.set("u_view", camera.view);
+                self.quads.set("u_projection", *camera.projection_matrix());
+                let _material_use = self.quads.bind_for(camera);
+                self.gl.bind_vertex_array(Some(&state.quads_vao));
+                self.gl.draw_arrays_instanced(
+                    gl::TRIANGLES,
+                    0,
+                    QUAD_VERTICES,
+                    self.capacity as i32,
+                );
+            }
+        }
+        self.gl.bind_vertex_array(None);
+    }
+}
+
+impl Drop for ParticleSystem {
+    fn drop(&mut self) {
+        self.gl.delete_buffer(Some(&self.quad));
+    }
+}
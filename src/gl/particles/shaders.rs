@@ -0,0 +1,231 @@
+pub const PARTICLE_UPDATE_VERTEX_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    const float PI = 3.14159265359;
+    const int SHAPE_SPHERE = 1;
+    const int SHAPE_CONE = 2;
+    const float CURL_EPSILON = 0.01;
+
+    in vec4 a_position_age;
+    in vec4 a_velocity_life;
+
+    uniform float u_delta_time;
+    uniform float u_time;
+    uniform uint u_frame;
+    uniform int u_capacity;
+    uniform int u_spawn_start;
+    uniform int u_spawn_count;
+
+    uniform int u_shape;
+    uniform float u_shape_radius;
+    uniform float u_shape_angle;
+    uniform vec3 u_emitter_position;
+    uniform vec3 u_emitter_direction;
+    uniform vec2 u_lifetime;
+    uniform vec2 u_speed;
+
+    uniform vec3 u_gravity;
+    uniform float u_drag;
+    uniform float u_curl_strength;
+    uniform float u_curl_scale;
+
+    out vec4 v_position_age;
+    out vec4 v_velocity_life;
+
+    uint hash(uint x) {
+        x ^= x >> 16;
+        x *= 0x7feb352du;
+        x ^= x >> 15;
+        x *= 0x846ca68bu;
+        x ^= x >> 16;
+        return x;
+    }
+
+    float random(inout uint seed) {
+        seed = hash(seed);
+        return float(seed >> 8) / 16777216.0;
+    }
+
+    vec3 random_direction(inout uint seed) {
+        float z = random(seed) * 2.0 - 1.0;
+        float phi = random(seed) * 2.0 * PI;
+        float r = sqrt(max(1.0 - z * z, 0.0));
+        return vec3(r * cos(phi), r * sin(phi), z);
+    }
+
+    mat3 basis(vec3 normal) {
+        vec3 up = abs(normal.z) < 0.999 ? vec3(0.0, 0.0, 1.0) : vec3(1.0, 0.0, 0.0);
+        vec3 tangent = normalize(cross(up, normal));
+        return mat3(tangent, cross(normal, tangent), normal);
+    }
+
+    void spawn(inout uint seed, out vec3 position, out vec3 direction) {
+        if (u_shape == SHAPE_SPHERE) {
+            direction = random_direction(seed);
+            float distance = u_shape_radius * pow(random(seed), 1.0 / 3.0);
+            position = u_emitter_position + direction * distance;
+        } else if (u_shape == SHAPE_CONE) {
+            mat3 frame = basis(normalize(u_emitter_direction));
+            float cos_theta = mix(1.0, cos(u_shape_angle), random(seed));
+            float sin_theta = sqrt(max(1.0 - cos_theta * cos_theta, 0.0));
+            float phi = random(seed) * 2.0 * PI;
+            direction = frame * vec3(sin_theta * cos(phi), sin_theta * sin(phi), cos_theta);
+
+            float radius = u_shape_radius * sqrt(random(seed));
+            float angle = random(seed) * 2.0 * PI;
+            position = u_emitter_position + frame * vec3(radius * cos(angle), radius * sin(angle), 0.0);
+        } else {
+            direction = random_direction(seed);
+            position = u_emitter_position;
+        }
+    }
+
+    vec3 potential(vec3 p) {
+        float t = u_time * 0.2;
+        return vec3(
+            sin(p.y * 1.3 + t) + cos(p.z * 1.7 - t),
+            sin(p.z * 1.1 + t) + cos(p.x * 1.9 + t),
+            sin(p.x * 1.5 - t) + cos(p.y * 1.2 + t)
+        );
+    }
+
+    vec3 curl_noise(vec3 p) {
+        vec3 dx = potential(p + vec3(CURL_EPSILON, 0.0, 0.0)) - potential(p - vec3(CURL_EPSILON, 0.0, 0.0));
+        vec3 dy = potential(p + vec3(0.0, CURL_EPSILON, 0.0)) - potential(p - vec3(0.0, CURL_EPSILON, 0.0));
+        vec3 dz = potential(p + vec3(0.0, 0.0, CURL_EPSILON)) - potential(p - vec3(0.0, 0.0, CURL_EPSILON));
+        return vec3(dy.z - dz.y, dz.x - dx.z, dx.y - dy.x) / (2.0 * CURL_EPSILON);
+    }
+
+    void main() {
+        vec3 position = a_position_age.xyz;
+        float age = a_position_age.w;
+        vec3 velocity = a_velocity_life.xyz;
+        float life = a_velocity_life.w;
+
+        int slot = (gl_VertexID - u_spawn_start + u_capacity) % u_capacity;
+        if (slot < u_spawn_count) {
+            uint seed = hash(uint(gl_VertexID) ^ hash(u_frame));
+            vec3 direction;
+            spawn(seed, position, direction);
+            velocity = direction * mix(u_speed.x, u_speed.y, random(seed));
+            life = mix(u_lifetime.x, u_lifetime.y, random(seed));
+            age = 0.0;
+        } else if (age < life) {
+            vec3 curl = curl_noise(position * u_curl_scale) * u_curl_strength;
+            vec3 acceleration = u_gravity - velocity * u_drag + curl;
+            velocity += acceleration * u_delta_time;
+            position += velocity * u_delta_time;
+            age += u_delta_time;
+        }
+
+        v_position_age = vec4(position, age);
+        v_velocity_life = vec4(velocity, life);
+    }
+"##;
+
+pub const PARTICLE_UPDATE_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    out vec4 out_color;
+
+    void main() {
+        out_color = vec4(0.0);
+    }
+"##;
+
+pub const PARTICLE_POINT_VERTEX_SHADER: &str = r##"#version 300 es
+    in vec4 a_position_age;
+    in vec4 a_velocity_life;
+
+    uniform mat4 u_view;
+    uniform mat4 u_projection;
+    uniform float u_viewport_height;
+    uniform vec2 u_size;
+
+    out float v_t;
+
+    void main() {
+        float life = a_velocity_life.w;
+        v_t = a_position_age.w / max(life, 0.0001);
+        if (a_position_age.w >= life) {
+            gl_Position = vec4(2.0, 2.0, 2.0, 1.0);
+            gl_PointSize = 0.0;
+            return;
+        }
+
+        gl_Position = u_projection * u_view * vec4(a_position_age.xyz, 1.0);
+        float size = mix(u_size.x, u_size.y, v_t);
+        gl_PointSize = size * u_projection[1][1] * 0.5 * u_viewport_height / gl_Position.w;
+    }
+"##;
+
+pub const PARTICLE_QUAD_VERTEX_SHADER: &str = r##"#version 300 es
+    in vec2 a_corner;
+    in vec4 a_position_age;
+    in vec4 a_velocity_life;
+
+    uniform mat4 u_view;
+    uniform mat4 u_projection;
+    uniform vec2 u_size;
+
+    out float v_t;
+    out vec2 v_offset;
+
+    void main() {
+        float life = a_velocity_life.w;
+        v_t = a_position_age.w / max(life, 0.0001);
+        v_offset = a_corner;
+        if (a_position_age.w >= life) {
+            gl_Position = vec4(2.0, 2.0, 2.0, 1.0);
+            return;
+        }
+
+        vec3 right = vec3(u_view[0][0], u_view[1][0], u_view[2][0]);
+        vec3 up = vec3(u_view[0][1], u_view[1][1], u_view[2][1]);
+        float half_size = mix(u_size.x, u_size.y, v_t) * 0.5;
+        vec3 position = a_position_age.xyz + (right * a_corner.x + up * a_corner.y) * half_size;
+        gl_Position = u_projection * u_view * vec4(position, 1.0);
+    }
+"##;
+
+pub const PARTICLE_POINT_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in float v_t;
+
+    uniform vec4 u_start_color;
+    uniform vec4 u_end_color;
+
+    out vec4 out_color;
+
+    void main() {
+        vec2 offset = gl_PointCoord * 2.0 - 1.0;
+        float distance = dot(offset, offset);
+        if (distance > 1.0) {
+            discard;
+        }
+        vec4 color = mix(u_start_color, u_end_color, clamp(v_t, 0.0, 1.0));
+        out_color = vec4(color.rgb, color.a * (1.0 - distance));
+    }
+"##;
+
+pub const PARTICLE_QUAD_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in float v_t;
+    in vec2 v_offset;
+
+    uniform vec4 u_start_color;
+    uniform vec4 u_end_color;
+
+    out vec4 out_color;
+
+    void main() {
+        float distance = dot(v_offset, v_offset);
+        if (distance > 1.0) {
+            discard;
+        }
+        vec4 color = mix(u_start_color, u_end_color, clamp(v_t, 0.0, 1.0));
+        out_color = vec4(color.rgb, color.a * (1.0 - distance));
+    }
+"##;
@@ -0,0 +1,3 @@
+pub mod pbr_material;
+pub mod pbr_pipeline;
+pub mod shaders;
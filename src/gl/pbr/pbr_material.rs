@@ -0,0 +1,67 @@
+use std::rc::Rc;
+
+use glm::{Vec3, Vec4};
+
+use crate::gl::textures::texture::Texture;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlphaMode {
+    Opaque,
+    Mask(f32),
+    Blend,
+}
+
+#[derive(Clone)]
+pub struct PbrMaterial {
+    pub base_color_factor: Vec4,
+    pub base_color_texture: Option<Rc<Texture>>,
+    pub metallic_factor: f32,
+    pub roughness_factor: f32,
+    pub metallic_roughness_texture: Option<Rc<Texture>>,
+    pub normal_texture: Option<Rc<Texture>>,
+    pub normal_scale: f32,
+    pub occlusion_texture: Option<Rc<Texture>>,
+    pub occlusion_strength: f32,
+    pub emissive_factor: Vec3,
+    pub emissive_texture: Option<Rc<Texture>>,
+    pub alpha_mode: AlphaMode,
+    pub double_sided: bool,
+}
+
+impl Default for PbrMaterial {
+    fn default() -> Self {
+        Self {
+            base_color_factor: Vec4::new(1.0, 1.0, 1.0, 1.0),
+            base_color_texture: None,
+            metallic_factor: 1.0,
+            roughness_factor: 1.0,
+            metallic_roughness_texture: None,
+            normal_texture: None,
+            normal_scale: 1.0,
+            occlusion_texture: None,
+            occlusion_strength: 1.0,
+            emissive_factor: Vec3::zeros(),
+            emissive_texture: None,
+            alpha_mode: AlphaMode::Opaque,
+            double_sided: false,
+        }
+    }
+}
+
+impl PbrMaterial {
+    pub fn new(base_color: Vec3, metallic: f32, roughness: f32) -> Self {
+        Self {
+            base_color_factor: base_color.push(1.0),
+            metallic_factor: metallic,
+            roughness_factor: roughness,
+            ..Self::default()
+        }
+    }
+
+    pub fn alpha_cutoff(&self) -> f32 {
+        match self.alpha_mode {
+            AlphaMode::Mask(cutoff) => cutoff,
+            AlphaMode::Opaque | AlphaMode::Blend => 0.0,
+        }
+    }
+}
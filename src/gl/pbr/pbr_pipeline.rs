@@ -0,0 +1,124 @@
+use std::rc::Rc;
+
+use crate::gl;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::ibl::environment::Environment;
+use crate::gl::lighting::light_buffer::LightBuffer;
+use crate::gl::lighting::shaders::LIT_VERTEX_SHADER;
+use crate::gl::materials::material::Material;
+use crate::gl::materials::pipeline_state::{BlendMode, CullFace, PipelineState};
+use crate::gl::shader::program::ShaderProgram;
+use crate::gl::shader::shader_type::ShaderType;
+use crate::gl::textures::texture::{Texture, TextureFormat};
+
+use super::pbr_material::{AlphaMode, PbrMaterial};
+use super::shaders::pbr_fragment_shader;
+
+const WHITE: [u8; 4] = [255, 255, 255, 255];
+const FLAT_NORMAL: [u8; 4] = [128, 128, 255, 255];
+
+pub struct PbrPipeline {
+    program: Rc<ShaderProgram>,
+    white: Rc<Texture>,
+    flat_normal: Rc<Texture>,
+    black_cube: Rc<Texture>,
+    environment: Option<Rc<Environment>>,
+    environment_intensity: f32,
+    gl: Rc<GL>,
+}
+
+impl PbrPipeline {
+    pub fn try_new(gl: &Rc<GL>, lights: &LightBuffer) -> Result<Self, GLError> {
+        let program = Rc::new(
+            ShaderProgram::builder(gl)
+                .add_source(ShaderType::Vertex, LIT_VERTEX_SHADER.into())
+                .add_source(
+                    ShaderType::Fragment,
+                    pbr_fragment_shader(lights.max_lights()),
+                )
+                .build()?,
+        );
+        lights.attach(&program);
+
+        let mut black_cube =
+            Texture::try_new(gl, gl::TEXTURE_CUBE_MAP, TextureFormat::RGBA8, 0, 0)?;
+        black_cube.resize(1, 1)?;
+
+        Ok(Self {
+            program,
+            white: Rc::new(Texture::solid_color(gl, WHITE)?),
+            flat_normal: Rc::new(Texture::solid_color(gl, FLAT_NORMAL)?),
+            black_cube: Rc::new(black_cube),
+            environment: None,
+            environment_intensity: 1.0,
+            gl: Rc::clone(gl),
+        })
+    }
+
+    pub fn program(&self) -> &Rc<ShaderProgram> {
+        &self.program
+    }
+
+    pub fn set_environment(&mut self, environment: Option<Rc<Environment>>, intensity: f32) {
+        self.environment = environment;
+        self.environment_intensity = intensity;
+    }
+
+    pub fn material(&self, pbr: &PbrMaterial) -> Material {
+        let texture_or = |texture: &Option<Rc<Texture>>, default: &Rc<Texture>| {
+            Rc::clone(texture.as_ref().unwrap_or(default))
+        };
+
+        let state = match pbr.alpha_mode {
+            AlphaMode::Opaque | AlphaMode::Mask(_) => PipelineState::opaque(),
+            AlphaMode::Blend => PipelineState::transparent(BlendMode::Alpha),
+        };
+        let cull = (!pbr.double_sided).then_some(CullFace::Back);
+
+        let material = Material::new(&self.gl, &self.program)
+            .with_state(state.with_cull(cull))
+            .with_param("u_material.base_color_factor", pbr.base_color_factor)
+            .with_param("u_material.metallic_factor", pbr.metallic_factor)
+            .with_param("u_material.roughness_factor", pbr.roughness_factor)
+            .with_param("u_material.normal_scale", pbr.normal_scale)
+            .with_param("u_material.occlusion_strength", pbr.occlusion_strength)
+            .with_param("u_material.emissive_factor", pbr.emissive_factor)
+            .with_param("u_material.alpha_cutoff", pbr.alpha_cutoff())
+            .with_param(
+                "u_base_color_texture",
+                texture_or(&pbr.base_color_texture, &self.white),
+            )
+            .with_param(
+                "u_metallic_roughness_texture",
+                texture_or(&pbr.metallic_roughness_texture, &self.white),
+            )
+            .with_param(
+                "u_normal_texture",
+                texture_or(&pbr.normal_texture, &self.flat_normal),
+            )
+            .with_param(
+                "u_occlusion_texture",
+                texture_or(&pbr.occlusion_texture, &self.white),
+            )
+            .with_param(
+                "u_emissive_texture",
+                texture_or(&pbr.emissive_texture, &self.white),
+            );
+
+        match &self.environment {
+            Some(environment) => material
+                .with_param("u_irradiance_map", Rc::clone(environment.irradiance()))
+                .with_param("u_prefiltered_map", Rc::clone(environment.prefiltered()))
+                .with_param("u_brdf_lut", Rc::clone(environment.brdf_lut()))
+                .with_param("u_max_reflection_lod", environment.max_reflection_lod())
+                .with_param("u_environment_intensity", self.environment_intensity),
+            None => material
+                .with_param("u_irradiance_map", Rc::clone(&self.black_cube))
+                .with_param("u_prefiltered_map", Rc::clone(&self.black_cube))
+                .with_param("u_brdf_lut", Rc::clone(&self.white))
+                .with_param("u_max_reflection_lod", 0.0)
+                .with_param("u_environment_intensity", 0.0),
+        }
+    }
+}
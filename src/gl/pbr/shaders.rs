@@ -0,0 +1,177 @@
+use crate::gl::lighting::shaders::lights_block;
+
+const PBR_FRAGMENT_BODY: &str = r##"
+    const float PI = 3.14159265359;
+    const float MIN_ROUGHNESS = 0.04;
+
+    in vec3 v_position;
+    in vec3 v_normal;
+    in vec2 v_tex_coords;
+    in vec4 v_tangent;
+    in vec3 v_eye_position;
+    in float v_view_depth;
+
+    struct pbr_material {
+        vec4 base_color_factor;
+        float metallic_factor;
+        float roughness_factor;
+        float normal_scale;
+        float occlusion_strength;
+        vec3 emissive_factor;
+        float alpha_cutoff;
+    };
+
+    uniform pbr_material u_material;
+
+    uniform sampler2D u_base_color_texture;
+    uniform sampler2D u_metallic_roughness_texture;
+    uniform sampler2D u_normal_texture;
+    uniform sampler2D u_occlusion_texture;
+    uniform sampler2D u_emissive_texture;
+
+    uniform samplerCube u_irradiance_map;
+    uniform samplerCube u_prefiltered_map;
+    uniform sampler2D u_brdf_lut;
+    uniform float u_max_reflection_lod;
+    uniform float u_environment_intensity;
+
+    out vec4 out_color;
+
+    mat3 derivative_tbn(vec3 normal, vec3 position, vec2 uv) {
+        vec3 dp1 = dFdx(position);
+        vec3 dp2 = dFdy(position);
+        vec2 duv1 = dFdx(uv);
+        vec2 duv2 = dFdy(uv);
+
+        vec3 dp2_perp = cross(dp2, normal);
+        vec3 dp1_perp = cross(normal, dp1);
+        vec3 tangent = dp2_perp * duv1.x + dp1_perp * duv2.x;
+        vec3 bitangent = dp2_perp * duv1.y + dp1_perp * duv2.y;
+
+        float frame_scale = max(dot(tangent, tangent), dot(bitangent, bitangent));
+        if (frame_scale < 1e-12) {
+            return mat3(0.0);
+        }
+        float inv_scale = inversesqrt(frame_scale);
+        return mat3(tangent * inv_scale, bitangent * inv_scale, normal);
+    }
+
+    vec3 orthogonal_tangent(vec3 normal) {
+        vec3 axis = abs(normal.x) < 0.9 ? vec3(1.0, 0.0, 0.0) : vec3(0.0, 1.0, 0.0);
+        return normalize(axis - normal * dot(normal, axis));
+    }
+
+    mat3 vertex_tbn(vec3 normal, vec4 tangent) {
+        vec3 projected = tangent.xyz - normal * dot(normal, tangent.xyz);
+        vec3 t = dot(projected, projected) > 1e-12
+            ? normalize(projected)
+            : orthogonal_tangent(normal);
+        vec3 b = cross(normal, t) * (tangent.w < 0.0 ? -1.0 : 1.0);
+        if (!gl_FrontFacing) {
+            b = -b;
+        }
+        return mat3(t, b, normal);
+    }
+
+    vec3 perturb_normal(vec3 normal, vec3 position, vec2 uv) {
+        mat3 tbn = dot(v_tangent.xyz, v_tangent.xyz) > 1e-12
+            ? vertex_tbn(normal, v_tangent)
+            : derivative_tbn(normal, position, uv);
+        if (tbn[0] == vec3(0.0)) {
+            return normal;
+        }
+
+        vec3 mapped = texture(u_normal_texture, uv).xyz * 2.0 - 1.0;
+        mapped.xy *= u_material.normal_scale;
+        return normalize(tbn * mapped);
+    }
+
+    float distribution_ggx(float n_dot_h, float roughness) {
+        float a = roughness * roughness;
+        float a2 = a * a;
+        float denominator = n_dot_h * n_dot_h * (a2 - 1.0) + 1.0;
+        return a2 / (PI * denominator * denominator);
+    }
+
+    float geometry_schlick_ggx(float n_dot_x, float roughness) {
+        float r = roughness + 1.0;
+        float k = r * r / 8.0;
+        return n_dot_x / (n_dot_x * (1.0 - k) + k);
+    }
+
+    vec3 fresnel_schlick(float cos_theta, vec3 f0) {
+        return f0 + (1.0 - f0) * pow(clamp(1.0 - cos_theta, 0.0, 1.0), 5.0);
+    }
+
+    vec3 fresnel_schlick_roughness(float cos_theta, vec3 f0, float roughness) {
+        vec3 f90 = max(vec3(1.0 - roughness), f0);
+        return f0 + (f90 - f0) * pow(clamp(1.0 - cos_theta, 0.0, 1.0), 5.0);
+    }
+
+    void main() {
+        vec4 base_color = u_material.base_color_factor * texture(u_base_color_texture, v_tex_coords);
+        if (base_color.a < u_material.alpha_cutoff) {
+            discard;
+        }
+
+        vec4 metallic_roughness = texture(u_metallic_roughness_texture, v_tex_coords);
+        float metallic = clamp(u_material.metallic_factor * metallic_roughness.b, 0.0, 1.0);
+        float roughness = clamp(u_material.roughness_factor * metallic_roughness.g, MIN_ROUGHNESS, 1.0);
+
+        vec3 normal = normalize(v_normal);
+        if (!gl_FrontFacing) {
+            normal = -normal;
+        }
+        normal = perturb_normal(normal, v_position, v_tex_coords);
+
+        vec3 view_dir = normalize(v_eye_position - v_position);
+        float n_dot_v = max(dot(normal, view_dir), 0.0001);
+
+        vec3 albedo = base_color.rgb;
+        vec3 f0 = mix(vec3(0.04), albedo, metallic);
+
+        vec3 color = vec3(0.0);
+        for (int i = 0; i < light_count(); ++i) {
+            light_data light = u_lights[i];
+
+            float attenuation;
+            vec3 light_dir = light_direction(light, v_position, attenuation);
+            vec3 half_dir = normalize(light_dir + view_dir);
+            float n_dot_l = max(dot(normal, light_dir), 0.0);
+            float n_dot_h = max(dot(normal, half_dir), 0.0);
+
+            float d = distribution_ggx(n_dot_h, roughness);
+            float g = geometry_schlick_ggx(n_dot_v, roughness) * geometry_schlick_ggx(n_dot_l, roughness);
+            vec3 f = fresnel_schlick(max(dot(half_dir, view_dir), 0.0), f0);
+
+            vec3 specular = d * g * f / (4.0 * n_dot_v * n_dot_l + 0.0001);
+            vec3 diffuse = (1.0 - f) * (1.0 - metallic) * albedo / PI;
+            float shadow = light_shadow(light, v_position, normal, light_dir, v_view_depth);
+            vec3 radiance = light.color.rgb * light.color.w * attenuation * shadow;
+            color += (diffuse + specular) * radiance * n_dot_l;
+        }
+
+        vec3 f = fresnel_schlick_roughness(n_dot_v, f0, roughness);
+        vec3 irradiance = texture(u_irradiance_map, normal).rgb;
+        vec3 reflected = reflect(-view_dir, normal);
+        vec3 prefiltered = textureLod(u_prefiltered_map, reflected, roughness * u_max_reflection_lod).rgb;
+        vec2 brdf = texture(u_brdf_lut, vec2(n_dot_v, roughness)).rg;
+
+        vec3 ambient_diffuse = (1.0 - f) * (1.0 - metallic) * irradiance * albedo;
+        vec3 ambient_specular = prefiltered * (f * brdf.x + brdf.y);
+        float occlusion = mix(1.0, texture(u_occlusion_texture, v_tex_coords).r, u_material.occlusion_strength);
+        color += (ambient_diffuse + ambient_specular) * occlusion * u_environment_intensity;
+
+        color += texture(u_emissive_texture, v_tex_coords).rgb * u_material.emissive_factor;
+
+        out_color = vec4(color, base_color.a);
+    }
+"##;
+
+pub fn pbr_fragment_shader(max_lights: usize) -> String {
+    format!(
+        "#version 300 es\nprecision highp float;\n{}{}",
+        lights_block(max_lights, None),
+        PBR_FRAGMENT_BODY
+    )
+}
@@ -0,0 +1,6 @@
+pub mod object_id;
+pub mod picker;
+pub mod picking_buffer;
+pub mod picking_pass;
+
+mod shaders;
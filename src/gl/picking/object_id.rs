@@ -0,0 +1,22 @@
+use std::num::NonZeroU32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ObjectId(NonZeroU32);
+
+impl ObjectId {
+    pub fn from_raw(raw: u32) -> Option<Self> {
+        NonZeroU32::new(raw).map(Self)
+    }
+
+    pub fn from_index(index: usize) -> Self {
+        Self(NonZeroU32::new(index as u32 + 1).expect("Object index overflow"))
+    }
+
+    pub fn raw(self) -> u32 {
+        self.0.get()
+    }
+
+    pub fn index(self) -> usize {
+        self.0.get() as usize - 1
+    }
+}
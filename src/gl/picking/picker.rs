@@ -0,0 +1,77 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use wasm_bindgen::JsCast;
+use web_sys::{Element, MouseEvent};
+
+use super::object_id::ObjectId;
+
+const CLICK_DRAG_THRESHOLD: i32 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PickKind {
+    Hover,
+    Click,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PickRequest {
+    pub kind: PickKind,
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PickEvent {
+    pub kind: PickKind,
+    pub object: Option<ObjectId>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Picker {
+    requests: Rc<RefCell<Vec<PickRequest>>>,
+    press_position: Rc<Cell<Option<(i32, i32)>>>,
+}
+
+impl Picker {
+    pub fn request(&self, kind: PickKind, x: f32, y: f32) {
+        let mut requests = self.requests.borrow_mut();
+        if kind == PickKind::Hover {
+            requests.retain(|r| r.kind != PickKind::Hover);
+        }
+        requests.push(PickRequest { kind, x, y });
+    }
+
+    pub fn forward_mouse_event(&self, kind: PickKind, event: &MouseEvent) {
+        let target = event.target().and_then(|t| t.dyn_into::<Element>().ok());
+        if let Some(element) = target {
+            let width = element.client_width().max(1) as f32;
+            let height = element.client_height().max(1) as f32;
+            self.request(
+                kind,
+                event.offset_x() as f32 / width,
+                event.offset_y() as f32 / height,
+            );
+        }
+    }
+
+    pub fn press(&self, event: &MouseEvent) {
+        self.press_position
+            .set(Some((event.client_x(), event.client_y())));
+    }
+
+    pub fn click(&self, event: &MouseEvent) {
+        let dragged = self.press_position.take().is_some_and(|(x, y)| {
+            let dx = event.client_x() - x;
+            let dy = event.client_y() - y;
+            dx * dx + dy * dy > CLICK_DRAG_THRESHOLD * CLICK_DRAG_THRESHOLD
+        });
+        if !dragged {
+            self.forward_mouse_event(PickKind::Click, event);
+        }
+    }
+
+    pub fn take_requests(&self) -> Vec<PickRequest> {
+        std::mem::take(&mut *self.requests.borrow_mut())
+    }
+}
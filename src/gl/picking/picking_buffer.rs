@@ -0,0 +1,81 @@
+use std::rc::Rc;
+
+use crate::gl;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::framebuffers::framebuffer::Framebuffer;
+use crate::gl::framebuffers::renderbuffer::Renderbuffer;
+
+use super::object_id::ObjectId;
+use super::picker::{PickEvent, PickRequest};
+use super::picking_pass::PickingPass;
+
+const BACKGROUND_ID: u32 = 0;
+
+pub struct PickingBuffer {
+    framebuffer: Framebuffer,
+    ids: Renderbuffer,
+    depth: Renderbuffer,
+    pass: PickingPass,
+    gl: Rc<GL>,
+}
+
+impl PickingBuffer {
+    pub fn try_new(gl: &Rc<GL>) -> Result<Self, GLError> {
+        let width = gl.drawing_buffer_width();
+        let height = gl.drawing_buffer_height();
+
+        let framebuffer = Framebuffer::try_new(gl)?;
+        let ids = Renderbuffer::try_new(gl, gl::R32UI, width, height)?;
+        let depth = Renderbuffer::try_new(gl, gl::DEPTH_COMPONENT24, width, height)?;
+
+        {
+            let binded = framebuffer.bind();
+            binded.attach_renderbuffer(gl::COLOR_ATTACHMENT0, &ids);
+            binded.attach_renderbuffer(gl::DEPTH_ATTACHMENT, &depth);
+            binded.check_status()?;
+        }
+
+        Ok(Self {
+            framebuffer,
+            ids,
+            depth,
+            pass: PickingPass::try_new(gl)?,
+            gl: Rc::clone(gl),
+        })
+    }
+
+    pub fn pick(
+        &mut self,
+        requests: &[PickRequest],
+        render: impl FnOnce(&PickingPass) -> Result<(), GLError>,
+    ) -> Result<Vec<PickEvent>, GLError> {
+        let width = self.gl.drawing_buffer_width();
+        let height = self.gl.drawing_buffer_height();
+        self.ids.resize(width, height);
+        self.depth.resize(width, height);
+
+        let binded = self.framebuffer.bind();
+        self.gl.viewport(0, 0, width, height);
+        binded.clear_color_uint(0, BACKGROUND_ID);
+        self.gl.clear(gl::DEPTH_BUFFER_BIT);
+
+        render(&self.pass)?;
+
+        let mut events = Vec::with_capacity(requests.len());
+        for request in requests {
+            let x = (request.x * width as f32) as i32;
+            let y = height - 1 - (request.y * height as f32) as i32;
+            let object = if (0..width).contains(&x) && (0..height).contains(&y) {
+                ObjectId::from_raw(binded.read_pixel_uint(x, y)?)
+            } else {
+                None
+            };
+            events.push(PickEvent {
+                kind: request.kind,
+                object,
+            });
+        }
+        Ok(events)
+    }
+}
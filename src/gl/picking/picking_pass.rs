@@ -0,0 +1,40 @@
+use std::rc::Rc;
+
+use crate::gl::camera::Camera;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::materials::material::Material;
+use crate::gl::mesh::Mesh;
+use crate::gl::shader::locations::SimpleUniformLocation;
+use crate::gl::shader::program::ShaderProgram;
+use crate::gl::shader::shader_type::ShaderType;
+
+use super::object_id::ObjectId;
+use super::shaders::{PICKING_FRAGMENT_SHADER, PICKING_VERTEX_SHADER};
+
+pub struct PickingPass {
+    material: Material,
+    u_object_id: SimpleUniformLocation<u32>,
+}
+
+impl PickingPass {
+    pub fn try_new(gl: &Rc<GL>) -> Result<Self, GLError> {
+        let shader = Rc::new(
+            ShaderProgram::builder(gl)
+                .add_source(ShaderType::Vertex, PICKING_VERTEX_SHADER.into())
+                .add_source(ShaderType::Fragment, PICKING_FRAGMENT_SHADER.into())
+                .build()?,
+        );
+        let u_object_id = shader.get_uniform_location::<u32>("u_object_id");
+        Ok(Self {
+            material: Material::new(gl, &shader),
+            u_object_id,
+        })
+    }
+
+    pub fn draw(&self, mesh: &Mesh, camera: &Camera, id: ObjectId) {
+        let material_use = self.material.bind_for(camera);
+        material_use.set_uniform(&self.u_object_id, &id.raw());
+        mesh.draw(material_use.shader_use(), camera);
+    }
+}
@@ -0,0 +1,28 @@
+pub const PICKING_VERTEX_SHADER: &str = r##"#version 300 es
+    in vec3 a_position;
+
+    struct camera {
+        mat4 model;
+        mat4 view;
+        mat4 projection;
+    };
+
+    uniform camera u_camera;
+
+    void main() {
+        gl_Position = u_camera.projection * u_camera.view * u_camera.model * vec4(a_position, 1.0);
+    }
+"##;
+
+pub const PICKING_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+    precision highp int;
+
+    uniform uint u_object_id;
+
+    out uint out_object_id;
+
+    void main() {
+        out_object_id = u_object_id;
+    }
+"##;
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PostProcessError {
+    #[error("WebGL extension is not supported: {}", .0)]
+    MissingExtension(&'static str),
+}
@@ -0,0 +1,6 @@
+pub mod error;
+pub mod passes;
+pub mod post_chain;
+pub mod post_pass;
+pub mod render_target;
+pub mod shaders;
@@ -0,0 +1,139 @@
+use std::any::Any;
+use std::rc::Rc;
+
+use crate::gl::error::GLError;
+use crate::gl::materials::material::Material;
+use crate::gl::materials::pipeline_state::{BlendMode, PipelineState};
+use crate::gl::postprocess::post_pass::{PostContext, PostPass, TEXEL_SIZE_UNIFORM};
+use crate::gl::postprocess::render_target::RenderTarget;
+use crate::gl::postprocess::shaders::{
+    BLOOM_COMPOSITE_FRAGMENT_SHADER, BLOOM_DOWNSAMPLE_FRAGMENT_SHADER,
+    BLOOM_PREFILTER_FRAGMENT_SHADER, BLOOM_UPSAMPLE_FRAGMENT_SHADER,
+};
+use crate::gl::textures::texture::{Texture, TextureFormat};
+
+const DEFAULT_LEVELS: usize = 5;
+
+pub struct BloomPass {
+    pub threshold: f32,
+    pub knee: f32,
+    pub intensity: f32,
+    pub radius: f32,
+    levels: usize,
+    mips: Vec<RenderTarget>,
+    prefilter: Material,
+    downsample: Material,
+    upsample: Material,
+    composite: Material,
+}
+
+impl BloomPass {
+    pub const NAME: &'static str = "bloom";
+
+    pub fn try_new(context: &PostContext) -> Result<Self, GLError> {
+        Ok(Self {
+            threshold: 1.0,
+            knee: 0.5,
+            intensity: 0.6,
+            radius: 1.0,
+            levels: DEFAULT_LEVELS,
+            mips: vec![],
+            prefilter: context.material(BLOOM_PREFILTER_FRAGMENT_SHADER)?,
+            downsample: context.material(BLOOM_DOWNSAMPLE_FRAGMENT_SHADER)?,
+            upsample: context
+                .material(BLOOM_UPSAMPLE_FRAGMENT_SHADER)?
+                .with_state(PipelineState::fullscreen().with_blend(Some(BlendMode::Additive))),
+            composite: context.material(BLOOM_COMPOSITE_FRAGMENT_SHADER)?,
+        })
+    }
+
+    pub fn with_levels(mut self, levels: usize) -> Self {
+        self.levels = levels.max(1);
+        self.mips.clear();
+        self
+    }
+
+    pub fn levels(&self) -> usize {
+        self.levels
+    }
+
+    fn build_mips(
+        &mut self,
+        context: &PostContext,
+        width: i32,
+        height: i32,
+    ) -> Result<(), GLError> {
+        self.mips.clear();
+        for level in 1..=self.levels {
+            let size = ((width >> level).max(1), (height >> level).max(1));
+            self.mips.push(RenderTarget::try_new(
+                context.gl(),
+                TextureFormat::RGBA16F,
+                size,
+                false,
+            )?);
+        }
+        Ok(())
+    }
+}
+
+impl PostPass for BloomPass {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn resize(&mut self, width: i32, height: i32) -> Result<(), GLError> {
+        for (level, mip) in self.mips.iter_mut().enumerate() {
+            let shift = level + 1;
+            mip.resize(width >> shift, height >> shift)?;
+        }
+        Ok(())
+    }
+
+    fn render(
+        &mut self,
+        context: &PostContext,
+        input: &Rc<Texture>,
+        output: Option<&RenderTarget>,
+    ) -> Result<(), GLError> {
+        if self.mips.len() != self.levels {
+            let (width, height) = input.size();
+            self.build_mips(context, width, height)?;
+        }
+
+        PostContext::set_input(&mut self.prefilter, input);
+        self.prefilter
+            .set(TEXEL_SIZE_UNIFORM, PostContext::texel_size(input));
+        self.prefilter.set("u_threshold", self.threshold);
+        self.prefilter.set("u_knee", self.knee.max(0.0001));
+        context.draw(&self.prefilter, Some(&self.mips[0]));
+
+        for level in 1..self.mips.len() {
+            let source = self.mips[level - 1].color();
+            PostContext::set_input(&mut self.downsample, source);
+            self.downsample
+                .set(TEXEL_SIZE_UNIFORM, PostContext::texel_size(source));
+            context.draw(&self.downsample, Some(&self.mips[level]));
+        }
+
+        self.upsample.set("u_radius", self.radius);
+        for level in (1..self.mips.len()).rev() {
+            let source = self.mips[level].color();
+            PostContext::set_input(&mut self.upsample, source);
+            self.upsample
+                .set(TEXEL_SIZE_UNIFORM, PostContext::texel_size(source));
+            context.draw(&self.upsample, Some(&self.mips[level - 1]));
+        }
+
+        PostContext::set_input(&mut self.composite, input);
+        self.composite
+            .set("u_bloom", Rc::clone(self.mips[0].color()));
+        self.composite.set("u_intensity", self.intensity);
+        context.draw(&self.composite, output);
+        Ok(())
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,105 @@
+use std::any::Any;
+use std::rc::Rc;
+
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::materials::material::Material;
+use crate::gl::postprocess::post_pass::{PostContext, PostPass};
+use crate::gl::postprocess::render_target::RenderTarget;
+use crate::gl::postprocess::shaders::COLOR_GRADING_FRAGMENT_SHADER;
+use crate::gl::textures::texture::Texture;
+
+pub const DEFAULT_LUT_SIZE: usize = 16;
+
+#[derive(Clone)]
+pub struct ColorLut {
+    texture: Rc<Texture>,
+    size: usize,
+}
+
+impl ColorLut {
+    pub fn from_strip(gl: &Rc<GL>, size: usize, pixels: &[u8]) -> Result<Self, GLError> {
+        let texture = Texture::from_rgba8(gl, (size * size) as i32, size as i32, pixels)?;
+        Ok(Self {
+            texture: Rc::new(texture),
+            size,
+        })
+    }
+
+    pub fn from_fn(
+        gl: &Rc<GL>,
+        size: usize,
+        grade: impl Fn([f32; 3]) -> [f32; 3],
+    ) -> Result<Self, GLError> {
+        let max = (size.max(2) - 1) as f32;
+        let mut pixels = Vec::with_capacity(size * size * size * 4);
+        for green in 0..size {
+            for blue in 0..size {
+                for red in 0..size {
+                    let color = grade([red as f32 / max, green as f32 / max, blue as f32 / max]);
+                    pixels.extend(
+                        color
+                            .iter()
+                            .map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round() as u8),
+                    );
+                    pixels.push(255);
+                }
+            }
+        }
+        Self::from_strip(gl, size, &pixels)
+    }
+
+    pub fn identity(gl: &Rc<GL>, size: usize) -> Result<Self, GLError> {
+        Self::from_fn(gl, size, |color| color)
+    }
+
+    pub fn texture(&self) -> &Rc<Texture> {
+        &self.texture
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+pub struct ColorGradingPass {
+    pub lut: ColorLut,
+    pub intensity: f32,
+    material: Material,
+}
+
+impl ColorGradingPass {
+    pub const NAME: &'static str = "color_grading";
+
+    pub fn try_new(context: &PostContext, lut: ColorLut) -> Result<Self, GLError> {
+        Ok(Self {
+            lut,
+            intensity: 1.0,
+            material: context.material(COLOR_GRADING_FRAGMENT_SHADER)?,
+        })
+    }
+}
+
+impl PostPass for ColorGradingPass {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn render(
+        &mut self,
+        context: &PostContext,
+        input: &Rc<Texture>,
+        output: Option<&RenderTarget>,
+    ) -> Result<(), GLError> {
+        PostContext::set_input(&mut self.material, input);
+        self.material.set("u_lut", Rc::clone(self.lut.texture()));
+        self.material.set("u_lut_size", self.lut.size() as f32);
+        self.material.set("u_intensity", self.intensity);
+        context.draw(&self.material, output);
+        Ok(())
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,46 @@
+use std::any::Any;
+use std::rc::Rc;
+
+use crate::gl::error::GLError;
+use crate::gl::materials::material::Material;
+use crate::gl::postprocess::post_pass::{PostContext, PostPass, TEXEL_SIZE_UNIFORM};
+use crate::gl::postprocess::render_target::RenderTarget;
+use crate::gl::postprocess::shaders::FXAA_FRAGMENT_SHADER;
+use crate::gl::textures::texture::Texture;
+
+pub struct FxaaPass {
+    material: Material,
+}
+
+impl FxaaPass {
+    pub const NAME: &'static str = "fxaa";
+
+    pub fn try_new(context: &PostContext) -> Result<Self, GLError> {
+        Ok(Self {
+            material: context.material(FXAA_FRAGMENT_SHADER)?,
+        })
+    }
+}
+
+impl PostPass for FxaaPass {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn render(
+        &mut self,
+        context: &PostContext,
+        input: &Rc<Texture>,
+        output: Option<&RenderTarget>,
+    ) -> Result<(), GLError> {
+        PostContext::set_input(&mut self.material, input);
+        self.material
+            .set(TEXEL_SIZE_UNIFORM, PostContext::texel_size(input));
+        context.draw(&self.material, output);
+        Ok(())
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
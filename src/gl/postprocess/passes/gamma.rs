@@ -0,0 +1,57 @@
+use std::any::Any;
+use std::rc::Rc;
+
+use crate::gl::error::GLError;
+use crate::gl::materials::material::Material;
+use crate::gl::postprocess::post_pass::{PostContext, PostPass};
+use crate::gl::postprocess::render_target::RenderTarget;
+use crate::gl::postprocess::shaders::GAMMA_FRAGMENT_SHADER;
+use crate::gl::textures::texture::Texture;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GammaCurve {
+    Srgb,
+    Power(f32),
+}
+
+pub struct GammaPass {
+    pub curve: GammaCurve,
+    material: Material,
+}
+
+impl GammaPass {
+    pub const NAME: &'static str = "gamma";
+
+    pub fn try_new(context: &PostContext, curve: GammaCurve) -> Result<Self, GLError> {
+        Ok(Self {
+            curve,
+            material: context.material(GAMMA_FRAGMENT_SHADER)?,
+        })
+    }
+}
+
+impl PostPass for GammaPass {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn render(
+        &mut self,
+        context: &PostContext,
+        input: &Rc<Texture>,
+        output: Option<&RenderTarget>,
+    ) -> Result<(), GLError> {
+        let gamma = match self.curve {
+            GammaCurve::Srgb => 0.0,
+            GammaCurve::Power(gamma) => gamma,
+        };
+        PostContext::set_input(&mut self.material, input);
+        self.material.set("u_gamma", gamma);
+        context.draw(&self.material, output);
+        Ok(())
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,6 @@
+pub mod bloom;
+pub mod color_grading;
+pub mod fxaa;
+pub mod gamma;
+pub mod tone_map;
+pub mod vignette;
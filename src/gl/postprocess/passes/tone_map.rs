@@ -0,0 +1,68 @@
+use std::any::Any;
+use std::rc::Rc;
+
+use crate::gl::error::GLError;
+use crate::gl::materials::material::Material;
+use crate::gl::postprocess::post_pass::{PostContext, PostPass};
+use crate::gl::postprocess::render_target::RenderTarget;
+use crate::gl::postprocess::shaders::TONE_MAP_FRAGMENT_SHADER;
+use crate::gl::textures::texture::Texture;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToneMapOperator {
+    Reinhard,
+    Aces,
+    Clamp,
+}
+
+impl ToneMapOperator {
+    fn shader_index(&self) -> i32 {
+        match self {
+            ToneMapOperator::Reinhard => 0,
+            ToneMapOperator::Aces => 1,
+            ToneMapOperator::Clamp => 2,
+        }
+    }
+}
+
+pub struct ToneMapPass {
+    pub operator: ToneMapOperator,
+    pub exposure: f32,
+    material: Material,
+}
+
+impl ToneMapPass {
+    pub const NAME: &'static str = "tone_map";
+
+    pub fn try_new(context: &PostContext, operator: ToneMapOperator) -> Result<Self, GLError> {
+        Ok(Self {
+            operator,
+            exposure: 1.0,
+            material: context.material(TONE_MAP_FRAGMENT_SHADER)?,
+        })
+    }
+}
+
+impl PostPass for ToneMapPass {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn render(
+        &mut self,
+        context: &PostContext,
+        input: &Rc<Texture>,
+        output: Option<&RenderTarget>,
+    ) -> Result<(), GLError> {
+        PostContext::set_input(&mut self.material, input);
+        self.material.set("u_exposure", self.exposure);
+        self.material
+            .set("u_operator", self.operator.shader_index());
+        context.draw(&self.material, output);
+        Ok(())
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,53 @@
+use std::any::Any;
+use std::rc::Rc;
+
+use crate::gl::error::GLError;
+use crate::gl::materials::material::Material;
+use crate::gl::postprocess::post_pass::{PostContext, PostPass};
+use crate::gl::postprocess::render_target::RenderTarget;
+use crate::gl::postprocess::shaders::VIGNETTE_FRAGMENT_SHADER;
+use crate::gl::textures::texture::Texture;
+
+pub struct VignettePass {
+    pub intensity: f32,
+    pub radius: f32,
+    pub softness: f32,
+    material: Material,
+}
+
+impl VignettePass {
+    pub const NAME: &'static str = "vignette";
+
+    pub fn try_new(context: &PostContext) -> Result<Self, GLError> {
+        Ok(Self {
+            intensity: 0.6,
+            radius: 0.95,
+            softness: 0.5,
+            material: context.material(VIGNETTE_FRAGMENT_SHADER)?,
+        })
+    }
+}
+
+impl PostPass for VignettePass {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn render(
+        &mut self,
+        context: &PostContext,
+        input: &Rc<Texture>,
+        output: Option<&RenderTarget>,
+    ) -> Result<(), GLError> {
+        PostContext::set_input(&mut self.material, input);
+        self.material.set("u_intensity", self.intensity);
+        self.material.set("u_radius", self.radius);
+        self.material.set("u_softness", self.softness);
+        context.draw(&self.material, output);
+        Ok(())
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,184 @@
+use std::rc::Rc;
+
+use crate::gl;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::framebuffers::binded_framebuffer_ctx::BindedFramebufferCtx;
+use crate::gl::materials::material::Material;
+use crate::gl::textures::texture::TextureFormat;
+
+use super::error::PostProcessError::MissingExtension;
+use super::passes::bloom::BloomPass;
+use super::passes::color_grading::{ColorGradingPass, ColorLut, DEFAULT_LUT_SIZE};
+use super::passes::fxaa::FxaaPass;
+use super::passes::gamma::{GammaCurve, GammaPass};
+use super::passes::tone_map::{ToneMapOperator, ToneMapPass};
+use super::passes::vignette::VignettePass;
+use super::post_pass::{PostContext, PostPass};
+use super::render_target::RenderTarget;
+use super::shaders::COPY_FRAGMENT_SHADER;
+
+const COLOR_BUFFER_FLOAT_EXTENSION: &str = "EXT_color_buffer_float";
+
+struct PassSlot {
+    pass: Box<dyn PostPass>,
+    enabled: bool,
+}
+
+pub struct PostProcessChain {
+    scene: RenderTarget,
+    ping_pong: [RenderTarget; 2],
+    passes: Vec<PassSlot>,
+    context: PostContext,
+    copy: Material,
+    gl: Rc<GL>,
+}
+
+impl PostProcessChain {
+    pub fn try_new(gl: &Rc<GL>) -> Result<Self, GLError> {
+        if !gl.enable_extension(COLOR_BUFFER_FLOAT_EXTENSION) {
+            return Err(MissingExtension(COLOR_BUFFER_FLOAT_EXTENSION).into());
+        }
+        let size = (gl.drawing_buffer_width(), gl.drawing_buffer_height());
+        let context = PostContext::try_new(gl)?;
+        Ok(Self {
+            scene: RenderTarget::try_new(gl, TextureFormat::RGBA16F, size, true)?,
+            ping_pong: [
+                RenderTarget::try_new(gl, TextureFormat::RGBA16F, size, false)?,
+                RenderTarget::try_new(gl, TextureFormat::RGBA16F, size, false)?,
+            ],
+            passes: vec![],
+            copy: context.material(COPY_FRAGMENT_SHADER)?,
+            context,
+            gl: Rc::clone(gl),
+        })
+    }
+
+    pub fn standard(gl: &Rc<GL>) -> Result<Self, GLError> {
+        let mut chain = Self::try_new(gl)?;
+        let context = &chain.context;
+        let bloom = BloomPass::try_new(context)?;
+        let tone_map = ToneMapPass::try_new(context, ToneMapOperator::Aces)?;
+        let gamma = GammaPass::try_new(context, GammaCurve::Srgb)?;
+        let color_grading =
+            ColorGradingPass::try_new(context, ColorLut::identity(gl, DEFAULT_LUT_SIZE)?)?;
+        let vignette = VignettePass::try_new(context)?;
+        let fxaa = FxaaPass::try_new(context)?;
+
+        chain.add_pass(Box::new(bloom));
+        chain.add_pass(Box::new(tone_map));
+        chain.add_pass(Box::new(gamma));
+        chain.add_pass(Box::new(color_grading));
+        chain.add_pass(Box::new(vignette));
+        chain.add_pass(Box::new(fxaa));
+        Ok(chain)
+    }
+
+    pub fn context(&self) -> &PostContext {
+        &self.context
+    }
+
+    pub fn with_pass(mut self, pass: impl PostPass + 'static) -> Self {
+        self.add_pass(Box::new(pass));
+        self
+    }
+
+    pub fn add_pass(&mut self, pass: Box<dyn PostPass>) {
+        self.passes.push(PassSlot {
+            pass,
+            enabled: true,
+        });
+    }
+
+    pub fn pass_names(&self) -> Vec<&'static str> {
+        self.passes.iter().map(|slot| slot.pass.name()).collect()
+    }
+
+    pub fn pass_mut<P: PostPass + 'static>(&mut self) -> Option<&mut P> {
+        self.passes
+            .iter_mut()
+            .find_map(|slot| slot.pass.as_any_mut().downcast_mut::<P>())
+    }
+
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.passes
+            .iter()
+            .any(|slot| slot.enabled && slot.pass.name() == name)
+    }
+
+    pub fn set_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        match self.passes.iter_mut().find(|slot| slot.pass.name() == name) {
+            Some(slot) => {
+                slot.enabled = enabled;
+                true
+            }
+            None => {
+                log::warn!("Unknown post-processing pass: {}", name);
+                false
+            }
+        }
+    }
+
+    pub fn toggle(&mut self, name: &str) -> bool {
+        let enabled = !self.is_enabled(name);
+        self.set_enabled(name, enabled) && enabled
+    }
+
+    pub fn scene_target(&self) -> &RenderTarget {
+        &self.scene
+    }
+
+    pub fn resize(&mut self, width: i32, height: i32) -> Result<(), GLError> {
+        if self.scene.size() == (width.max(1), height.max(1)) {
+            return Ok(());
+        }
+        self.scene.resize(width, height)?;
+        for target in self.ping_pong.iter_mut() {
+            target.resize(width, height)?;
+        }
+        for slot in self.passes.iter_mut() {
+            slot.pass.resize(width, height)?;
+        }
+        Ok(())
+    }
+
+    pub fn begin(&mut self) -> Result<BindedFramebufferCtx<'_>, GLError> {
+        let width = self.gl.drawing_buffer_width();
+        let height = self.gl.drawing_buffer_height();
+        self.resize(width, height)?;
+
+        let binded = self.scene.bind();
+        self.gl.clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
+        Ok(binded)
+    }
+
+    pub fn finish(&mut self) -> Result<(), GLError> {
+        let last = match self.passes.iter().rposition(|slot| slot.enabled) {
+            Some(last) => last,
+            None => {
+                PostContext::set_input(&mut self.copy, self.scene.color());
+                self.context.draw(&self.copy, None);
+                return Ok(());
+            }
+        };
+
+        let mut input = Rc::clone(self.scene.color());
+        let mut target = 0;
+        for (index, slot) in self.passes.iter_mut().enumerate() {
+            if !slot.enabled {
+                continue;
+            }
+            let output = if index == last {
+                None
+            } else {
+                Some(&self.ping_pong[target])
+            };
+            slot.pass.render(&self.context, &input, output)?;
+            if let Some(output) = output {
+                input = Rc::clone(output.color());
+                target = 1 - target;
+            }
+        }
+        Ok(())
+    }
+}
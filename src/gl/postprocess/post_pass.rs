@@ -0,0 +1,91 @@
+use std::any::Any;
+use std::rc::Rc;
+
+use glm::Vec2;
+
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::materials::material::Material;
+use crate::gl::materials::pipeline_state::PipelineState;
+use crate::gl::mesh::Mesh;
+use crate::gl::screen_quad::{build_screen_quad, SCREEN_QUAD_VERTEX_SHADER};
+use crate::gl::shader::program::ShaderProgram;
+use crate::gl::shader::shader_type::ShaderType;
+use crate::gl::textures::texture::Texture;
+
+use super::render_target::RenderTarget;
+
+pub const INPUT_UNIFORM: &str = "u_input";
+pub const TEXEL_SIZE_UNIFORM: &str = "u_texel_size";
+
+pub trait PostPass {
+    fn name(&self) -> &'static str;
+
+    fn resize(&mut self, _width: i32, _height: i32) -> Result<(), GLError> {
+        Ok(())
+    }
+
+    fn render(
+        &mut self,
+        context: &PostContext,
+        input: &Rc<Texture>,
+        output: Option<&RenderTarget>,
+    ) -> Result<(), GLError>;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+pub struct PostContext {
+    quad: Mesh,
+    gl: Rc<GL>,
+}
+
+impl PostContext {
+    pub fn try_new(gl: &Rc<GL>) -> Result<Self, GLError> {
+        Ok(Self {
+            quad: build_screen_quad(gl)?,
+            gl: Rc::clone(gl),
+        })
+    }
+
+    pub fn gl(&self) -> &Rc<GL> {
+        &self.gl
+    }
+
+    pub fn material(&self, fragment: &str) -> Result<Material, GLError> {
+        let program = Rc::new(
+            ShaderProgram::builder(&self.gl)
+                .add_source(ShaderType::Vertex, SCREEN_QUAD_VERTEX_SHADER.into())
+                .add_source(ShaderType::Fragment, fragment.into())
+                .build()?,
+        );
+        Ok(Material::new(&self.gl, &program).with_state(PipelineState::fullscreen()))
+    }
+
+    pub fn set_input(material: &mut Material, input: &Rc<Texture>) {
+        material.set(INPUT_UNIFORM, Rc::clone(input));
+    }
+
+    pub fn texel_size(texture: &Texture) -> Vec2 {
+        let (width, height) = texture.size();
+        Vec2::new(1.0 / width.max(1) as f32, 1.0 / height.max(1) as f32)
+    }
+
+    pub fn draw(&self, material: &Material, output: Option<&RenderTarget>) {
+        let _binded = output.map(RenderTarget::bind);
+        if output.is_none() {
+            self.gl.viewport(
+                0,
+                0,
+                self.gl.drawing_buffer_width(),
+                self.gl.drawing_buffer_height(),
+            );
+        }
+        self.draw_quad(material);
+    }
+
+    pub fn draw_quad(&self, material: &Material) {
+        let material_use = material.bind();
+        self.quad.draw_geometry(material_use.shader_use());
+    }
+}
@@ -0,0 +1,88 @@
+use std::rc::Rc;
+
+use crate::gl;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::framebuffers::binded_framebuffer_ctx::BindedFramebufferCtx;
+use crate::gl::framebuffers::framebuffer::Framebuffer;
+use crate::gl::framebuffers::renderbuffer::Renderbuffer;
+use crate::gl::textures::texture::{Texture, TextureFormat};
+
+pub struct RenderTarget {
+    framebuffer: Framebuffer,
+    color: Rc<Texture>,
+    depth: Option<Renderbuffer>,
+    gl: Rc<GL>,
+}
+
+impl RenderTarget {
+    pub fn try_new(
+        gl: &Rc<GL>,
+        format: TextureFormat,
+        size: (i32, i32),
+        with_depth: bool,
+    ) -> Result<Self, GLError> {
+        let (width, height) = size;
+        let depth = if with_depth {
+            Some(Renderbuffer::try_new(
+                gl,
+                gl::DEPTH_COMPONENT24,
+                width,
+                height,
+            )?)
+        } else {
+            None
+        };
+        let target = Self {
+            framebuffer: Framebuffer::try_new(gl)?,
+            color: Rc::new(Texture::new_2d(gl, format, width.max(1), height.max(1))?),
+            depth,
+            gl: Rc::clone(gl),
+        };
+        target.attach()?;
+        Ok(target)
+    }
+
+    fn attach(&self) -> Result<(), GLError> {
+        let binded = self.framebuffer.bind();
+        binded.attach_texture(gl::COLOR_ATTACHMENT0, &self.color, gl::TEXTURE_2D, 0);
+        if let Some(depth) = self.depth.as_ref() {
+            binded.attach_renderbuffer(gl::DEPTH_ATTACHMENT, depth);
+        }
+        binded.check_status()?;
+        Ok(())
+    }
+
+    pub fn color(&self) -> &Rc<Texture> {
+        &self.color
+    }
+
+    pub fn size(&self) -> (i32, i32) {
+        self.color.size()
+    }
+
+    pub fn resize(&mut self, width: i32, height: i32) -> Result<(), GLError> {
+        let (width, height) = (width.max(1), height.max(1));
+        if self.size() == (width, height) {
+            return Ok(());
+        }
+        self.color = Rc::new(Texture::new_2d(
+            &self.gl,
+            self.color.format(),
+            width,
+            height,
+        )?);
+        if let Some(depth) = self.depth.as_mut() {
+            depth.resize(width, height);
+        }
+        self.attach()
+    }
+
+    #[must_use]
+    pub fn bind(&self) -> BindedFramebufferCtx<'_> {
+        let binded = self.framebuffer.bind();
+        let (width, height) = self.size();
+        self.gl.viewport(0, 0, width, height);
+        binded
+    }
+}
@@ -0,0 +1,287 @@
+pub const COPY_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in vec2 v_uv;
+
+    uniform sampler2D u_input;
+
+    out vec4 out_color;
+
+    void main() {
+        out_color = vec4(texture(u_input, v_uv).rgb, 1.0);
+    }
+"##;
+
+pub const TONE_MAP_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in vec2 v_uv;
+
+    uniform sampler2D u_input;
+    uniform float u_exposure;
+    uniform int u_operator;
+
+    out vec4 out_color;
+
+    vec3 reinhard(vec3 color) {
+        return color / (color + vec3(1.0));
+    }
+
+    vec3 aces(vec3 color) {
+        const float a = 2.51;
+        const float b = 0.03;
+        const float c = 2.43;
+        const float d = 0.59;
+        const float e = 0.14;
+        return clamp((color * (a * color + b)) / (color * (c * color + d) + e), 0.0, 1.0);
+    }
+
+    void main() {
+        vec3 color = texture(u_input, v_uv).rgb * u_exposure;
+        if (u_operator == 0) {
+            color = reinhard(color);
+        } else if (u_operator == 1) {
+            color = aces(color);
+        } else {
+            color = clamp(color, 0.0, 1.0);
+        }
+        out_color = vec4(color, 1.0);
+    }
+"##;
+
+pub const GAMMA_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in vec2 v_uv;
+
+    uniform sampler2D u_input;
+    uniform float u_gamma;
+
+    out vec4 out_color;
+
+    vec3 linear_to_srgb(vec3 color) {
+        vec3 low = color * 12.92;
+        vec3 high = 1.055 * pow(color, vec3(1.0 / 2.4)) - 0.055;
+        return mix(low, high, step(vec3(0.0031308), color));
+    }
+
+    void main() {
+        vec3 color = max(texture(u_input, v_uv).rgb, vec3(0.0));
+        if (u_gamma > 0.0) {
+            color = pow(color, vec3(1.0 / u_gamma));
+        } else {
+            color = linear_to_srgb(color);
+        }
+        out_color = vec4(color, 1.0);
+    }
+"##;
+
+pub const FXAA_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    const float FXAA_REDUCE_MIN = 1.0 / 128.0;
+    const float FXAA_REDUCE_MUL = 1.0 / 8.0;
+    const float FXAA_SPAN_MAX = 8.0;
+
+    in vec2 v_uv;
+
+    uniform sampler2D u_input;
+    uniform vec2 u_texel_size;
+
+    out vec4 out_color;
+
+    float luma(vec3 color) {
+        return dot(color, vec3(0.299, 0.587, 0.114));
+    }
+
+    vec3 sample_at(vec2 offset) {
+        return texture(u_input, v_uv + offset * u_texel_size).rgb;
+    }
+
+    void main() {
+        vec3 color_m = sample_at(vec2(0.0));
+        float luma_nw = luma(sample_at(vec2(-1.0, -1.0)));
+        float luma_ne = luma(sample_at(vec2(1.0, -1.0)));
+        float luma_sw = luma(sample_at(vec2(-1.0, 1.0)));
+        float luma_se = luma(sample_at(vec2(1.0, 1.0)));
+        float luma_m = luma(color_m);
+
+        float luma_min = min(luma_m, min(min(luma_nw, luma_ne), min(luma_sw, luma_se)));
+        float luma_max = max(luma_m, max(max(luma_nw, luma_ne), max(luma_sw, luma_se)));
+
+        vec2 direction = vec2(
+            -((luma_nw + luma_ne) - (luma_sw + luma_se)),
+            (luma_nw + luma_sw) - (luma_ne + luma_se)
+        );
+        float reduce = max(
+            (luma_nw + luma_ne + luma_sw + luma_se) * 0.25 * FXAA_REDUCE_MUL,
+            FXAA_REDUCE_MIN
+        );
+        float inverse_min = 1.0 / (min(abs(direction.x), abs(direction.y)) + reduce);
+        direction = clamp(direction * inverse_min, vec2(-FXAA_SPAN_MAX), vec2(FXAA_SPAN_MAX));
+
+        vec3 color_a = 0.5 * (
+            sample_at(direction * (1.0 / 3.0 - 0.5)) +
+            sample_at(direction * (2.0 / 3.0 - 0.5))
+        );
+        vec3 color_b = color_a * 0.5 + 0.25 * (
+            sample_at(direction * -0.5) +
+            sample_at(direction * 0.5)
+        );
+
+        float luma_b = luma(color_b);
+        vec3 color = (luma_b < luma_min || luma_b > luma_max) ? color_a : color_b;
+        out_color = vec4(color, 1.0);
+    }
+"##;
+
+pub const BLOOM_PREFILTER_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in vec2 v_uv;
+
+    uniform sampler2D u_input;
+    uniform vec2 u_texel_size;
+    uniform float u_threshold;
+    uniform float u_knee;
+
+    out vec4 out_color;
+
+    void main() {
+        vec4 offsets = u_texel_size.xyxy * vec4(-1.0, -1.0, 1.0, 1.0);
+        vec3 color = 0.25 * (
+            texture(u_input, v_uv + offsets.xy).rgb +
+            texture(u_input, v_uv + offsets.zy).rgb +
+            texture(u_input, v_uv + offsets.xw).rgb +
+            texture(u_input, v_uv + offsets.zw).rgb
+        );
+
+        float brightness = max(color.r, max(color.g, color.b));
+        float soft = clamp(brightness - u_threshold + u_knee, 0.0, 2.0 * u_knee);
+        soft = soft * soft / (4.0 * u_knee + 0.0001);
+        float contribution = max(soft, brightness - u_threshold) / max(brightness, 0.0001);
+        out_color = vec4(color * contribution, 1.0);
+    }
+"##;
+
+pub const BLOOM_DOWNSAMPLE_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in vec2 v_uv;
+
+    uniform sampler2D u_input;
+    uniform vec2 u_texel_size;
+
+    out vec4 out_color;
+
+    void main() {
+        vec4 offsets = u_texel_size.xyxy * vec4(-1.0, -1.0, 1.0, 1.0);
+        vec3 color = 0.25 * (
+            texture(u_input, v_uv + offsets.xy).rgb +
+            texture(u_input, v_uv + offsets.zy).rgb +
+            texture(u_input, v_uv + offsets.xw).rgb +
+            texture(u_input, v_uv + offsets.zw).rgb
+        );
+        out_color = vec4(color, 1.0);
+    }
+"##;
+
+pub const BLOOM_UPSAMPLE_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in vec2 v_uv;
+
+    uniform sampler2D u_input;
+    uniform vec2 u_texel_size;
+    uniform float u_radius;
+
+    out vec4 out_color;
+
+    void main() {
+        vec4 d = u_texel_size.xyxy * vec4(1.0, 1.0, -1.0, 0.0) * u_radius;
+
+        vec3 color = texture(u_input, v_uv - d.xy).rgb;
+        color += texture(u_input, v_uv - d.wy).rgb * 2.0;
+        color += texture(u_input, v_uv - d.zy).rgb;
+        color += texture(u_input, v_uv + d.zw).rgb * 2.0;
+        color += texture(u_input, v_uv).rgb * 4.0;
+        color += texture(u_input, v_uv + d.xw).rgb * 2.0;
+        color += texture(u_input, v_uv + d.zy).rgb;
+        color += texture(u_input, v_uv + d.wy).rgb * 2.0;
+        color += texture(u_input, v_uv + d.xy).rgb;
+
+        out_color = vec4(color / 16.0, 1.0);
+    }
+"##;
+
+pub const BLOOM_COMPOSITE_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in vec2 v_uv;
+
+    uniform sampler2D u_input;
+    uniform sampler2D u_bloom;
+    uniform float u_intensity;
+
+    out vec4 out_color;
+
+    void main() {
+        vec3 color = texture(u_input, v_uv).rgb;
+        vec3 bloom = texture(u_bloom, v_uv).rgb;
+        out_color = vec4(color + bloom * u_intensity, 1.0);
+    }
+"##;
+
+pub const VIGNETTE_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in vec2 v_uv;
+
+    uniform sampler2D u_input;
+    uniform float u_intensity;
+    uniform float u_radius;
+    uniform float u_softness;
+
+    out vec4 out_color;
+
+    void main() {
+        vec3 color = texture(u_input, v_uv).rgb;
+        float distance = length(v_uv - 0.5) * 1.41421356;
+        float vignette = 1.0 - smoothstep(u_radius - u_softness, u_radius, distance);
+        out_color = vec4(color * mix(1.0, vignette, u_intensity), 1.0);
+    }
+"##;
+
+pub const COLOR_GRADING_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in vec2 v_uv;
+
+    uniform sampler2D u_input;
+    uniform sampler2D u_lut;
+    uniform float u_lut_size;
+    uniform float u_intensity;
+
+    out vec4 out_color;
+
+    vec3 lut_lookup(vec3 color) {
+        float size = u_lut_size;
+        float blue = color.b * (size - 1.0);
+        float slice_low = floor(blue);
+        float slice_high = min(slice_low + 1.0, size - 1.0);
+
+        vec2 red_green = (color.rg * (size - 1.0) + 0.5) / vec2(size * size, size);
+        vec2 uv_low = red_green + vec2(slice_low / size, 0.0);
+        vec2 uv_high = red_green + vec2(slice_high / size, 0.0);
+        return mix(
+            texture(u_lut, uv_low).rgb,
+            texture(u_lut, uv_high).rgb,
+            blue - slice_low
+        );
+    }
+
+    void main() {
+        vec3 color = clamp(texture(u_input, v_uv).rgb, 0.0, 1.0);
+        out_color = vec4(mix(color, lut_lookup(color), u_intensity), 1.0);
+    }
+"##;
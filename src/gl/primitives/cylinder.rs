@@ -0,0 +1,44 @@
+use glm::Vec2;
+
+use crate::gl::mesh::MeshBuilder;
+
+use super::geometry::{Geometry, LatheRing};
+
+pub fn cylinder(
+    bottom_radius: f32,
+    top_radius: f32,
+    height: f32,
+    segments: usize,
+    height_segments: usize,
+    capped: bool,
+) -> MeshBuilder {
+    let height_segments = height_segments.max(1);
+    let half_height = height * 0.5;
+    let normal = Vec2::new(height, bottom_radius - top_radius);
+
+    let profile: Vec<LatheRing> = (0..=height_segments)
+        .map(|step| {
+            let v = step as f32 / height_segments as f32;
+            LatheRing {
+                radius: top_radius + (bottom_radius - top_radius) * v,
+                y: half_height - height * v,
+                normal,
+                v,
+            }
+        })
+        .collect();
+
+    let mut geometry = Geometry::new();
+    geometry.add_lathe(&profile, segments);
+    if capped && top_radius > 0.0 {
+        geometry.add_disc(half_height, top_radius, segments, true);
+    }
+    if capped && bottom_radius > 0.0 {
+        geometry.add_disc(-half_height, bottom_radius, segments, false);
+    }
+    geometry.into_builder()
+}
+
+pub fn cone(radius: f32, height: f32, segments: usize, height_segments: usize) -> MeshBuilder {
+    cylinder(radius, 0.0, height, segments, height_segments, true)
+}
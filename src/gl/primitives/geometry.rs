@@ -0,0 +1,132 @@
+use std::f32::consts::TAU;
+
+use glm::{Vec2, Vec3};
+use na::{Matrix2xX, Matrix3xX};
+
+use crate::gl::mesh::{Faces, MeshBuilder};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatheRing {
+    pub radius: f32,
+    pub y: f32,
+    pub normal: Vec2,
+    pub v: f32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Geometry {
+    positions: Vec<Vec3>,
+    normals: Vec<Vec3>,
+    tex_coords: Vec<Vec2>,
+    faces: Vec<[i32; 3]>,
+}
+
+impl Geometry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn vertices_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn position(&self, index: i32) -> &Vec3 {
+        &self.positions[index as usize]
+    }
+
+    pub fn tex_coord(&self, index: i32) -> &Vec2 {
+        &self.tex_coords[index as usize]
+    }
+
+    pub fn add_vertex(&mut self, position: Vec3, normal: Vec3, tex_coord: Vec2) -> i32 {
+        self.positions.push(position);
+        self.normals.push(normal);
+        self.tex_coords.push(tex_coord);
+        (self.positions.len() - 1) as i32
+    }
+
+    pub fn duplicate_vertex(&mut self, index: i32, tex_coord: Vec2) -> i32 {
+        let index = index as usize;
+        self.add_vertex(self.positions[index], self.normals[index], tex_coord)
+    }
+
+    pub fn add_triangle(&mut self, a: i32, b: i32, c: i32) {
+        self.faces.push([a, b, c]);
+    }
+
+    pub fn add_lathe(&mut self, rings: &[LatheRing], segments: usize) {
+        let segments = segments.max(3);
+        let columns = segments + 1;
+        let first = self.vertices_count() as i32;
+
+        for ring in rings.iter() {
+            let normal = ring.normal.normalize();
+            for segment in 0..=segments {
+                let u = segment as f32 / segments as f32;
+                let (sin, cos) = (u * TAU).sin_cos();
+                self.add_vertex(
+                    Vec3::new(ring.radius * cos, ring.y, -ring.radius * sin),
+                    Vec3::new(normal.x * cos, normal.y, -normal.x * sin),
+                    Vec2::new(u, 1.0 - ring.v),
+                );
+            }
+        }
+
+        for (index, pair) in rings.windows(2).enumerate() {
+            for segment in 0..segments {
+                let a = first + (index * columns + segment) as i32;
+                let b = a + columns as i32;
+                if pair[0].radius > 0.0 {
+                    self.add_triangle(a, b, a + 1);
+                }
+                if pair[1].radius > 0.0 {
+                    self.add_triangle(a + 1, b, b + 1);
+                }
+            }
+        }
+    }
+
+    pub fn add_disc(&mut self, y: f32, radius: f32, segments: usize, facing_up: bool) {
+        let segments = segments.max(3);
+        let normal = if facing_up { Vec3::y() } else { -Vec3::y() };
+        let center = self.add_vertex(Vec3::new(0.0, y, 0.0), normal, Vec2::new(0.5, 0.5));
+
+        for segment in 0..=segments {
+            let (sin, cos) = (segment as f32 / segments as f32 * TAU).sin_cos();
+            let flip = if facing_up { 1.0 } else { -1.0 };
+            self.add_vertex(
+                Vec3::new(radius * cos, y, -radius * sin),
+                normal,
+                Vec2::new(0.5 + 0.5 * cos, 0.5 + 0.5 * sin * flip),
+            );
+        }
+        for segment in 0..segments as i32 {
+            let rim = center + 1 + segment;
+            if facing_up {
+                self.add_triangle(center, rim, rim + 1);
+            } else {
+                self.add_triangle(center, rim + 1, rim);
+            }
+        }
+    }
+
+    pub fn into_builder(self) -> MeshBuilder {
+        let faces = Faces::from_iterator(
+            self.faces.len(),
+            self.faces.iter().flat_map(|face| face.iter().copied()),
+        );
+        let count = self.positions.len();
+        MeshBuilder::new(
+            Matrix3xX::from_iterator(count, self.positions.iter().flat_map(|p| p.iter().copied())),
+            faces,
+        )
+        .add_normals(Matrix3xX::from_iterator(
+            count,
+            self.normals.iter().flat_map(|n| n.iter().copied()),
+        ))
+        .add_tex_coords(Matrix2xX::from_iterator(
+            count,
+            self.tex_coords.iter().flat_map(|t| t.iter().copied()),
+        ))
+    }
+}
@@ -0,0 +1,41 @@
+use glm::{Vec2, Vec3};
+
+use crate::gl::mesh::MeshBuilder;
+
+use super::geometry::Geometry;
+
+pub fn grid(width: f32, depth: f32, columns: usize, rows: usize) -> MeshBuilder {
+    let columns = columns.max(1);
+    let rows = rows.max(1);
+    let mut geometry = Geometry::new();
+
+    for row in 0..=rows {
+        let v = row as f32 / rows as f32;
+        for column in 0..=columns {
+            let u = column as f32 / columns as f32;
+            geometry.add_vertex(
+                Vec3::new((u - 0.5) * width, 0.0, (v - 0.5) * depth),
+                Vec3::y(),
+                Vec2::new(u, 1.0 - v),
+            );
+        }
+    }
+    add_grid_faces(&mut geometry, columns, rows);
+    geometry.into_builder()
+}
+
+pub fn plane(size: f32) -> MeshBuilder {
+    grid(size, size, 1, 1)
+}
+
+pub(super) fn add_grid_faces(geometry: &mut Geometry, columns: usize, rows: usize) {
+    let stride = (columns + 1) as i32;
+    for row in 0..rows as i32 {
+        for column in 0..columns as i32 {
+            let a = row * stride + column;
+            let below = a + stride;
+            geometry.add_triangle(a, below, a + 1);
+            geometry.add_triangle(a + 1, below, below + 1);
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::f32::consts::{PI, TAU};
+
+use glm::{Vec2, Vec3};
+
+use crate::gl::mesh::MeshBuilder;
+
+use super::geometry::Geometry;
+
+const POLE_EPSILON: f32 = 1.0e-6;
+
+const ICOSAHEDRON_FACES: [[u32; 3]; 20] = [
+    [0, 11, 5],
+    [0, 5, 1],
+    [0, 1, 7],
+    [0, 7, 10],
+    [0, 10, 11],
+    [1, 5, 9],
+    [5, 11, 4],
+    [11, 10, 2],
+    [10, 7, 6],
+    [7, 1, 8],
+    [3, 9, 4],
+    [3, 4, 2],
+    [3, 2, 6],
+    [3, 6, 8],
+    [3, 8, 9],
+    [4, 9, 5],
+    [2, 4, 11],
+    [6, 2, 10],
+    [8, 6, 7],
+    [9, 8, 1],
+];
+
+fn icosahedron() -> Vec<Vec3> {
+    let t = (1.0 + 5.0_f32.sqrt()) * 0.5;
+    [
+        [-1.0, t, 0.0],
+        [1.0, t, 0.0],
+        [-1.0, -t, 0.0],
+        [1.0, -t, 0.0],
+        [0.0, -1.0, t],
+        [0.0, 1.0, t],
+        [0.0, -1.0, -t],
+        [0.0, 1.0, -t],
+        [t, 0.0, -1.0],
+        [t, 0.0, 1.0],
+        [-t, 0.0, -1.0],
+        [-t, 0.0, 1.0],
+    ]
+    .iter()
+    .map(|&[x, y, z]| Vec3::new(x, y, z).normalize())
+    .collect()
+}
+
+fn subdivide(points: &mut Vec<Vec3>, faces: &[[u32; 3]]) -> Vec<[u32; 3]> {
+    let mut midpoints: HashMap<(u32, u32), u32> = HashMap::new();
+    let mut midpoint = |a: u32, b: u32, points: &mut Vec<Vec3>| {
+        let key = (a.min(b), a.max(b));
+        *midpoints.entry(key).or_insert_with(|| {
+            let point = (points[a as usize] + points[b as usize]).normalize();
+            points.push(point);
+            (points.len() - 1) as u32
+        })
+    };
+
+    faces
+        .iter()
+        .flat_map(|&[a, b, c]| {
+            let ab = midpoint(a, b, points);
+            let bc = midpoint(b, c, points);
+            let ca = midpoint(c, a, points);
+            vec![[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]
+        })
+        .collect()
+}
+
+fn spherical_tex_coord(direction: &Vec3) -> Vec2 {
+    let u = (-direction.z).atan2(direction.x).rem_euclid(TAU) / TAU;
+    let v = direction.y.clamp(-1.0, 1.0).acos() / PI;
+    Vec2::new(u, 1.0 - v)
+}
+
+fn is_pole(direction: &Vec3) -> bool {
+    direction.x.abs() < POLE_EPSILON && direction.z.abs() < POLE_EPSILON
+}
+
+fn add_polygon(geometry: &mut Geometry, corners: &[(i32, bool)]) {
+    if corners.len() < 3 {
+        return;
+    }
+
+    let count = corners.len();
+    let indices: Vec<i32> = (0..count)
+        .map(|corner| {
+            let (index, pole) = corners[corner];
+            if !pole {
+                return index;
+            }
+            let next = *geometry.tex_coord(corners[(corner + 1) % count].0);
+            let previous = *geometry.tex_coord(corners[(corner + count - 1) % count].0);
+            let v = geometry.tex_coord(index).y;
+            geometry.duplicate_vertex(index, Vec2::new((next.x + previous.x) * 0.5, v))
+        })
+        .collect();
+    for corner in 1..count - 1 {
+        geometry.add_triangle(indices[0], indices[corner], indices[corner + 1]);
+    }
+}
+
+pub fn icosphere(radius: f32, subdivisions: usize) -> MeshBuilder {
+    let mut points = icosahedron();
+    let mut faces = ICOSAHEDRON_FACES.to_vec();
+    for _ in 0..subdivisions {
+        faces = subdivide(&mut points, &faces);
+    }
+
+    let mut geometry = Geometry::new();
+    for point in points.iter() {
+        geometry.add_vertex(point * radius, *point, spherical_tex_coord(point));
+    }
+
+    let mut wrapped: HashMap<i32, i32> = HashMap::new();
+    let mut seam_cuts: HashMap<(i32, i32), (i32, i32)> = HashMap::new();
+    let u_of = |index: i32| spherical_tex_coord(&points[index as usize]).x;
+    for face in faces.iter() {
+        let poles = face.map(|index| is_pole(&points[index as usize]));
+        let face = face.map(|index| index as i32);
+        let corners: Vec<(i32, bool)> = face.iter().copied().zip(poles.iter().copied()).collect();
+
+        let us: Vec<f32> = corners
+            .iter()
+            .filter(|(_, pole)| !pole)
+            .map(|&(index, _)| u_of(index))
+            .collect();
+        let max_u = us.iter().copied().fold(f32::MIN, f32::max);
+        let min_u = us.iter().copied().fold(f32::MAX, f32::min);
+        if max_u - min_u <= 0.5 {
+            add_polygon(&mut geometry, &corners);
+            continue;
+        }
+
+        let unwrapped = |index: i32| {
+            let u = u_of(index);
+            if u < 0.5 {
+                u + 1.0
+            } else {
+                u
+            }
+        };
+        let mut before = Vec::with_capacity(4);
+        let mut after = Vec::with_capacity(4);
+        for (corner, &(index, pole)) in corners.iter().enumerate() {
+            let u = if pole { 1.0 } else { unwrapped(index) };
+            if pole || u < 1.0 {
+                before.push((index, pole));
+            } else if u == 1.0 {
+                let v = geometry.tex_coord(index).y;
+                let end = *wrapped
+                    .entry(index)
+                    .or_insert_with(|| geometry.duplicate_vertex(index, Vec2::new(1.0, v)));
+                before.push((end, false));
+            }
+            if pole || u >= 1.0 {
+                after.push((index, pole));
+            }
+
+            let (next, next_pole) = corners[(corner + 1) % 3];
+            if pole || next_pole {
+                continue;
+            }
+            let next_u = unwrapped(next);
+            if (u < 1.0 && next_u > 1.0) || (u > 1.0 && next_u < 1.0) {
+                let key = (index.min(next), index.max(next));
+                let (end, start) = *seam_cuts.entry(key).or_insert_with(|| {
+                    let (a, b) = (points[key.0 as usize], points[key.1 as usize]);
+                    let chord = a.lerp(&b, a.z / (a.z - b.z));
+                    let direction = chord.normalize();
+                    let v = spherical_tex_coord(&direction).y;
+                    let end = geometry.add_vertex(chord * radius, direction, Vec2::new(1.0, v));
+                    (end, geometry.duplicate_vertex(end, Vec2::new(0.0, v)))
+                });
+                before.push((end, false));
+                after.push((start, false));
+            }
+        }
+        add_polygon(&mut geometry, &before);
+        add_polygon(&mut geometry, &after);
+    }
+    geometry.into_builder()
+}
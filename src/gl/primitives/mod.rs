@@ -0,0 +1,8 @@
+pub mod cylinder;
+pub mod geometry;
+pub mod grid;
+pub mod icosphere;
+pub mod sphere;
+pub mod terrain;
+pub mod torus;
+pub mod validation;
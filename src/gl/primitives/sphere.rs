@@ -0,0 +1,79 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use glm::Vec2;
+
+use crate::gl::mesh::MeshBuilder;
+
+use super::geometry::{Geometry, LatheRing};
+
+pub fn uv_sphere(radius: f32, segments: usize, rings: usize) -> MeshBuilder {
+    let rings = rings.max(2);
+    let profile: Vec<LatheRing> = (0..=rings)
+        .map(|ring| {
+            let v = ring as f32 / rings as f32;
+            let (sin, cos) = (v * PI).sin_cos();
+            LatheRing {
+                radius: if ring == 0 || ring == rings {
+                    0.0
+                } else {
+                    radius * sin
+                },
+                y: radius * cos,
+                normal: Vec2::new(sin, cos),
+                v,
+            }
+        })
+        .collect();
+
+    let mut geometry = Geometry::new();
+    geometry.add_lathe(&profile, segments);
+    geometry.into_builder()
+}
+
+pub fn capsule(radius: f32, height: f32, segments: usize, rings: usize) -> MeshBuilder {
+    let rings = rings.max(1);
+    let half_height = height.max(0.0) * 0.5;
+    let hemisphere = |offset: f32, start: f32| {
+        (0..=rings).map(move |ring| {
+            let theta = start + ring as f32 / rings as f32 * FRAC_PI_2;
+            let (sin, cos) = theta.sin_cos();
+            (radius * sin, offset + radius * cos, Vec2::new(sin, cos))
+        })
+    };
+    let shared_equator = if half_height > 0.0 { 0 } else { 1 };
+    let points: Vec<_> = hemisphere(half_height, 0.0)
+        .chain(hemisphere(-half_height, FRAC_PI_2).skip(shared_equator))
+        .collect();
+
+    let length = PI * radius + 2.0 * half_height;
+    let last = points.len() - 1;
+    let mut travelled = 0.0;
+    let profile: Vec<LatheRing> = points
+        .iter()
+        .enumerate()
+        .map(|(index, &(ring_radius, y, normal))| {
+            if index > 0 {
+                let (previous_radius, previous_y, _) = points[index - 1];
+                travelled += Vec2::new(ring_radius - previous_radius, y - previous_y).norm();
+            }
+            LatheRing {
+                radius: if index == 0 || index == last {
+                    0.0
+                } else {
+                    ring_radius
+                },
+                y,
+                normal,
+                v: if length > 0.0 {
+                    travelled / length
+                } else {
+                    0.0
+                },
+            }
+        })
+        .collect();
+
+    let mut geometry = Geometry::new();
+    geometry.add_lathe(&profile, segments);
+    geometry.into_builder()
+}
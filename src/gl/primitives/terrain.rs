@@ -0,0 +1,128 @@
+use glm::{Vec2, Vec3};
+
+use crate::gl::mesh::MeshBuilder;
+
+use super::geometry::Geometry;
+use super::grid::add_grid_faces;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heightmap {
+    width: usize,
+    height: usize,
+    values: Vec<f32>,
+}
+
+impl Heightmap {
+    pub fn from_fn(width: usize, height: usize, sample: impl Fn(f32, f32) -> f32) -> Self {
+        let (width, height) = (width.max(2), height.max(2));
+        let values = (0..width * height)
+            .map(|index| {
+                let u = (index % width) as f32 / (width - 1) as f32;
+                let v = (index / width) as f32 / (height - 1) as f32;
+                sample(u, v)
+            })
+            .collect();
+        Self {
+            width,
+            height,
+            values,
+        }
+    }
+
+    pub fn from_rgba8(width: usize, height: usize, pixels: &[u8]) -> Self {
+        Self::from_fn(width, height, |u, v| {
+            let x = (u * (width.max(1) - 1) as f32).round() as usize;
+            let y = (v * (height.max(1) - 1) as f32).round() as usize;
+            pixels
+                .get((y * width + x) * 4)
+                .map_or(0.0, |&red| red as f32 / 255.0)
+        })
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    pub fn value(&self, x: usize, y: usize) -> f32 {
+        self.values[y.min(self.height - 1) * self.width + x.min(self.width - 1)]
+    }
+
+    pub fn sample(&self, u: f32, v: f32) -> f32 {
+        let x = u.clamp(0.0, 1.0) * (self.width - 1) as f32;
+        let y = v.clamp(0.0, 1.0) * (self.height - 1) as f32;
+        let (x0, y0) = (x.floor() as usize, y.floor() as usize);
+        let (tx, ty) = (x.fract(), y.fract());
+
+        let top = self.value(x0, y0) * (1.0 - tx) + self.value(x0 + 1, y0) * tx;
+        let bottom = self.value(x0, y0 + 1) * (1.0 - tx) + self.value(x0 + 1, y0 + 1) * tx;
+        top * (1.0 - ty) + bottom * ty
+    }
+}
+
+pub fn terrain(
+    heightmap: &Heightmap,
+    size: Vec2,
+    height_scale: f32,
+    columns: usize,
+    rows: usize,
+) -> MeshBuilder {
+    let columns = columns.max(1);
+    let rows = rows.max(1);
+    let (du, dv) = (1.0 / columns as f32, 1.0 / rows as f32);
+    let height_at = |u: f32, v: f32| heightmap.sample(u, v) * height_scale;
+
+    let mut geometry = Geometry::new();
+    for row in 0..=rows {
+        let v = row as f32 * dv;
+        let (v0, v1) = (
+            row.saturating_sub(1) as f32 * dv,
+            (row + 1).min(rows) as f32 * dv,
+        );
+        for column in 0..=columns {
+            let u = column as f32 * du;
+            let (u0, u1) = (
+                column.saturating_sub(1) as f32 * du,
+                (column + 1).min(columns) as f32 * du,
+            );
+            let slope_x = (height_at(u1, v) - height_at(u0, v)) / ((u1 - u0) * size.x);
+            let slope_z = (height_at(u, v1) - height_at(u, v0)) / ((v1 - v0) * size.y);
+            geometry.add_vertex(
+                Vec3::new((u - 0.5) * size.x, height_at(u, v), (v - 0.5) * size.y),
+                Vec3::new(-slope_x, 1.0, -slope_z).normalize(),
+                Vec2::new(u, 1.0 - v),
+            );
+        }
+    }
+    add_grid_faces(&mut geometry, columns, rows);
+    geometry.into_builder()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f32 = 1e-5;
+
+    #[test]
+    fn ramp_has_uniform_normals_up_to_the_edges() {
+        let heightmap = Heightmap::from_fn(9, 5, |u, v| u + 0.5 * v);
+        let size = Vec2::new(4.0, 2.0);
+        let builder = terrain(&heightmap, size, 2.0, 8, 4);
+
+        let expected = Vec3::new(-2.0 / size.x, 1.0, -1.0 / size.y).normalize();
+        assert_eq!(builder.normals().ncols(), 9 * 5);
+        for normal in builder.normals().column_iter() {
+            assert!((normal - expected).norm() < TOLERANCE, "{:?}", normal);
+        }
+    }
+
+    #[test]
+    fn single_cell_uses_one_sided_differences() {
+        let heightmap = Heightmap::from_fn(2, 2, |u, _| u);
+        let builder = terrain(&heightmap, Vec2::new(1.0, 1.0), 1.0, 1, 1);
+        let expected = Vec3::new(-1.0, 1.0, 0.0).normalize();
+        for normal in builder.normals().column_iter() {
+            assert!((normal - expected).norm() < TOLERANCE, "{:?}", normal);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::f32::consts::TAU;
+
+use glm::{Vec2, Vec3};
+
+use crate::gl::mesh::MeshBuilder;
+
+use super::geometry::Geometry;
+
+pub fn torus(
+    major_radius: f32,
+    minor_radius: f32,
+    radial_segments: usize,
+    tubular_segments: usize,
+) -> MeshBuilder {
+    let radial_segments = radial_segments.max(3);
+    let tubular_segments = tubular_segments.max(3);
+    let mut geometry = Geometry::new();
+
+    for tubular in 0..=tubular_segments {
+        let u = tubular as f32 / tubular_segments as f32;
+        let (sin_phi, cos_phi) = (u * TAU).sin_cos();
+        let outward = Vec3::new(cos_phi, 0.0, -sin_phi);
+        for radial in 0..=radial_segments {
+            let v = radial as f32 / radial_segments as f32;
+            let (sin_theta, cos_theta) = (v * TAU).sin_cos();
+            let normal = outward * cos_theta + Vec3::y() * sin_theta;
+            geometry.add_vertex(
+                outward * major_radius + normal * minor_radius,
+                normal,
+                Vec2::new(u, v),
+            );
+        }
+    }
+
+    let stride = (radial_segments + 1) as i32;
+    for tubular in 0..tubular_segments as i32 {
+        for radial in 0..radial_segments as i32 {
+            let a = tubular * stride + radial;
+            let next_tubular = a + stride;
+            geometry.add_triangle(a, next_tubular, a + 1);
+            geometry.add_triangle(next_tubular, next_tubular + 1, a + 1);
+        }
+    }
+    geometry.into_builder()
+}
@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use glm::{Vec2, Vec3};
+
+use crate::gl::mesh::MeshBuilder;
+
+pub const DEFAULT_WELD_EPSILON: f32 = 1.0e-5;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeshReport {
+    pub boundary_edges: usize,
+    pub non_manifold_edges: usize,
+    pub inverted_faces: usize,
+    pub degenerate_faces: usize,
+    pub tex_coords_min: Vec2,
+    pub tex_coords_max: Vec2,
+}
+
+impl MeshReport {
+    pub fn is_watertight(&self) -> bool {
+        self.boundary_edges == 0 && self.non_manifold_edges == 0
+    }
+
+    pub fn has_outward_normals(&self) -> bool {
+        self.inverted_faces == 0
+    }
+
+    pub fn tex_coords_in_unit_range(&self) -> bool {
+        self.tex_coords_min.min() >= 0.0 && self.tex_coords_max.max() <= 1.0
+    }
+}
+
+fn weld_key(position: &Vec3, epsilon: f32) -> (i64, i64, i64) {
+    let quantize = |value: f32| (value / epsilon).round() as i64;
+    (
+        quantize(position.x),
+        quantize(position.y),
+        quantize(position.z),
+    )
+}
+
+pub fn inspect(builder: &MeshBuilder, weld_epsilon: f32) -> MeshReport {
+    let vertices = builder.vertices();
+    let normals = builder.normals();
+    let tex_coords = builder.tex_coords();
+
+    let mut welded_ids: HashMap<(i64, i64, i64), usize> = HashMap::new();
+    let welded: Vec<usize> = vertices
+        .column_iter()
+        .map(|position| {
+            let next = welded_ids.len();
+            *welded_ids
+                .entry(weld_key(&position.into_owned(), weld_epsilon))
+                .or_insert(next)
+        })
+        .collect();
+
+    let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut inverted_faces = 0;
+    let mut degenerate_faces = 0;
+    for face in builder.faces().column_iter() {
+        let corners = [face[0] as usize, face[1] as usize, face[2] as usize];
+        let ids = corners.map(|corner| welded[corner]);
+        if ids[0] == ids[1] || ids[1] == ids[2] || ids[2] == ids[0] {
+            degenerate_faces += 1;
+            continue;
+        }
+        for edge in 0..3 {
+            *edges.entry((ids[edge], ids[(edge + 1) % 3])).or_insert(0) += 1;
+        }
+
+        if normals.ncols() == vertices.ncols() {
+            let [a, b, c] = corners.map(|corner| vertices.column(corner).into_owned());
+            let face_normal = (b - a).cross(&(c - a));
+            let vertex_normal: Vec3 = corners
+                .iter()
+                .map(|&corner| normals.column(corner).into_owned())
+                .sum();
+            if face_normal.dot(&vertex_normal) < 0.0 {
+                inverted_faces += 1;
+            }
+        }
+    }
+
+    let mut boundary_edges = 0;
+    let mut non_manifold_edges = 0;
+    for (&(from, to), &count) in edges.iter() {
+        let opposite = edges.get(&(to, from)).copied().unwrap_or(0);
+        if count > 1 || opposite > 1 {
+            non_manifold_edges += 1;
+        } else if opposite == 0 {
+            boundary_edges += 1;
+        }
+    }
+
+    let (tex_coords_min, tex_coords_max) = if tex_coords.ncols() > 0 {
+        (
+            Vec2::new(tex_coords.row(0).min(), tex_coords.row(1).min()),
+            Vec2::new(tex_coords.row(0).max(), tex_coords.row(1).max()),
+        )
+    } else {
+        (Vec2::zeros(), Vec2::zeros())
+    };
+
+    MeshReport {
+        boundary_edges,
+        non_manifold_edges,
+        inverted_faces,
+        degenerate_faces,
+        tex_coords_min,
+        tex_coords_max,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gl::primitives::cylinder::{cone, cylinder};
+    use crate::gl::primitives::grid::{grid, plane};
+    use crate::gl::primitives::icosphere::icosphere;
+    use crate::gl::primitives::sphere::{capsule, uv_sphere};
+    use crate::gl::primitives::terrain::{terrain, Heightmap};
+    use crate::gl::primitives::torus::torus;
+
+    const SEGMENTS: usize = 16;
+    const MAJOR_RADIUS: f32 = 1.0;
+
+    fn closed_primitives() -> Vec<(&'static str, MeshBuilder)> {
+        vec![
+            ("uv_sphere", uv_sphere(1.0, SEGMENTS, 8)),
+            ("icosahedron", icosphere(1.0, 0)),
+            ("icosphere", icosphere(1.0, 2)),
+            ("fine_icosphere", icosphere(1.0, 4)),
+            ("capsule", capsule(0.5, 1.0, SEGMENTS, 4)),
+            ("flat_capsule", capsule(0.5, 0.0, SEGMENTS, 4)),
+            ("cylinder", cylinder(0.5, 0.5, 2.0, SEGMENTS, 3, true)),
+            ("frustum", cylinder(0.8, 0.3, 1.0, SEGMENTS, 2, true)),
+            ("cone", cone(0.5, 1.0, SEGMENTS, 2)),
+            ("torus", torus(MAJOR_RADIUS, 0.25, SEGMENTS, 24)),
+        ]
+    }
+
+    fn open_primitives() -> Vec<(&'static str, MeshBuilder, usize)> {
+        let heightmap = Heightmap::from_fn(8, 8, |u, v| (u * 6.0).sin() * (v * 4.0).cos());
+        vec![
+            ("grid", grid(2.0, 3.0, 4, 6), 2 * (4 + 6)),
+            ("plane", plane(1.0), 4),
+            (
+                "terrain",
+                terrain(&heightmap, Vec2::new(4.0, 4.0), 0.5, 10, 7),
+                2 * (10 + 7),
+            ),
+            (
+                "open_cylinder",
+                cylinder(0.5, 0.5, 1.0, SEGMENTS, 2, false),
+                2 * SEGMENTS,
+            ),
+        ]
+    }
+
+    fn all_primitives() -> Vec<(&'static str, MeshBuilder)> {
+        closed_primitives()
+            .into_iter()
+            .chain(
+                open_primitives()
+                    .into_iter()
+                    .map(|(name, builder, _)| (name, builder)),
+            )
+            .collect()
+    }
+
+    fn torus_ring_point(position: &Vec3) -> Vec3 {
+        Vec3::new(position.x, 0.0, position.z).normalize() * MAJOR_RADIUS
+    }
+
+    #[test]
+    fn closed_primitives_are_watertight() {
+        for (name, builder) in closed_primitives() {
+            let report = inspect(&builder, DEFAULT_WELD_EPSILON);
+            assert!(report.is_watertight(), "{}: {:?}", name, report);
+        }
+    }
+
+    #[test]
+    fn open_primitives_only_have_outer_boundary() {
+        for (name, builder, boundary_edges) in open_primitives() {
+            let report = inspect(&builder, DEFAULT_WELD_EPSILON);
+            assert_eq!(report.non_manifold_edges, 0, "{}", name);
+            assert_eq!(report.degenerate_faces, 0, "{}", name);
+            assert_eq!(report.boundary_edges, boundary_edges, "{}", name);
+        }
+    }
+
+    #[test]
+    fn normals_agree_with_winding() {
+        for (name, builder) in all_primitives() {
+            assert_eq!(
+                builder.normals().ncols(),
+                builder.vertices().ncols(),
+                "{}",
+                name
+            );
+            let report = inspect(&builder, DEFAULT_WELD_EPSILON);
+            assert!(report.has_outward_normals(), "{}: {:?}", name, report);
+        }
+    }
+
+    #[test]
+    fn closed_normals_point_outward() {
+        for (name, builder) in closed_primitives() {
+            let vertices = builder.vertices();
+            for (index, normal) in builder.normals().column_iter().enumerate() {
+                let position: Vec3 = vertices.column(index).into_owned();
+                let center = if name == "torus" {
+                    torus_ring_point(&position)
+                } else {
+                    Vec3::zeros()
+                };
+                assert!(
+                    normal.dot(&(position - center)) > 0.0,
+                    "{}: vertex {} at {:?} has inward normal {:?}",
+                    name,
+                    index,
+                    position,
+                    normal
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn tex_coords_stay_in_unit_range() {
+        for (name, builder) in all_primitives() {
+            assert_eq!(
+                builder.tex_coords().ncols(),
+                builder.vertices().ncols(),
+                "{}",
+                name
+            );
+            let report = inspect(&builder, DEFAULT_WELD_EPSILON);
+            assert!(report.tex_coords_in_unit_range(), "{}: {:?}", name, report);
+        }
+    }
+
+    #[test]
+    fn closed_faces_do_not_stretch_across_seam() {
+        for (name, builder) in closed_primitives() {
+            let tex_coords = builder.tex_coords();
+            for face in builder.faces().column_iter() {
+                let us = face.map(|corner| tex_coords[(0, corner as usize)]);
+                assert!(us.max() - us.min() <= 0.5, "{}: face spans seam", name);
+            }
+        }
+    }
+
+    #[test]
+    fn reports_flipped_and_degenerate_faces() {
+        let sphere = icosphere(1.0, 1);
+        let mut faces = sphere.faces().clone();
+        faces.swap((1, 0), (2, 0));
+        let degenerate = faces.ncols() - 1;
+        faces[(2, degenerate)] = faces[(0, degenerate)];
+        let broken = MeshBuilder::new(sphere.vertices().clone(), faces)
+            .add_normals(sphere.normals().clone());
+
+        let report = inspect(&broken, DEFAULT_WELD_EPSILON);
+        assert_eq!(report.inverted_faces, 1);
+        assert_eq!(report.degenerate_faces, 1);
+        assert!(!report.is_watertight());
+        assert!(!report.has_outward_normals());
+    }
+}
@@ -0,0 +1,78 @@
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+use glm::Mat4x4;
+
+use crate::gl::materials::material::Material;
+use crate::gl::materials::pipeline_state::PipelineState;
+use crate::gl::mesh::Mesh;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrawKey {
+    pub program: u32,
+    pub material: u32,
+    pub state: PipelineState,
+    pub mesh: u32,
+    pub depth: f32,
+}
+
+impl DrawKey {
+    pub fn opaque_order(&self, other: &Self) -> Ordering {
+        self.program
+            .cmp(&other.program)
+            .then(self.material.cmp(&other.material))
+            .then(self.mesh.cmp(&other.mesh))
+            .then(self.depth.total_cmp(&other.depth))
+    }
+
+    pub fn transparent_order(&self, other: &Self) -> Ordering {
+        other.depth.total_cmp(&self.depth)
+    }
+}
+
+#[derive(Clone)]
+pub struct DrawItem {
+    pub mesh: Rc<Mesh>,
+    pub material: Rc<Material>,
+    pub world: Mat4x4,
+    pub depth: f32,
+}
+
+impl DrawItem {
+    pub fn new(mesh: &Rc<Mesh>, material: &Rc<Material>, world: Mat4x4) -> Self {
+        Self {
+            mesh: Rc::clone(mesh),
+            material: Rc::clone(material),
+            world,
+            depth: 0.0,
+        }
+    }
+
+    pub fn update_depth(&mut self, view: &Mat4x4) {
+        let center = self.mesh.bounding_sphere().center;
+        let view_position = view * self.world * center.push(1.0);
+        self.depth = -view_position.z;
+    }
+
+    pub fn program_uid(&self) -> u32 {
+        self.material.program().uid()
+    }
+
+    pub fn key(&self) -> DrawKey {
+        DrawKey {
+            program: self.program_uid(),
+            material: self.material.uid(),
+            state: *self.material.state(),
+            mesh: self.mesh.uid(),
+            depth: self.depth,
+        }
+    }
+
+    pub fn opaque_order(&self, other: &Self) -> Ordering {
+        self.key().opaque_order(&other.key())
+    }
+
+    pub fn transparent_order(&self, other: &Self) -> Ordering {
+        self.key().transparent_order(&other.key())
+    }
+}
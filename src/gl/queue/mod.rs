@@ -0,0 +1,4 @@
+pub mod draw_item;
+pub mod render_queue;
+pub mod render_stats;
+pub mod state_tracker;
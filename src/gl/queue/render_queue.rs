@@ -0,0 +1,180 @@
+use std::rc::Rc;
+
+use glm::Mat4x4;
+
+use crate::gl::camera::{Camera, CameraUniformLocation};
+use crate::gl::core::instance::GL;
+use crate::gl::materials::material::Material;
+use crate::gl::materials::pipeline_state::PipelineState;
+use crate::gl::mesh::{BindedMeshCtx, Mesh};
+use crate::gl::shader::shader_use_ctx::ShaderUseCtx;
+
+use super::draw_item::{DrawItem, DrawKey};
+use super::render_stats::RenderStats;
+use super::state_tracker::StateTracker;
+
+pub struct RenderQueue {
+    opaque: Vec<DrawItem>,
+    transparent: Vec<DrawItem>,
+    stats: RenderStats,
+    gl: Rc<GL>,
+}
+
+impl RenderQueue {
+    pub fn new(gl: &Rc<GL>) -> Self {
+        Self {
+            opaque: vec![],
+            transparent: vec![],
+            stats: RenderStats::default(),
+            gl: Rc::clone(gl),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.opaque.len() + self.transparent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn stats(&self) -> RenderStats {
+        self.stats
+    }
+
+    pub fn clear(&mut self) {
+        self.opaque.clear();
+        self.transparent.clear();
+    }
+
+    pub fn push(&mut self, mesh: &Rc<Mesh>, material: &Rc<Material>, world: Mat4x4) {
+        let item = DrawItem::new(mesh, material, world);
+        if material.state().is_transparent() {
+            self.transparent.push(item);
+        } else {
+            self.opaque.push(item);
+        }
+    }
+
+    pub fn sort(&mut self, camera: &Camera) {
+        for item in self.opaque.iter_mut().chain(self.transparent.iter_mut()) {
+            item.update_depth(&camera.view);
+        }
+        self.opaque.sort_by(DrawItem::opaque_order);
+        self.transparent.sort_by(DrawItem::transparent_order);
+    }
+
+    pub fn submit(&mut self, camera: &Camera) -> RenderStats {
+        self.sort(camera);
+        let items = self.opaque.iter().chain(self.transparent.iter());
+        self.stats = submit_items(&self.gl, camera, items);
+        self.clear();
+        self.stats
+    }
+
+    pub fn submit_opaque(&mut self, camera: &Camera) -> RenderStats {
+        self.sort(camera);
+        self.stats = submit_items(&self.gl, camera, self.opaque.iter());
+        self.opaque.clear();
+        self.stats
+    }
+
+    pub fn submit_transparent(&mut self, camera: &Camera) -> RenderStats {
+        self.sort(camera);
+        self.stats = submit_items(&self.gl, camera, self.transparent.iter());
+        self.transparent.clear();
+        self.stats
+    }
+}
+
+fn submit_items<'a>(
+    gl: &'a GL,
+    camera: &'a Camera,
+    items: impl Iterator<Item = &'a DrawItem>,
+) -> RenderStats {
+    let mut submission = Submission::new(gl, camera);
+    for item in items {
+        submission.draw(item);
+    }
+    submission.finish()
+}
+
+struct Submission<'a> {
+    gl: &'a GL,
+    camera: &'a Camera,
+    shader_use: Option<(ShaderUseCtx<'a>, CameraUniformLocation)>,
+    material: Option<&'a Material>,
+    mesh: Option<BindedMeshCtx<'a>>,
+    tracker: StateTracker,
+}
+
+impl<'a> Submission<'a> {
+    fn new(gl: &'a GL, camera: &'a Camera) -> Self {
+        Self {
+            gl,
+            camera,
+            shader_use: None,
+            material: None,
+            mesh: None,
+            tracker: StateTracker::new(),
+        }
+    }
+
+    fn draw(&mut self, item: &'a DrawItem) {
+        let key = item.key();
+        let state = key.state.for_projection(self.camera.projection());
+        let changes = self.tracker.track(&DrawKey { state, ..key });
+
+        if changes.program {
+            self.release_mesh();
+            self.release_material();
+            self.shader_use = None;
+            let program = item.material.program();
+            let u_camera = program.get_uniform_location::<Camera>("u_camera");
+            self.shader_use = Some((program.use_program(), u_camera));
+        }
+
+        let material: &'a Material = &item.material;
+        if changes.material {
+            self.release_material();
+            if changes.state {
+                state.apply(self.gl);
+            }
+            material.upload_params();
+            self.material = Some(material);
+        }
+
+        let (shader_use, u_camera) = match &self.shader_use {
+            Some(shader_use) => shader_use,
+            None => return,
+        };
+
+        if changes.mesh {
+            self.mesh = None;
+            self.mesh = Some(item.mesh.bind(shader_use));
+        }
+
+        shader_use.set_uniform(u_camera, &self.camera.with_model(item.world));
+        if let Some(binded) = &self.mesh {
+            binded.draw(shader_use);
+        }
+    }
+
+    fn release_mesh(&mut self) {
+        self.mesh = None;
+    }
+
+    fn release_material(&mut self) {
+        if let Some(material) = self.material.take() {
+            material.unbind_textures();
+        }
+    }
+
+    fn finish(mut self) -> RenderStats {
+        self.release_mesh();
+        self.release_material();
+        self.shader_use = None;
+        PipelineState::reset(self.gl);
+        self.tracker.stats()
+    }
+}
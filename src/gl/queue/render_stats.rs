@@ -0,0 +1,24 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    pub draw_calls: usize,
+    pub program_changes: usize,
+    pub material_changes: usize,
+    pub pipeline_state_changes: usize,
+    pub mesh_binds: usize,
+}
+
+impl RenderStats {
+    pub fn state_changes(&self) -> usize {
+        self.program_changes + self.material_changes + self.pipeline_state_changes + self.mesh_binds
+    }
+
+    pub fn merge(&self, other: &RenderStats) -> RenderStats {
+        RenderStats {
+            draw_calls: self.draw_calls + other.draw_calls,
+            program_changes: self.program_changes + other.program_changes,
+            material_changes: self.material_changes + other.material_changes,
+            pipeline_state_changes: self.pipeline_state_changes + other.pipeline_state_changes,
+            mesh_binds: self.mesh_binds + other.mesh_binds,
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use crate::gl::materials::pipeline_state::PipelineState;
+
+use super::draw_item::DrawKey;
+use super::render_stats::RenderStats;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StateChanges {
+    pub program: bool,
+    pub material: bool,
+    pub state: bool,
+    pub mesh: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct StateTracker {
+    program: Option<u32>,
+    material: Option<u32>,
+    state: Option<PipelineState>,
+    mesh: Option<u32>,
+    stats: RenderStats,
+}
+
+impl StateTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn track(&mut self, key: &DrawKey) -> StateChanges {
+        let mut changes = StateChanges::default();
+
+        if self.program != Some(key.program) {
+            self.program = Some(key.program);
+            self.material = None;
+            self.mesh = None;
+            self.stats.program_changes += 1;
+            changes.program = true;
+        }
+
+        if self.material != Some(key.material) {
+            if self.state != Some(key.state) {
+                self.state = Some(key.state);
+                self.stats.pipeline_state_changes += 1;
+                changes.state = true;
+            }
+            self.material = Some(key.material);
+            self.stats.material_changes += 1;
+            changes.material = true;
+        }
+
+        if self.mesh != Some(key.mesh) {
+            self.mesh = Some(key.mesh);
+            self.stats.mesh_binds += 1;
+            changes.mesh = true;
+        }
+
+        self.stats.draw_calls += 1;
+        changes
+    }
+
+    pub fn stats(&self) -> RenderStats {
+        self.stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gl::materials::pipeline_state::BlendMode;
+
+    fn key(program: u32, material: u32, mesh: u32, depth: f32) -> DrawKey {
+        DrawKey {
+            program,
+            material,
+            state: PipelineState::opaque(),
+            mesh,
+            depth,
+        }
+    }
+
+    fn transparent_key(program: u32, material: u32, mesh: u32, depth: f32) -> DrawKey {
+        DrawKey {
+            state: PipelineState::transparent(BlendMode::Alpha),
+            ..key(program, material, mesh, depth)
+        }
+    }
+
+    fn submit<'a>(keys: impl Iterator<Item = &'a DrawKey>) -> RenderStats {
+        let mut tracker = StateTracker::new();
+        for key in keys {
+            tracker.track(key);
+        }
+        tracker.stats()
+    }
+
+    fn queue() -> Vec<DrawKey> {
+        vec![
+            key(2, 20, 200, 5.0),
+            transparent_key(1, 30, 100, 2.0),
+            key(1, 10, 101, 3.0),
+            key(2, 21, 200, 1.0),
+            key(1, 11, 100, 4.0),
+            transparent_key(2, 31, 200, 9.0),
+            key(1, 10, 100, 8.0),
+            key(2, 20, 200, 2.0),
+            key(1, 10, 100, 6.0),
+            transparent_key(1, 30, 100, 5.0),
+            key(2, 21, 201, 7.0),
+            key(1, 11, 100, 1.0),
+        ]
+    }
+
+    #[test]
+    fn sorts_opaque_by_state_and_transparent_back_to_front() {
+        let (mut opaque, mut transparent): (Vec<DrawKey>, Vec<DrawKey>) = queue()
+            .into_iter()
+            .partition(|key| !key.state.is_transparent());
+        opaque.sort_by(DrawKey::opaque_order);
+        transparent.sort_by(DrawKey::transparent_order);
+
+        let order: Vec<(u32, u32, u32, f32)> = opaque
+            .iter()
+            .map(|key| (key.program, key.material, key.mesh, key.depth))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                (1, 10, 100, 6.0),
+                (1, 10, 100, 8.0),
+                (1, 10, 101, 3.0),
+                (1, 11, 100, 1.0),
+                (1, 11, 100, 4.0),
+                (2, 20, 200, 2.0),
+                (2, 20, 200, 5.0),
+                (2, 21, 200, 1.0),
+                (2, 21, 201, 7.0),
+            ]
+        );
+
+        let depths: Vec<f32> = transparent.iter().map(|key| key.depth).collect();
+        assert_eq!(depths, vec![9.0, 5.0, 2.0]);
+    }
+
+    #[test]
+    fn sorting_reduces_state_changes() {
+        let unsorted = queue();
+        let (mut opaque, mut transparent): (Vec<DrawKey>, Vec<DrawKey>) = unsorted
+            .iter()
+            .copied()
+            .partition(|key| !key.state.is_transparent());
+        opaque.sort_by(DrawKey::opaque_order);
+        transparent.sort_by(DrawKey::transparent_order);
+
+        let unsorted_stats = submit(unsorted.iter());
+        let sorted_stats = submit(opaque.iter().chain(transparent.iter()));
+
+        assert_eq!(unsorted_stats.draw_calls, 12);
+        assert_eq!(sorted_stats.draw_calls, 12);
+        assert_eq!(
+            sorted_stats,
+            RenderStats {
+                draw_calls: 12,
+                program_changes: 3,
+                material_changes: 6,
+                pipeline_state_changes: 2,
+                mesh_binds: 7,
+            }
+        );
+        assert!(sorted_stats.program_changes < unsorted_stats.program_changes);
+        assert!(sorted_stats.material_changes < unsorted_stats.material_changes);
+        assert!(sorted_stats.state_changes() < unsorted_stats.state_changes());
+    }
+
+    #[test]
+    fn program_change_rebinds_material_and_mesh() {
+        let mut tracker = StateTracker::new();
+        let first = tracker.track(&key(1, 10, 100, 0.0));
+        assert_eq!(
+            first,
+            StateChanges {
+                program: true,
+                material: true,
+                state: true,
+                mesh: true,
+            }
+        );
+
+        let repeat = tracker.track(&key(1, 10, 100, 1.0));
+        assert_eq!(repeat, StateChanges::default());
+
+        let switched = tracker.track(&key(2, 10, 100, 2.0));
+        assert_eq!(
+            switched,
+            StateChanges {
+                program: true,
+                material: true,
+                state: false,
+                mesh: true,
+            }
+        );
+        assert_eq!(tracker.stats().pipeline_state_changes, 1);
+    }
+}
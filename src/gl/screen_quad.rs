@@ -0,0 +1,35 @@
+use std::rc::Rc;
+
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::mesh::Mesh;
+use crate::{faces, vertices};
+
+pub const SCREEN_QUAD_VERTEX_SHADER: &str = r##"#version 300 es
+    in vec3 a_position;
+
+    out vec2 v_uv;
+
+    void main() {
+        v_uv = a_position.xy * 0.5 + 0.5;
+        gl_Position = vec4(a_position.xy, 0.0, 1.0);
+    }
+"##;
+
+pub fn build_screen_quad(gl: &Rc<GL>) -> Result<Mesh, GLError> {
+    let vertices = vertices![-1.0, -1.0, 0.0, 1.0, -1.0, 0.0, 1.0, 1.0, 0.0, -1.0, 1.0, 0.0,];
+    let faces = faces![0, 1, 2, 2, 3, 0];
+    Mesh::new_builder(vertices, faces).build(gl)
+}
+
+pub fn build_unit_cube(gl: &Rc<GL>) -> Result<Mesh, GLError> {
+    let vertices = vertices![
+        -1.0, -1.0, -1.0, 1.0, -1.0, -1.0, 1.0, 1.0, -1.0, -1.0, 1.0, -1.0, -1.0, -1.0, 1.0, 1.0,
+        -1.0, 1.0, 1.0, 1.0, 1.0, -1.0, 1.0, 1.0,
+    ];
+    let faces = faces![
+        0, 2, 1, 0, 3, 2, 4, 5, 6, 4, 6, 7, 0, 1, 5, 0, 5, 4, 3, 6, 2, 3, 7, 6, 0, 4, 7, 0, 7, 3,
+        1, 2, 6, 1, 6, 5,
+    ];
+    Mesh::new_builder(vertices, faces).build(gl)
+}
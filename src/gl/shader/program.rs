@@ -10,6 +10,7 @@ use std::rc::Rc;
 use super::uniform_value::UniformValue;
 use std::sync::atomic::AtomicU32;
 use std::sync::atomic::Ordering;
+use wasm_bindgen::JsValue;
 use web_sys::WebGlProgram;
 use web_sys::WebGlShader;
 use web_sys::WebGlUniformLocation;
@@ -24,6 +25,7 @@ pub struct ShaderProgram {
 #[derive(Debug)]
 pub struct ShaderProgramBuilder {
     shaders: Vec<(ShaderType, String)>,
+    feedback_varyings: Vec<String>,
     gl: Rc<GL>,
 }
 
@@ -31,6 +33,7 @@ impl ShaderProgramBuilder {
     pub fn new(gl: &Rc<GL>) -> Self {
         Self {
             shaders: vec![],
+            feedback_varyings: vec![],
             gl: Rc::clone(gl),
         }
     }
@@ -40,8 +43,13 @@ impl ShaderProgramBuilder {
         self
     }
 
+    pub fn with_feedback_varyings(mut self, varyings: &[&str]) -> Self {
+        self.feedback_varyings = varyings.iter().map(|&name| name.into()).collect();
+        self
+    }
+
     pub fn build(self) -> Result<ShaderProgram, ShaderError> {
-        ShaderProgram::new(&self.gl, self.shaders.as_slice())
+        ShaderProgram::with_feedback(&self.gl, &self.shaders, &self.feedback_varyings)
     }
 }
 
@@ -51,6 +59,14 @@ impl ShaderProgram {
     }
 
     pub fn new(gl: &Rc<GL>, sources: &[(ShaderType, String)]) -> Result<Self, ShaderError> {
+        Self::with_feedback(gl, sources, &[])
+    }
+
+    pub fn with_feedback(
+        gl: &Rc<GL>,
+        sources: &[(ShaderType, String)],
+        feedback_varyings: &[String],
+    ) -> Result<Self, ShaderError> {
         static NEXT_UID: AtomicU32 = AtomicU32::new(0);
 
         let mut compiled_shaders = vec![];
@@ -58,7 +74,7 @@ impl ShaderProgram {
             compiled_shaders.push(compile_shader(gl, text, *shader_type)?);
         }
 
-        let program = link_into_program(gl, &compiled_shaders)?;
+        let program = link_into_program(gl, &compiled_shaders, feedback_varyings)?;
         let uid = NEXT_UID.fetch_add(1, Ordering::SeqCst);
 
         Ok(ShaderProgram {
@@ -150,11 +166,22 @@ fn compile_shader<'a>(
     }
 }
 
-fn link_into_program(gl: &GL, shaders: &[CompiledShader]) -> Result<WebGlProgram, ShaderError> {
+fn link_into_program(
+    gl: &GL,
+    shaders: &[CompiledShader],
+    feedback_varyings: &[String],
+) -> Result<WebGlProgram, ShaderError> {
     let gl_program = gl.create_program().ok_or(ShaderError::ProgramCreateError)?;
     for compiled_shader in shaders {
         gl.attach_shader(&gl_program, &compiled_shader.inner);
     }
+    if !feedback_varyings.is_empty() {
+        let varyings: js_sys::Array = feedback_varyings
+            .iter()
+            .map(|name| JsValue::from_str(name))
+            .collect();
+        gl.transform_feedback_varyings(&gl_program, &varyings, gl::INTERLEAVED_ATTRIBS);
+    }
     gl.link_program(&gl_program);
     let success = gl
         .get_program_parameter(&gl_program, gl::LINK_STATUS)
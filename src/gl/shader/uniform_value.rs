@@ -1,5 +1,5 @@
 use crate::gl::core::instance::GL;
-use glm::{Mat4x4, Vec2, Vec3};
+use glm::{Mat4x4, Vec2, Vec3, Vec4};
 use web_sys::WebGlUniformLocation;
 
 use super::locations::{SimpleUniformLocation, UniformLocation};
@@ -28,6 +28,18 @@ impl SimpleUniformValue for f32 {
     }
 }
 
+impl SimpleUniformValue for u32 {
+    fn simple_set_to(&self, gl: &GL, location: Option<&WebGlUniformLocation>) {
+        gl.uniform1ui(location, *self)
+    }
+}
+
+impl SimpleUniformValue for i32 {
+    fn simple_set_to(&self, gl: &GL, location: Option<&WebGlUniformLocation>) {
+        gl.uniform1i(location, *self)
+    }
+}
+
 impl SimpleUniformValue for Vec2 {
     fn simple_set_to(&self, gl: &GL, location: Option<&WebGlUniformLocation>) {
         gl.uniform2fv_with_f32_array(location, self.as_slice())
@@ -40,8 +52,30 @@ impl SimpleUniformValue for Vec3 {
     }
 }
 
+impl SimpleUniformValue for Vec4 {
+    fn simple_set_to(&self, gl: &GL, location: Option<&WebGlUniformLocation>) {
+        gl.uniform4fv_with_f32_array(location, self.as_slice())
+    }
+}
+
 impl SimpleUniformValue for Mat4x4 {
     fn simple_set_to(&self, gl: &GL, location: Option<&WebGlUniformLocation>) {
         gl.uniform_matrix4fv_with_f32_array(location, false, self.data.as_slice())
     }
 }
+
+impl SimpleUniformValue for Vec<f32> {
+    fn simple_set_to(&self, gl: &GL, location: Option<&WebGlUniformLocation>) {
+        gl.uniform1fv_with_f32_array(location, self)
+    }
+}
+
+impl SimpleUniformValue for Vec<Mat4x4> {
+    fn simple_set_to(&self, gl: &GL, location: Option<&WebGlUniformLocation>) {
+        let data: Vec<f32> = self
+            .iter()
+            .flat_map(|matrix| matrix.data.as_slice().iter().copied())
+            .collect();
+        gl.uniform_matrix4fv_with_f32_array(location, false, &data)
+    }
+}
@@ -0,0 +1,84 @@
+use glm::{Mat4x4, Vec3, Vec4};
+
+use crate::gl::camera::Camera;
+use crate::gl::lighting::light::{Light, LightKind};
+
+const SPOT_SHADOW_NEAR: f32 = 0.05;
+
+pub fn cascade_splits(near: f32, far: f32, count: usize, lambda: f32) -> Vec<f32> {
+    (1..=count)
+        .map(|i| {
+            let part = i as f32 / count as f32;
+            let logarithmic = near * (far / near).powf(part);
+            let uniform = near + (far - near) * part;
+            lambda * logarithmic + (1.0 - lambda) * uniform
+        })
+        .collect()
+}
+
+fn light_up(direction: &Vec3) -> Vec3 {
+    if direction.y.abs() > 0.99 {
+        Vec3::z()
+    } else {
+        Vec3::y()
+    }
+}
+
+pub fn fit_directional_cascade(
+    camera: &Camera,
+    direction: &Vec3,
+    near: f32,
+    far: f32,
+    map_size: i32,
+    caster_distance: f32,
+) -> Option<Mat4x4> {
+    let corners = camera.frustum_corners(near, far)?;
+    let center = corners
+        .iter()
+        .fold(Vec3::zeros(), |sum, corner| sum + corner)
+        / 8.0;
+    let radius = corners
+        .iter()
+        .map(|corner| (corner - center).norm())
+        .fold(0.0f32, f32::max);
+    let radius = (radius * 16.0).ceil() / 16.0;
+
+    let direction = direction.normalize();
+    let eye = center - direction * (radius + caster_distance);
+    let view = glm::look_at(&eye, &center, &light_up(&direction));
+    let mut projection = glm::ortho(
+        -radius,
+        radius,
+        -radius,
+        radius,
+        0.0,
+        2.0 * radius + caster_distance,
+    );
+
+    let half_size = map_size as f32 * 0.5;
+    let origin = projection * view * Vec4::new(0.0, 0.0, 0.0, 1.0) * half_size;
+    let offset_x = (origin.x.round() - origin.x) / half_size;
+    let offset_y = (origin.y.round() - origin.y) / half_size;
+    projection[(0, 3)] += offset_x;
+    projection[(1, 3)] += offset_y;
+
+    Some(projection * view)
+}
+
+pub fn spot_shadow_matrix(light: &Light) -> Option<Mat4x4> {
+    match light.kind {
+        LightKind::Spot {
+            range, outer_angle, ..
+        } => {
+            let fov = (outer_angle * 2.0).min(std::f32::consts::PI * 0.95);
+            let projection = glm::perspective(1.0, fov, SPOT_SHADOW_NEAR, range);
+            let view = glm::look_at(
+                &light.position,
+                &(light.position + light.direction),
+                &light_up(&light.direction),
+            );
+            Some(projection * view)
+        }
+        _ => None,
+    }
+}
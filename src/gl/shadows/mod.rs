@@ -0,0 +1,4 @@
+pub mod cascades;
+pub mod settings;
+pub mod shaders;
+pub mod shadow_renderer;
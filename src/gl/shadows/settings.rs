@@ -0,0 +1,40 @@
+pub const MAX_CASCADES: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShadowSettings {
+    pub map_size: i32,
+    pub cascade_count: usize,
+    pub cascade_split_lambda: f32,
+    pub max_distance: f32,
+    pub max_spot_shadows: usize,
+    pub depth_bias: f32,
+    pub normal_bias: f32,
+    pub slope_scale_bias: f32,
+    pub pcf_radius: i32,
+}
+
+impl Default for ShadowSettings {
+    fn default() -> Self {
+        Self {
+            map_size: 2048,
+            cascade_count: 3,
+            cascade_split_lambda: 0.75,
+            max_distance: 50.0,
+            max_spot_shadows: 2,
+            depth_bias: 0.0015,
+            normal_bias: 0.02,
+            slope_scale_bias: 2.0,
+            pcf_radius: 1,
+        }
+    }
+}
+
+impl ShadowSettings {
+    pub fn cascades(&self) -> usize {
+        self.cascade_count.clamp(1, MAX_CASCADES)
+    }
+
+    pub fn layers(&self) -> usize {
+        self.cascades() + self.max_spot_shadows
+    }
+}
@@ -0,0 +1,84 @@
+pub const SHADOW_DEPTH_VERTEX_SHADER: &str = r##"#version 300 es
+    in vec3 a_position;
+
+    uniform mat4 u_model;
+    uniform mat4 u_light_matrix;
+
+    void main() {
+        gl_Position = u_light_matrix * u_model * vec4(a_position, 1.0);
+    }
+"##;
+
+pub const SHADOW_DEPTH_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    void main() {
+    }
+"##;
+
+const SHADOWS_BLOCK: &str = r##"
+    layout(std140) uniform Shadows {
+        vec4 u_shadow_params;
+        vec4 u_cascade_splits;
+        vec4 u_cascade_info;
+        mat4 u_shadow_matrices[MAX_SHADOW_MAPS];
+    };
+
+    uniform highp sampler2DArrayShadow u_shadow_map;
+
+    float sample_shadow(int layer, vec3 position, vec3 normal, vec3 light_dir) {
+        float n_dot_l = clamp(dot(normal, light_dir), 0.0, 1.0);
+        vec3 offset_position = position + normal * u_shadow_params.z * (1.0 - n_dot_l);
+        vec4 clip = u_shadow_matrices[layer] * vec4(offset_position, 1.0);
+        vec3 coords = clip.xyz / clip.w * 0.5 + 0.5;
+        if (any(lessThan(coords, vec3(0.0))) || any(greaterThan(coords, vec3(1.0)))) {
+            return 1.0;
+        }
+
+        float reference = coords.z - u_shadow_params.y;
+        int radius = int(u_shadow_params.x);
+        vec2 texel = 1.0 / vec2(textureSize(u_shadow_map, 0).xy);
+
+        float lit = 0.0;
+        float samples = 0.0;
+        for (int x = -radius; x <= radius; ++x) {
+            for (int y = -radius; y <= radius; ++y) {
+                vec2 uv = coords.xy + vec2(float(x), float(y)) * texel;
+                lit += texture(u_shadow_map, vec4(uv, float(layer), reference));
+                samples += 1.0;
+            }
+        }
+        return lit / samples;
+    }
+
+    float light_shadow(light_data light, vec3 position, vec3 normal, vec3 light_dir, float view_depth) {
+        int layer = int(light.cone.z);
+        if (layer < 0) {
+            return 1.0;
+        }
+
+        if (light.position.w < 0.5) {
+            int cascades = int(u_cascade_info.x);
+            int cascade = cascades;
+            for (int i = 0; i < 4; ++i) {
+                if (i < cascades && view_depth < u_cascade_splits[i]) {
+                    cascade = i;
+                    break;
+                }
+            }
+            if (cascade >= cascades) {
+                return 1.0;
+            }
+            layer += cascade;
+        }
+        return sample_shadow(layer, position, normal, light_dir);
+    }
+"##;
+
+pub fn shadows_block(max_shadow_maps: usize) -> String {
+    format!(
+        "#define MAX_SHADOW_MAPS {}\n{}",
+        max_shadow_maps.max(1),
+        SHADOWS_BLOCK
+    )
+}
@@ -0,0 +1,205 @@
+use std::rc::Rc;
+
+use glm::Mat4x4;
+
+use crate::gl;
+use crate::gl::buffers::uniform_buffer::UniformBuffer;
+use crate::gl::camera::Camera;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::framebuffers::framebuffer::Framebuffer;
+use crate::gl::lighting::light::{Light, LightKind};
+use crate::gl::materials::material::Material;
+use crate::gl::mesh::Mesh;
+use crate::gl::shader::locations::SimpleUniformLocation;
+use crate::gl::shader::program::ShaderProgram;
+use crate::gl::shader::shader_type::ShaderType;
+use crate::gl::textures::texture::{Texture, TextureFormat};
+use crate::math::frustum::Frustum;
+
+use super::cascades::{cascade_splits, fit_directional_cascade, spot_shadow_matrix};
+use super::settings::{ShadowSettings, MAX_CASCADES};
+use super::shaders::{SHADOW_DEPTH_FRAGMENT_SHADER, SHADOW_DEPTH_VERTEX_SHADER};
+
+pub const SHADOWS_BLOCK_NAME: &str = "Shadows";
+pub const SHADOWS_BINDING: u32 = 1;
+pub const SHADOW_MAP_UNIFORM: &str = "u_shadow_map";
+
+const HEADER_STD140_FLOATS: usize = 12;
+const MATRIX_STD140_FLOATS: usize = 16;
+
+pub struct ShadowRenderer {
+    settings: ShadowSettings,
+    shadow_map: Rc<Texture>,
+    framebuffer: Framebuffer,
+    material: Material,
+    u_model: SimpleUniformLocation<Mat4x4>,
+    u_light_matrix: SimpleUniformLocation<Mat4x4>,
+    buffer: UniformBuffer,
+    matrices: Vec<Option<Mat4x4>>,
+    data: Vec<f32>,
+    gl: Rc<GL>,
+}
+
+impl ShadowRenderer {
+    pub fn try_new(gl: &Rc<GL>, settings: ShadowSettings) -> Result<Self, GLError> {
+        let layers = settings.layers();
+        let shadow_map = Texture::with_layers(
+            gl,
+            TextureFormat::DEPTH32F,
+            (settings.map_size, settings.map_size),
+            layers as i32,
+        )?;
+        shadow_map.set_depth_compare(Some(gl::LEQUAL));
+
+        let shader = Rc::new(
+            ShaderProgram::builder(gl)
+                .add_source(ShaderType::Vertex, SHADOW_DEPTH_VERTEX_SHADER.into())
+                .add_source(ShaderType::Fragment, SHADOW_DEPTH_FRAGMENT_SHADER.into())
+                .build()?,
+        );
+        let u_model = shader.get_uniform_location::<Mat4x4>("u_model");
+        let u_light_matrix = shader.get_uniform_location::<Mat4x4>("u_light_matrix");
+
+        let floats = HEADER_STD140_FLOATS + layers * MATRIX_STD140_FLOATS;
+        let buffer =
+            UniformBuffer::try_new(gl, floats * std::mem::size_of::<f32>(), SHADOWS_BINDING)?;
+
+        Ok(Self {
+            settings,
+            shadow_map: Rc::new(shadow_map),
+            framebuffer: Framebuffer::try_new(gl)?,
+            material: Material::new(gl, &shader),
+            u_model,
+            u_light_matrix,
+            buffer,
+            matrices: vec![None; layers],
+            data: Vec::with_capacity(floats),
+            gl: Rc::clone(gl),
+        })
+    }
+
+    pub fn settings(&self) -> &ShadowSettings {
+        &self.settings
+    }
+
+    pub fn max_shadow_maps(&self) -> usize {
+        self.settings.layers()
+    }
+
+    pub fn shadow_map(&self) -> &Rc<Texture> {
+        &self.shadow_map
+    }
+
+    pub fn attach(&self, program: &ShaderProgram) -> bool {
+        self.buffer.attach(program, SHADOWS_BLOCK_NAME)
+    }
+
+    pub fn prepare(&mut self, camera: &Camera, lights: &[Light]) -> Vec<Option<usize>> {
+        let cascades = self.settings.cascades();
+        let near = camera.projection().near();
+        let far = camera.projection().far().min(self.settings.max_distance);
+        let splits = cascade_splits(near, far, cascades, self.settings.cascade_split_lambda);
+
+        self.matrices.iter_mut().for_each(|matrix| *matrix = None);
+        let mut sun_casting = false;
+        let mut next_spot = cascades;
+
+        let layers = lights
+            .iter()
+            .map(|light| {
+                if !light.cast_shadows {
+                    return None;
+                }
+                match light.kind {
+                    LightKind::Directional if !sun_casting => {
+                        sun_casting = true;
+                        let mut cascade_near = near;
+                        for (cascade, &cascade_far) in splits.iter().enumerate() {
+                            self.matrices[cascade] = fit_directional_cascade(
+                                camera,
+                                &light.direction,
+                                cascade_near,
+                                cascade_far,
+                                self.settings.map_size,
+                                self.settings.max_distance,
+                            );
+                            cascade_near = cascade_far;
+                        }
+                        Some(0)
+                    }
+                    LightKind::Spot { .. } if next_spot < self.matrices.len() => {
+                        let layer = next_spot;
+                        self.matrices[layer] = spot_shadow_matrix(light);
+                        next_spot += 1;
+                        Some(layer)
+                    }
+                    _ => None,
+                }
+            })
+            .collect();
+
+        self.upload(&splits);
+        layers
+    }
+
+    fn upload(&mut self, splits: &[f32]) {
+        let mut cascade_splits = [0.0; MAX_CASCADES];
+        cascade_splits[..splits.len()].copy_from_slice(splits);
+
+        self.data.clear();
+        self.data.extend_from_slice(&[
+            self.settings.pcf_radius as f32,
+            self.settings.depth_bias,
+            self.settings.normal_bias,
+            self.settings.map_size as f32,
+        ]);
+        self.data.extend_from_slice(&cascade_splits);
+        self.data
+            .extend_from_slice(&[splits.len() as f32, 0.0, 0.0, 0.0]);
+        for matrix in self.matrices.iter() {
+            let matrix = matrix.unwrap_or_else(Mat4x4::identity);
+            self.data.extend_from_slice(matrix.as_slice());
+        }
+        self.buffer.upload(&self.data);
+    }
+
+    pub fn render(&self, casters: &[(Rc<Mesh>, Mat4x4)]) -> Result<(), GLError> {
+        let size = self.settings.map_size;
+        let binded = self.framebuffer.bind();
+        self.gl.viewport(0, 0, size, size);
+
+        let material_use = self.material.bind();
+        let shader_use = material_use.shader_use();
+        self.gl.enable(gl::POLYGON_OFFSET_FILL);
+        self.gl.polygon_offset(self.settings.slope_scale_bias, 1.0);
+
+        for (layer, matrix) in self.matrices.iter().enumerate() {
+            let matrix = match matrix {
+                Some(matrix) => matrix,
+                None => continue,
+            };
+            binded.attach_texture_layer(gl::DEPTH_ATTACHMENT, &self.shadow_map, layer as i32);
+            binded.check_status()?;
+            binded.clear_depth(1.0);
+            material_use.set_uniform(&self.u_light_matrix, matrix);
+
+            let frustum = Frustum::from_matrix(matrix);
+            for (mesh, world) in casters.iter() {
+                if !frustum.intersects_aabb(&mesh.aabb().transform(world)) {
+                    continue;
+                }
+                material_use.set_uniform(&self.u_model, world);
+                mesh.bind(shader_use).draw(shader_use);
+            }
+        }
+
+        self.gl.disable(gl::POLYGON_OFFSET_FILL);
+        binded.detach(gl::DEPTH_ATTACHMENT);
+        Ok(())
+    }
+
+    pub fn bind(&self) {
+        self.buffer.bind();
+    }
+}
@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SkinningError {
+    #[error("Skeleton has {joints} joints, but the palette supports at most {max}")]
+    TooManyJoints { joints: usize, max: usize },
+    #[error("Expected {expected} joint matrices, got {actual}")]
+    JointCountMismatch { expected: usize, actual: usize },
+}
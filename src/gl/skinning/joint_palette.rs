@@ -0,0 +1,120 @@
+use std::rc::Rc;
+
+use glm::Mat4x4;
+
+use crate::gl;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::materials::material::Material;
+use crate::gl::textures::texture::{Texture, TextureFormat};
+
+use super::error::SkinningError;
+use super::shaders::skinned_vertex_shader;
+
+pub const DEFAULT_MAX_UNIFORM_JOINTS: usize = 64;
+
+const JOINT_UNIFORM: &str = "u_joint_matrices";
+const JOINT_TEXTURE_UNIFORM: &str = "u_joint_texture";
+const TEXELS_PER_JOINT: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JointStorage {
+    Uniforms { max_joints: usize },
+    Texture,
+}
+
+pub struct JointPalette {
+    storage: JointStorage,
+    joint_count: usize,
+    texture: Option<Rc<Texture>>,
+}
+
+impl JointPalette {
+    pub fn try_new(
+        gl: &Rc<GL>,
+        storage: JointStorage,
+        joint_count: usize,
+    ) -> Result<Self, GLError> {
+        let texture = match storage {
+            JointStorage::Uniforms { max_joints } => {
+                if joint_count > max_joints {
+                    return Err(SkinningError::TooManyJoints {
+                        joints: joint_count,
+                        max: max_joints,
+                    }
+                    .into());
+                }
+                None
+            }
+            JointStorage::Texture => {
+                let width = (joint_count.max(1) * TEXELS_PER_JOINT) as i32;
+                let texture =
+                    Texture::try_new(gl, gl::TEXTURE_2D, TextureFormat::RGBA32F, width, 1)?;
+                texture.set_filter(gl::NEAREST, gl::NEAREST);
+                texture.upload_f32(
+                    gl::TEXTURE_2D,
+                    0,
+                    width,
+                    1,
+                    &identity_palette(joint_count.max(1)),
+                )?;
+                Some(Rc::new(texture))
+            }
+        };
+
+        Ok(Self {
+            storage,
+            joint_count,
+            texture,
+        })
+    }
+
+    pub fn storage(&self) -> JointStorage {
+        self.storage
+    }
+
+    pub fn joint_count(&self) -> usize {
+        self.joint_count
+    }
+
+    pub fn vertex_shader(&self) -> String {
+        skinned_vertex_shader(self.storage)
+    }
+
+    pub fn attach(&self, material: &mut Material) {
+        match self.texture.as_ref() {
+            Some(texture) => material.set(JOINT_TEXTURE_UNIFORM, Rc::clone(texture)),
+            None => material.set(JOINT_UNIFORM, vec![Mat4x4::identity(); self.joint_count]),
+        }
+    }
+
+    pub fn upload(&self, material: &mut Material, matrices: &[Mat4x4]) -> Result<(), GLError> {
+        if matrices.len() != self.joint_count {
+            return Err(SkinningError::JointCountMismatch {
+                expected: self.joint_count,
+                actual: matrices.len(),
+            }
+            .into());
+        }
+
+        match self.texture.as_ref() {
+            Some(texture) => {
+                let data: Vec<f32> = matrices
+                    .iter()
+                    .flat_map(|matrix| matrix.data.as_slice().iter().copied())
+                    .collect();
+                let width = (self.joint_count * TEXELS_PER_JOINT) as i32;
+                texture.sub_upload_f32(gl::TEXTURE_2D, 0, width, 1, &data)?;
+            }
+            None => material.set(JOINT_UNIFORM, matrices.to_vec()),
+        }
+        Ok(())
+    }
+}
+
+fn identity_palette(joint_count: usize) -> Vec<f32> {
+    let identity = Mat4x4::identity();
+    (0..joint_count)
+        .flat_map(|_| identity.data.as_slice().iter().copied())
+        .collect()
+}
@@ -0,0 +1,3 @@
+pub mod error;
+pub mod joint_palette;
+pub mod shaders;
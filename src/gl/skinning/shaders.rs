@@ -0,0 +1,80 @@
+use super::joint_palette::JointStorage;
+
+const JOINT_UNIFORMS_BLOCK: &str = r##"
+    uniform mat4 u_joint_matrices[MAX_JOINTS];
+
+    mat4 joint_matrix(int index) {
+        return u_joint_matrices[index];
+    }
+"##;
+
+const JOINT_TEXTURE_BLOCK: &str = r##"
+    uniform highp sampler2D u_joint_texture;
+
+    mat4 joint_matrix(int index) {
+        return mat4(
+            texelFetch(u_joint_texture, ivec2(index * 4, 0), 0),
+            texelFetch(u_joint_texture, ivec2(index * 4 + 1, 0), 0),
+            texelFetch(u_joint_texture, ivec2(index * 4 + 2, 0), 0),
+            texelFetch(u_joint_texture, ivec2(index * 4 + 3, 0), 0)
+        );
+    }
+"##;
+
+const SKINNED_VERTEX_MAIN: &str = r##"
+    in vec3 a_position;
+    in vec3 a_normal;
+    in vec2 a_tex_coords;
+    in vec4 a_tangent;
+    in vec4 a_joints;
+    in vec4 a_weights;
+
+    struct camera {
+        mat4 model;
+        mat4 view;
+        mat4 projection;
+    };
+
+    uniform camera u_camera;
+
+    out vec3 v_position;
+    out vec3 v_normal;
+    out vec2 v_tex_coords;
+    out vec4 v_tangent;
+    out vec3 v_eye_position;
+    out float v_view_depth;
+
+    mat4 skin_matrix() {
+        ivec4 joints = ivec4(a_joints + 0.5);
+        return a_weights.x * joint_matrix(joints.x)
+            + a_weights.y * joint_matrix(joints.y)
+            + a_weights.z * joint_matrix(joints.z)
+            + a_weights.w * joint_matrix(joints.w);
+    }
+
+    void main() {
+        mat4 model = u_camera.model * skin_matrix();
+        vec4 world_position = model * vec4(a_position, 1.0);
+
+        v_position = world_position.xyz;
+        v_normal = normalize(transpose(inverse(mat3(model))) * a_normal);
+        v_tex_coords = a_tex_coords;
+        v_tangent = vec4(mat3(model) * a_tangent.xyz, a_tangent.w);
+        v_eye_position = inverse(u_camera.view)[3].xyz;
+        v_view_depth = -(u_camera.view * world_position).z;
+
+        gl_Position = u_camera.projection * u_camera.view * world_position;
+    }
+"##;
+
+pub fn skinned_vertex_shader(storage: JointStorage) -> String {
+    let joints = match storage {
+        JointStorage::Uniforms { max_joints } => format!(
+            "#define MAX_JOINTS {}\n{}",
+            max_joints.max(1),
+            JOINT_UNIFORMS_BLOCK
+        ),
+        JointStorage::Texture => JOINT_TEXTURE_BLOCK.into(),
+    };
+    format!("#version 300 es\n{}{}", joints, SKINNED_VERTEX_MAIN)
+}
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SkyboxError {
+    #[error(
+        "Cube map face {} has {} bytes, expected {} for RGBA8 {}x{}",
+        .face, .actual, .expected, .size, .size
+    )]
+    InvalidFaceSize {
+        face: usize,
+        size: i32,
+        expected: usize,
+        actual: usize,
+    },
+}
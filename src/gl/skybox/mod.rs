@@ -0,0 +1,4 @@
+pub mod error;
+pub mod procedural_sky;
+pub mod shaders;
+pub mod skybox_renderer;
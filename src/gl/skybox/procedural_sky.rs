@@ -0,0 +1,55 @@
+use glm::Vec3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProceduralSky {
+    pub up: Vec3,
+    pub sun_direction: Vec3,
+    pub zenith_color: Vec3,
+    pub horizon_color: Vec3,
+    pub ground_color: Vec3,
+    pub sun_color: Vec3,
+    pub sun_size: f32,
+    pub haze: f32,
+    pub intensity: f32,
+}
+
+impl Default for ProceduralSky {
+    fn default() -> Self {
+        Self {
+            up: Vec3::y(),
+            sun_direction: Vec3::new(0.4, 0.6, 0.7),
+            zenith_color: Vec3::new(0.15, 0.35, 0.9),
+            horizon_color: Vec3::new(0.9, 0.85, 0.8),
+            ground_color: Vec3::new(0.25, 0.22, 0.2),
+            sun_color: Vec3::new(20.0, 18.0, 15.0),
+            sun_size: 0.03,
+            haze: 0.05,
+            intensity: 1.0,
+        }
+    }
+}
+
+impl ProceduralSky {
+    pub fn gradient(zenith_color: Vec3, horizon_color: Vec3, ground_color: Vec3) -> Self {
+        Self {
+            zenith_color,
+            horizon_color,
+            ground_color,
+            sun_color: Vec3::zeros(),
+            haze: 0.0,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_up(self, up: Vec3) -> Self {
+        Self { up, ..self }
+    }
+
+    pub fn with_sun(self, sun_direction: Vec3, sun_color: Vec3) -> Self {
+        Self {
+            sun_direction,
+            sun_color,
+            ..self
+        }
+    }
+}
@@ -0,0 +1,73 @@
+pub const SKYBOX_VERTEX_SHADER: &str = r##"#version 300 es
+    in vec3 a_position;
+
+    struct camera {
+        mat4 model;
+        mat4 view;
+        mat4 projection;
+    };
+
+    uniform camera u_camera;
+
+    out vec3 v_direction;
+
+    void main() {
+        v_direction = a_position;
+        mat4 rotation = mat4(mat3(u_camera.view * u_camera.model));
+        vec4 position = u_camera.projection * rotation * vec4(a_position, 1.0);
+        gl_Position = position.xyww;
+    }
+"##;
+
+pub const CUBEMAP_SKY_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in vec3 v_direction;
+
+    uniform samplerCube u_skybox;
+    uniform float u_intensity;
+
+    out vec4 out_color;
+
+    void main() {
+        vec3 color = textureLod(u_skybox, normalize(v_direction), 0.0).rgb;
+        out_color = vec4(color * u_intensity, 1.0);
+    }
+"##;
+
+pub const PROCEDURAL_SKY_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in vec3 v_direction;
+
+    uniform vec3 u_up;
+    uniform vec3 u_sun_direction;
+    uniform vec3 u_zenith_color;
+    uniform vec3 u_horizon_color;
+    uniform vec3 u_ground_color;
+    uniform vec3 u_sun_color;
+    uniform float u_sun_size;
+    uniform float u_haze;
+    uniform float u_intensity;
+
+    out vec4 out_color;
+
+    void main() {
+        vec3 direction = normalize(v_direction);
+        vec3 sun = normalize(u_sun_direction);
+        float height = dot(direction, normalize(u_up));
+
+        vec3 sky = height >= 0.0
+            ? mix(u_horizon_color, u_zenith_color, sqrt(height))
+            : mix(u_horizon_color, u_ground_color, min(-height * 4.0, 1.0));
+
+        float cos_theta = dot(direction, sun);
+        float rayleigh = 0.75 * (1.0 + cos_theta * cos_theta);
+        float mie = u_haze * pow(max(cos_theta, 0.0), 8.0);
+        float disc = smoothstep(cos(u_sun_size), cos(u_sun_size * 0.5), cos_theta);
+        float above_horizon = smoothstep(-0.02, 0.02, height);
+
+        vec3 color = sky * mix(1.0, rayleigh, 0.3) + u_sun_color * (mie + disc) * above_horizon;
+        out_color = vec4(color * u_intensity, 1.0);
+    }
+"##;
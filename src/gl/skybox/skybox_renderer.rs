@@ -0,0 +1,153 @@
+use std::rc::Rc;
+
+use glm::Mat4x4;
+
+use crate::gl;
+use crate::gl::camera::Camera;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::ibl::baker::EnvironmentBaker;
+use crate::gl::ibl::environment::bake_cubemap;
+use crate::gl::ibl::hdr_image::HdrImage;
+use crate::gl::materials::material::Material;
+use crate::gl::materials::pipeline_state::PipelineState;
+use crate::gl::mesh::Mesh;
+use crate::gl::screen_quad::build_unit_cube;
+use crate::gl::shader::program::ShaderProgram;
+use crate::gl::shader::shader_type::ShaderType;
+use crate::gl::textures::texture::Texture;
+
+use super::error::SkyboxError::InvalidFaceSize;
+use super::procedural_sky::ProceduralSky;
+use super::shaders::{
+    CUBEMAP_SKY_FRAGMENT_SHADER, PROCEDURAL_SKY_FRAGMENT_SHADER, SKYBOX_VERTEX_SHADER,
+};
+
+#[derive(Clone)]
+pub enum SkySource {
+    Cubemap(Rc<Texture>),
+    Procedural(ProceduralSky),
+}
+
+pub struct Skybox {
+    source: SkySource,
+    intensity: f32,
+    cube: Mesh,
+    material: Material,
+    gl: Rc<GL>,
+}
+
+fn sky_state() -> PipelineState {
+    PipelineState {
+        depth_write: false,
+        depth_func: gl::LEQUAL,
+        ..PipelineState::default()
+    }
+}
+
+fn sky_material(gl: &Rc<GL>, source: &SkySource, intensity: f32) -> Result<Material, GLError> {
+    let fragment = match source {
+        SkySource::Cubemap(_) => CUBEMAP_SKY_FRAGMENT_SHADER,
+        SkySource::Procedural(_) => PROCEDURAL_SKY_FRAGMENT_SHADER,
+    };
+    let program = Rc::new(
+        ShaderProgram::builder(gl)
+            .add_source(ShaderType::Vertex, SKYBOX_VERTEX_SHADER.into())
+            .add_source(ShaderType::Fragment, fragment.into())
+            .build()?,
+    );
+    let mut material = Material::new(gl, &program).with_state(sky_state());
+    set_source_params(&mut material, source, intensity);
+    Ok(material)
+}
+
+fn set_source_params(material: &mut Material, source: &SkySource, intensity: f32) {
+    match source {
+        SkySource::Cubemap(cubemap) => {
+            material.set("u_skybox", Rc::clone(cubemap));
+            material.set("u_intensity", intensity);
+        }
+        SkySource::Procedural(sky) => {
+            material.set("u_up", sky.up);
+            material.set("u_sun_direction", sky.sun_direction);
+            material.set("u_zenith_color", sky.zenith_color);
+            material.set("u_horizon_color", sky.horizon_color);
+            material.set("u_ground_color", sky.ground_color);
+            material.set("u_sun_color", sky.sun_color);
+            material.set("u_sun_size", sky.sun_size);
+            material.set("u_haze", sky.haze);
+            material.set("u_intensity", sky.intensity * intensity);
+        }
+    }
+}
+
+impl Skybox {
+    pub fn try_new(gl: &Rc<GL>, source: SkySource) -> Result<Self, GLError> {
+        Ok(Self {
+            material: sky_material(gl, &source, 1.0)?,
+            source,
+            intensity: 1.0,
+            cube: build_unit_cube(gl)?,
+            gl: Rc::clone(gl),
+        })
+    }
+
+    pub fn from_faces(gl: &Rc<GL>, size: i32, faces: [&[u8]; 6]) -> Result<Self, GLError> {
+        let expected = (size.max(0) as usize).pow(2) * 4;
+        if let Some((face, pixels)) = faces
+            .iter()
+            .enumerate()
+            .find(|(_, pixels)| pixels.len() != expected)
+        {
+            return Err(InvalidFaceSize {
+                face,
+                size,
+                expected,
+                actual: pixels.len(),
+            }
+            .into());
+        }
+        let cubemap = Texture::cube_from_rgba8(gl, size, faces)?;
+        Self::try_new(gl, SkySource::Cubemap(Rc::new(cubemap)))
+    }
+
+    pub fn from_equirect(gl: &Rc<GL>, image: &HdrImage, size: i32) -> Result<Self, GLError> {
+        let baker = EnvironmentBaker::try_new(gl)?;
+        let cubemap = bake_cubemap(gl, &baker, image, size)?;
+        Self::try_new(gl, SkySource::Cubemap(Rc::new(cubemap)))
+    }
+
+    pub fn procedural(gl: &Rc<GL>, sky: ProceduralSky) -> Result<Self, GLError> {
+        Self::try_new(gl, SkySource::Procedural(sky))
+    }
+
+    pub fn source(&self) -> &SkySource {
+        &self.source
+    }
+
+    pub fn set_source(&mut self, source: SkySource) -> Result<(), GLError> {
+        let same_kind = std::mem::discriminant(&self.source) == std::mem::discriminant(&source);
+        if same_kind {
+            set_source_params(&mut self.material, &source, self.intensity);
+        } else {
+            self.material = sky_material(&self.gl, &source, self.intensity)?;
+        }
+        self.source = source;
+        Ok(())
+    }
+
+    pub fn intensity(&self) -> f32 {
+        self.intensity
+    }
+
+    pub fn set_intensity(&mut self, intensity: f32) {
+        self.intensity = intensity;
+        set_source_params(&mut self.material, &self.source, intensity);
+    }
+
+    pub fn render(&self, camera: &Camera) {
+        let material_use = self.material.bind_for(camera);
+        let camera = camera.with_model(Mat4x4::identity());
+        self.cube.draw(material_use.shader_use(), &camera);
+    }
+}
@@ -0,0 +1,80 @@
+pub const SSAO_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in vec2 v_uv;
+
+    uniform highp sampler2D u_position;
+    uniform highp sampler2D u_normal;
+    uniform highp sampler2D u_kernel;
+    uniform highp sampler2D u_noise;
+    uniform mat4 u_view;
+    uniform mat4 u_projection;
+    uniform float u_radius;
+    uniform float u_bias;
+    uniform float u_power;
+
+    out vec4 out_occlusion;
+
+    void main() {
+        vec4 position = texture(u_position, v_uv);
+        if (position.w < 0.5) {
+            out_occlusion = vec4(1.0);
+            return;
+        }
+
+        vec3 origin = (u_view * vec4(position.xyz, 1.0)).xyz;
+        vec3 normal = normalize(mat3(u_view) * texture(u_normal, v_uv).xyz);
+
+        ivec2 noise_size = textureSize(u_noise, 0);
+        vec3 rotation = texelFetch(u_noise, ivec2(gl_FragCoord.xy) % noise_size, 0).xyz;
+        vec3 tangent = normalize(rotation - normal * dot(rotation, normal));
+        vec3 bitangent = cross(normal, tangent);
+        mat3 tbn = mat3(tangent, bitangent, normal);
+
+        int kernel_size = textureSize(u_kernel, 0).x;
+        float occlusion = 0.0;
+        for (int i = 0; i < kernel_size; ++i) {
+            vec3 offset = texelFetch(u_kernel, ivec2(i, 0), 0).xyz;
+            vec3 sample_position = origin + tbn * offset * u_radius;
+
+            vec4 clip = u_projection * vec4(sample_position, 1.0);
+            vec2 uv = clip.xy / clip.w * 0.5 + 0.5;
+            vec4 scene = texture(u_position, uv);
+            if (scene.w < 0.5) {
+                continue;
+            }
+
+            float scene_depth = (u_view * vec4(scene.xyz, 1.0)).z;
+            float range = smoothstep(0.0, 1.0, u_radius / max(abs(origin.z - scene_depth), 0.0001));
+            occlusion += (scene_depth >= sample_position.z + u_bias ? 1.0 : 0.0) * range;
+        }
+
+        float visibility = 1.0 - occlusion / float(max(kernel_size, 1));
+        out_occlusion = vec4(vec3(pow(visibility, u_power)), 1.0);
+    }
+"##;
+
+pub const SSAO_BLUR_FRAGMENT_SHADER: &str = r##"#version 300 es
+    precision highp float;
+
+    in vec2 v_uv;
+
+    uniform sampler2D u_input;
+    uniform vec2 u_texel_size;
+    uniform int u_blur_size;
+
+    out vec4 out_occlusion;
+
+    void main() {
+        int half_size = u_blur_size / 2;
+        float result = 0.0;
+        for (int x = 0; x < u_blur_size; ++x) {
+            for (int y = 0; y < u_blur_size; ++y) {
+                vec2 offset = vec2(float(x - half_size), float(y - half_size)) * u_texel_size;
+                result += texture(u_input, v_uv + offset).r;
+            }
+        }
+        float count = float(max(u_blur_size * u_blur_size, 1));
+        out_occlusion = vec4(vec3(result / count), 1.0);
+    }
+"##;
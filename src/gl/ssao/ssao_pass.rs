@@ -0,0 +1,152 @@
+use std::rc::Rc;
+
+use na::Vector3;
+
+use crate::gl;
+use crate::gl::camera::Camera;
+use crate::gl::error::GLError;
+use crate::gl::materials::material::Material;
+use crate::gl::postprocess::post_pass::{PostContext, TEXEL_SIZE_UNIFORM};
+use crate::gl::postprocess::render_target::RenderTarget;
+use crate::gl::textures::texture::{Texture, TextureFormat};
+use crate::math::random::Random;
+use crate::math::ssao::{hemisphere_kernel, rotation_noise};
+
+use super::shaders::{SSAO_BLUR_FRAGMENT_SHADER, SSAO_FRAGMENT_SHADER};
+
+pub const DEFAULT_KERNEL_SIZE: usize = 32;
+pub const DEFAULT_NOISE_SIZE: usize = 4;
+const DEFAULT_SEED: u32 = 0x5a0_5eed;
+
+pub struct SsaoPass {
+    pub radius: f32,
+    pub bias: f32,
+    pub power: f32,
+    pub blur: bool,
+    kernel_size: usize,
+    noise_size: usize,
+    occlusion: RenderTarget,
+    blurred: RenderTarget,
+    material: Material,
+    blur_material: Material,
+}
+
+fn vectors_texture(
+    context: &PostContext,
+    vectors: &[Vector3<f32>],
+    width: usize,
+    height: usize,
+) -> Result<Rc<Texture>, GLError> {
+    let pixels: Vec<f32> = vectors
+        .iter()
+        .flat_map(|vector| [vector.x, vector.y, vector.z, 0.0])
+        .collect();
+    let (width, height) = (width as i32, height as i32);
+    let texture = Texture::try_new(
+        context.gl(),
+        gl::TEXTURE_2D,
+        TextureFormat::RGBA32F,
+        width,
+        height,
+    )?;
+    texture.set_filter(gl::NEAREST, gl::NEAREST);
+    texture.upload_f32(gl::TEXTURE_2D, 0, width, height, &pixels)?;
+    Ok(Rc::new(texture))
+}
+
+impl SsaoPass {
+    pub fn try_new(context: &PostContext, size: (i32, i32)) -> Result<Self, GLError> {
+        let gl = context.gl();
+        let pass = Self {
+            radius: 0.5,
+            bias: 0.025,
+            power: 1.5,
+            blur: true,
+            kernel_size: 0,
+            noise_size: 0,
+            occlusion: RenderTarget::try_new(gl, TextureFormat::R8, size, false)?,
+            blurred: RenderTarget::try_new(gl, TextureFormat::R8, size, false)?,
+            material: context.material(SSAO_FRAGMENT_SHADER)?,
+            blur_material: context.material(SSAO_BLUR_FRAGMENT_SHADER)?,
+        };
+        pass.with_kernel(
+            context,
+            DEFAULT_KERNEL_SIZE,
+            DEFAULT_NOISE_SIZE,
+            DEFAULT_SEED,
+        )
+    }
+
+    pub fn with_kernel(
+        mut self,
+        context: &PostContext,
+        kernel_size: usize,
+        noise_size: usize,
+        seed: u32,
+    ) -> Result<Self, GLError> {
+        self.kernel_size = kernel_size.max(1);
+        self.noise_size = noise_size.max(1);
+
+        let mut random = Random::new(seed);
+        let kernel = hemisphere_kernel(self.kernel_size, &mut random);
+        let noise = rotation_noise(self.noise_size, &mut random);
+        self.material.set(
+            "u_kernel",
+            vectors_texture(context, &kernel, self.kernel_size, 1)?,
+        );
+        self.material.set(
+            "u_noise",
+            vectors_texture(context, &noise, self.noise_size, self.noise_size)?,
+        );
+        Ok(self)
+    }
+
+    pub fn kernel_size(&self) -> usize {
+        self.kernel_size
+    }
+
+    pub fn noise_size(&self) -> usize {
+        self.noise_size
+    }
+
+    pub fn occlusion(&self) -> &Rc<Texture> {
+        if self.blur {
+            self.blurred.color()
+        } else {
+            self.occlusion.color()
+        }
+    }
+
+    pub fn resize(&mut self, width: i32, height: i32) -> Result<(), GLError> {
+        self.occlusion.resize(width, height)?;
+        self.blurred.resize(width, height)
+    }
+
+    pub fn render(
+        &mut self,
+        context: &PostContext,
+        camera: &Camera,
+        position: &Rc<Texture>,
+        normal: &Rc<Texture>,
+    ) {
+        self.material.set("u_position", Rc::clone(position));
+        self.material.set("u_normal", Rc::clone(normal));
+        self.material.set("u_view", camera.view);
+        self.material
+            .set("u_projection", *camera.projection_matrix());
+        self.material.set("u_radius", self.radius);
+        self.material.set("u_bias", self.bias);
+        self.material.set("u_power", self.power);
+        context.draw(&self.material, Some(&self.occlusion));
+
+        if self.blur {
+            let input = self.occlusion.color();
+            PostContext::set_input(&mut self.blur_material, input);
+            self.blur_material
+                .set(TEXEL_SIZE_UNIFORM, PostContext::texel_size(input));
+            self.blur_material
+                .set("u_blur_size", self.noise_size as i32);
+            context.draw(&self.blur_material, Some(&self.blurred));
+        }
+    }
+}
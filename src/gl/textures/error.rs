@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TextureError {
+    #[error("Can't create texture")]
+    CreateTextureError,
+    #[error("Can't upload texture data. Value = {:?}", .0)]
+    UploadError(wasm_bindgen::JsValue),
+}
@@ -0,0 +1,337 @@
+use std::rc::Rc;
+
+use web_sys::WebGlTexture;
+
+use crate::gl;
+use crate::gl::core::instance::GL;
+
+use super::error::TextureError::{self, CreateTextureError, UploadError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextureFormat {
+    pub internal_format: u32,
+    pub format: u32,
+    pub data_type: u32,
+}
+
+impl TextureFormat {
+    pub const RGBA8: Self = Self::new(gl::RGBA8, gl::RGBA, gl::UNSIGNED_BYTE);
+    pub const SRGB8_ALPHA8: Self = Self::new(gl::SRGB8_ALPHA8, gl::RGBA, gl::UNSIGNED_BYTE);
+    pub const RGBA16F: Self = Self::new(gl::RGBA16F, gl::RGBA, gl::HALF_FLOAT);
+    pub const RGBA32F: Self = Self::new(gl::RGBA32F, gl::RGBA, gl::FLOAT);
+    pub const R8: Self = Self::new(gl::R8, gl::RED, gl::UNSIGNED_BYTE);
+    pub const R16F: Self = Self::new(gl::R16F, gl::RED, gl::HALF_FLOAT);
+    pub const RG16F: Self = Self::new(gl::RG16F, gl::RG, gl::HALF_FLOAT);
+    pub const R32UI: Self = Self::new(gl::R32UI, gl::RED_INTEGER, gl::UNSIGNED_INT);
+    pub const DEPTH24: Self =
+        Self::new(gl::DEPTH_COMPONENT24, gl::DEPTH_COMPONENT, gl::UNSIGNED_INT);
+    pub const DEPTH32F: Self = Self::new(gl::DEPTH_COMPONENT32F, gl::DEPTH_COMPONENT, gl::FLOAT);
+
+    pub const fn new(internal_format: u32, format: u32, data_type: u32) -> Self {
+        Self {
+            internal_format,
+            format,
+            data_type,
+        }
+    }
+}
+
+pub struct Texture {
+    texture: WebGlTexture,
+    target: u32,
+    format: TextureFormat,
+    width: i32,
+    height: i32,
+    levels: i32,
+    gl: Rc<GL>,
+}
+
+impl Texture {
+    pub fn try_new(
+        gl: &Rc<GL>,
+        target: u32,
+        format: TextureFormat,
+        width: i32,
+        height: i32,
+    ) -> Result<Self, TextureError> {
+        let texture = gl.create_texture().ok_or(CreateTextureError)?;
+        let result = Self {
+            texture,
+            target,
+            format,
+            width,
+            height,
+            levels: 1,
+            gl: Rc::clone(gl),
+        };
+        result.set_filter(gl::LINEAR, gl::LINEAR);
+        result.set_wrap(gl::CLAMP_TO_EDGE, gl::CLAMP_TO_EDGE);
+        Ok(result)
+    }
+
+    pub fn new_2d(
+        gl: &Rc<GL>,
+        format: TextureFormat,
+        width: i32,
+        height: i32,
+    ) -> Result<Self, TextureError> {
+        let mut texture = Self::try_new(gl, gl::TEXTURE_2D, format, 0, 0)?;
+        texture.resize(width, height)?;
+        Ok(texture)
+    }
+
+    pub fn with_storage(
+        gl: &Rc<GL>,
+        target: u32,
+        format: TextureFormat,
+        size: (i32, i32),
+        levels: i32,
+    ) -> Result<Self, TextureError> {
+        let (width, height) = size;
+        let mut texture = Self::try_new(gl, target, format, width, height)?;
+        texture.levels = levels.max(1);
+        gl.bind_texture(target, Some(&texture.texture));
+        gl.tex_storage_2d(
+            target,
+            texture.levels,
+            format.internal_format,
+            width,
+            height,
+        );
+        gl.bind_texture(target, None);
+        if texture.levels > 1 {
+            texture.set_filter(gl::LINEAR_MIPMAP_LINEAR, gl::LINEAR);
+        }
+        Ok(texture)
+    }
+
+    pub fn with_layers(
+        gl: &Rc<GL>,
+        format: TextureFormat,
+        size: (i32, i32),
+        layers: i32,
+    ) -> Result<Self, TextureError> {
+        let (width, height) = size;
+        let texture = Self::try_new(gl, gl::TEXTURE_2D_ARRAY, format, width, height)?;
+        gl.bind_texture(gl::TEXTURE_2D_ARRAY, Some(&texture.texture));
+        gl.tex_storage_3d(
+            gl::TEXTURE_2D_ARRAY,
+            1,
+            format.internal_format,
+            width,
+            height,
+            layers.max(1),
+        );
+        gl.bind_texture(gl::TEXTURE_2D_ARRAY, None);
+        Ok(texture)
+    }
+
+    pub fn mip_levels_for(size: i32) -> i32 {
+        32 - (size.max(1) as u32).leading_zeros() as i32
+    }
+
+    pub fn from_rgba8(
+        gl: &Rc<GL>,
+        width: i32,
+        height: i32,
+        pixels: &[u8],
+    ) -> Result<Self, TextureError> {
+        let texture = Self::try_new(gl, gl::TEXTURE_2D, TextureFormat::RGBA8, width, height)?;
+        texture.upload_u8(gl::TEXTURE_2D, 0, width, height, Some(pixels))?;
+        Ok(texture)
+    }
+
+    pub fn cube_from_rgba8(
+        gl: &Rc<GL>,
+        size: i32,
+        faces: [&[u8]; 6],
+    ) -> Result<Self, TextureError> {
+        let texture = Self::try_new(gl, gl::TEXTURE_CUBE_MAP, TextureFormat::RGBA8, size, size)?;
+        for (face, pixels) in (0..).zip(faces.iter()) {
+            texture.upload_u8(
+                gl::TEXTURE_CUBE_MAP_POSITIVE_X + face,
+                0,
+                size,
+                size,
+                Some(*pixels),
+            )?;
+        }
+        Ok(texture)
+    }
+
+    pub fn solid_color(gl: &Rc<GL>, rgba: [u8; 4]) -> Result<Self, TextureError> {
+        Self::from_rgba8(gl, 1, 1, &rgba)
+    }
+
+    pub fn as_gl_texture(&self) -> &WebGlTexture {
+        &self.texture
+    }
+
+    pub fn target(&self) -> u32 {
+        self.target
+    }
+
+    pub fn format(&self) -> TextureFormat {
+        self.format
+    }
+
+    pub fn size(&self) -> (i32, i32) {
+        (self.width, self.height)
+    }
+
+    pub fn levels(&self) -> i32 {
+        self.levels
+    }
+
+    pub fn level_size(&self, level: i32) -> (i32, i32) {
+        ((self.width >> level).max(1), (self.height >> level).max(1))
+    }
+
+    pub fn resize(&mut self, width: i32, height: i32) -> Result<(), TextureError> {
+        if (self.width, self.height) == (width, height) {
+            return Ok(());
+        }
+        self.width = width;
+        self.height = height;
+
+        let faces: Vec<u32> = if self.target == gl::TEXTURE_CUBE_MAP {
+            (0..6)
+                .map(|i| gl::TEXTURE_CUBE_MAP_POSITIVE_X + i)
+                .collect()
+        } else {
+            vec![self.target]
+        };
+        for face in faces {
+            self.upload_object(face, 0, width.max(1), height.max(1), None)?;
+        }
+        Ok(())
+    }
+
+    pub fn upload_u8(
+        &self,
+        face: u32,
+        level: i32,
+        width: i32,
+        height: i32,
+        pixels: Option<&[u8]>,
+    ) -> Result<(), TextureError> {
+        let array = pixels.map(js_sys::Uint8Array::from);
+        self.upload_object(face, level, width, height, array.as_deref())
+    }
+
+    pub fn sub_upload_f32(
+        &self,
+        face: u32,
+        level: i32,
+        width: i32,
+        height: i32,
+        pixels: &[f32],
+    ) -> Result<(), TextureError> {
+        let array = js_sys::Float32Array::from(pixels);
+        self.gl.bind_texture(self.target, Some(&self.texture));
+        let result = self
+            .gl
+            .tex_sub_image_2d_with_i32_and_i32_and_u32_and_type_and_opt_array_buffer_view(
+                face,
+                level,
+                0,
+                0,
+                width,
+                height,
+                self.format.format,
+                self.format.data_type,
+                Some(&*array),
+            );
+        self.gl.bind_texture(self.target, None);
+        result.map_err(UploadError)
+    }
+
+    pub fn upload_f32(
+        &self,
+        face: u32,
+        level: i32,
+        width: i32,
+        height: i32,
+        pixels: &[f32],
+    ) -> Result<(), TextureError> {
+        let array = js_sys::Float32Array::from(pixels);
+        self.upload_object(face, level, width, height, Some(&*array))
+    }
+
+    fn upload_object(
+        &self,
+        face: u32,
+        level: i32,
+        width: i32,
+        height: i32,
+        pixels: Option<&js_sys::Object>,
+    ) -> Result<(), TextureError> {
+        self.gl.bind_texture(self.target, Some(&self.texture));
+        let result = self
+            .gl
+            .tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_array_buffer_view(
+                face,
+                level,
+                self.format.internal_format as i32,
+                width,
+                height,
+                0,
+                self.format.format,
+                self.format.data_type,
+                pixels,
+            );
+        self.gl.bind_texture(self.target, None);
+        result.map_err(UploadError)
+    }
+
+    pub fn set_filter(&self, min_filter: u32, mag_filter: u32) {
+        self.set_parameter(gl::TEXTURE_MIN_FILTER, min_filter as i32);
+        self.set_parameter(gl::TEXTURE_MAG_FILTER, mag_filter as i32);
+    }
+
+    pub fn set_wrap(&self, wrap_s: u32, wrap_t: u32) {
+        self.set_parameter(gl::TEXTURE_WRAP_S, wrap_s as i32);
+        self.set_parameter(gl::TEXTURE_WRAP_T, wrap_t as i32);
+        if self.target == gl::TEXTURE_CUBE_MAP {
+            self.set_parameter(gl::TEXTURE_WRAP_R, wrap_s as i32);
+        }
+    }
+
+    pub fn set_parameter(&self, name: u32, value: i32) {
+        self.gl.bind_texture(self.target, Some(&self.texture));
+        self.gl.tex_parameteri(self.target, name, value);
+        self.gl.bind_texture(self.target, None);
+    }
+
+    pub fn set_depth_compare(&self, compare_func: Option<u32>) {
+        match compare_func {
+            Some(func) => {
+                self.set_parameter(gl::TEXTURE_COMPARE_MODE, gl::COMPARE_REF_TO_TEXTURE as i32);
+                self.set_parameter(gl::TEXTURE_COMPARE_FUNC, func as i32);
+            }
+            None => self.set_parameter(gl::TEXTURE_COMPARE_MODE, gl::NONE as i32),
+        }
+    }
+
+    pub fn generate_mipmaps(&self) {
+        self.gl.bind_texture(self.target, Some(&self.texture));
+        self.gl.generate_mipmap(self.target);
+        self.gl.bind_texture(self.target, None);
+    }
+
+    pub fn bind_to_unit(&self, unit: u32) {
+        self.gl.active_texture(gl::TEXTURE0 + unit);
+        self.gl.bind_texture(self.target, Some(&self.texture));
+    }
+
+    pub fn unbind_from_unit(&self, unit: u32) {
+        self.gl.active_texture(gl::TEXTURE0 + unit);
+        self.gl.bind_texture(self.target, None);
+    }
+}
+
+impl Drop for Texture {
+    fn drop(&mut self) {
+        self.gl.delete_texture(Some(&self.texture));
+    }
+}
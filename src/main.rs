@@ -1,10 +1,26 @@
+mod animation;
+mod capture;
 mod gl;
 mod math;
 mod render_loop;
 mod samples;
+mod scene;
 mod utils;
 
+use crate::capture::frame_capture::FrameCapture;
+use crate::gl::camera::web_input::WebInputForwarder;
+use crate::gl::picking::picker::{PickKind, Picker};
 use crate::render_loop::RenderLoop;
+use crate::samples::blend_shapes::BlendShapes;
+use crate::samples::cubes::{Cubes, CAMERA_QUERY_PARAM, FLY_CAMERA, PAN_ZOOM_CAMERA};
+use crate::samples::deferred_lights::{
+    DeferredLights, FORWARD_PATH, RENDER_PATH_QUERY_PARAM, SSAO_OFF, SSAO_QUERY_PARAM,
+};
+use crate::samples::mesh_processing::MeshProcessing;
+use crate::samples::model_viewer::ModelViewer;
+use crate::samples::particles::Particles;
+use crate::samples::sample::Sample;
+use crate::samples::skinning::{Skinning, JOINT_STORAGE_QUERY_PARAM, JOINT_TEXTURE_STORAGE};
 use crate::utils::logging::ExtUnwrapLog;
 use crate::utils::web;
 use render_loop::OnIterResult;
@@ -21,12 +37,27 @@ const FPS_UPDATE_TIME: f64 = 1000.0 / FPS_UPDATE_PER_SECOND;
 const LOGIC_UPDATE_PER_SECOND: f64 = 30.0;
 const LOGIC_UPDATE_TIME: f64 = 1000.0 / LOGIC_UPDATE_PER_SECOND;
 
+const CAPTURE_FILE_NAME: &str = "webgl-sample";
+const CAPTURE_SEQUENCE_FRAMES: usize = 60;
+
+const SAMPLE_QUERY_PARAM: &str = "sample";
+const CUBES_SAMPLE: &str = "cubes";
+const MODEL_VIEWER_SAMPLE: &str = "model-viewer";
+const DEFERRED_LIGHTS_SAMPLE: &str = "deferred-lights";
+const PARTICLES_SAMPLE: &str = "particles";
+const SKINNING_SAMPLE: &str = "skinning";
+const BLEND_SHAPES_SAMPLE: &str = "blend-shapes";
+const MESH_PROCESSING_SAMPLE: &str = "mesh-processing";
 
 #[derive(Debug, Default)]
 pub struct App {
     canvas_ref: NodeRef,
     fps_counter_ref: NodeRef,
     fps_limiter_ref: NodeRef,
+    stats_ref: NodeRef,
+    capture: FrameCapture,
+    picker: Picker,
+    input: WebInputForwarder,
 }
 
 impl Component for App {
@@ -38,90 +69,211 @@ impl Component for App {
     }
 
     fn view(&self, _ctx: &Context<Self>) -> Html {
+        let capture_frame = {
+            let capture = self.capture.clone();
+            Callback::from(move |_: MouseEvent| capture.request_frame(CAPTURE_FILE_NAME))
+        };
+        let capture_sequence = {
+            let capture = self.capture.clone();
+            Callback::from(move |_: MouseEvent| {
+                capture.request_sequence(
+                    CAPTURE_FILE_NAME,
+                    CAPTURE_SEQUENCE_FRAMES,
+                    LOGIC_UPDATE_TIME,
+                )
+            })
+        };
+
+        let mouse_move = {
+            let picker = self.picker.clone();
+            let input = self.input.clone();
+            Callback::from(move |e: MouseEvent| {
+                picker.forward_mouse_event(PickKind::Hover, &e);
+                input.mouse_move(&e);
+            })
+        };
+        let mouse_down = {
+            let picker = self.picker.clone();
+            let input = self.input.clone();
+            Callback::from(move |e: MouseEvent| {
+                picker.press(&e);
+                input.mouse_down(&e);
+            })
+        };
+        let mouse_up = {
+            let input = self.input.clone();
+            Callback::from(move |e: MouseEvent| input.mouse_up(&e))
+        };
+        let wheel = {
+            let input = self.input.clone();
+            Callback::from(move |e: WheelEvent| input.wheel(&e))
+        };
+        let key_down = {
+            let input = self.input.clone();
+            Callback::from(move |e: KeyboardEvent| input.key_down(&e))
+        };
+        let key_up = {
+            let input = self.input.clone();
+            Callback::from(move |e: KeyboardEvent| input.key_up(&e))
+        };
+        let touch = {
+            let input = self.input.clone();
+            Callback::from(move |e: TouchEvent| input.touch(&e))
+        };
+        let double_click = {
+            let input = self.input.clone();
+            Callback::from(move |e: MouseEvent| input.request_pointer_lock(&e))
+        };
+        let context_menu = Callback::from(|e: MouseEvent| e.prevent_default());
+        let pick_click = {
+            let picker = self.picker.clone();
+            Callback::from(move |e: MouseEvent| picker.click(&e))
+        };
+
         html! {
             <div>
-                <canvas ref={self.canvas_ref.clone()} id="main-canvas" />
+                <canvas
+                    ref={self.canvas_ref.clone()}
+                    id="main-canvas"
+                    tabindex="0"
+                    onmousemove={mouse_move}
+                    onmousedown={mouse_down}
+                    onmouseup={mouse_up}
+                    onclick={pick_click}
+                    ondblclick={double_click}
+                    onwheel={wheel}
+                    onkeydown={key_down}
+                    onkeyup={key_up}
+                    ontouchstart={touch.clone()}
+                    ontouchmove={touch.clone()}
+                    ontouchend={touch}
+                    oncontextmenu={context_menu}
+                />
                 <div ref={self.fps_counter_ref.clone()} id="fps-counter"> {"fps:___"} </div>
                 <div id="fps-limiter">
                     <input ref={self.fps_limiter_ref.clone()} type="checkbox"/>
                     {"limit at 30"}
                 </div>
+                <div ref={self.stats_ref.clone()} id="sample-stats"></div>
+                <div id="sample-links">
+                    <a href={format!("?{}={}", SAMPLE_QUERY_PARAM, CUBES_SAMPLE)}>{"cubes"}</a>
+                    <a href={format!("?{}={}&{}={}", SAMPLE_QUERY_PARAM, CUBES_SAMPLE, CAMERA_QUERY_PARAM, FLY_CAMERA)}>{"cubes with fly camera"}</a>
+                    <a href={format!("?{}={}&{}={}", SAMPLE_QUERY_PARAM, CUBES_SAMPLE, CAMERA_QUERY_PARAM, PAN_ZOOM_CAMERA)}>{"cubes with pan-zoom camera"}</a>
+                    <a href={format!("?{}={}", SAMPLE_QUERY_PARAM, MODEL_VIEWER_SAMPLE)}>{"model viewer"}</a>
+                    <a href={format!("?{}={}", SAMPLE_QUERY_PARAM, DEFERRED_LIGHTS_SAMPLE)}>{"deferred lights"}</a>
+                    <a href={format!("?{}={}&{}={}", SAMPLE_QUERY_PARAM, DEFERRED_LIGHTS_SAMPLE, SSAO_QUERY_PARAM, SSAO_OFF)}>{"deferred lights without ssao"}</a>
+                    <a href={format!("?{}={}&{}={}", SAMPLE_QUERY_PARAM, DEFERRED_LIGHTS_SAMPLE, RENDER_PATH_QUERY_PARAM, FORWARD_PATH)}>{"forward lights"}</a>
+                    <a href={format!("?{}={}", SAMPLE_QUERY_PARAM, PARTICLES_SAMPLE)}>{"particles"}</a>
+                    <a href={format!("?{}={}", SAMPLE_QUERY_PARAM, SKINNING_SAMPLE)}>{"skinning"}</a>
+                    <a href={format!("?{}={}&{}={}", SAMPLE_QUERY_PARAM, SKINNING_SAMPLE, JOINT_STORAGE_QUERY_PARAM, JOINT_TEXTURE_STORAGE)}>{"skinning with joint texture"}</a>
+                    <a href={format!("?{}={}", SAMPLE_QUERY_PARAM, BLEND_SHAPES_SAMPLE)}>{"blend shapes"}</a>
+                    <a href={format!("?{}={}", SAMPLE_QUERY_PARAM, MESH_PROCESSING_SAMPLE)}>{"mesh processing"}</a>
+                </div>
+                <div id="capture-controls">
+                    <button onclick={capture_frame}>{"screenshot"}</button>
+                    <button onclick={capture_sequence}>{"record 60 frames"}</button>
+                </div>
             </div>
         }
     }
 
     fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
         if first_render {
-            let canvas = self
-                .canvas_ref
-                .cast::<web_sys::HtmlCanvasElement>()
-                .unwrap();
-
-            let fps_counter_ref = self.fps_counter_ref.clone();
-            let fps_limiter_ref = self.fps_limiter_ref.clone();
-
-            let mut prev_render_time = js_sys::Date::now();
-            let mut prev_fps_update_time = prev_render_time;
-            let mut prev_logic_update_time = prev_render_time;
-            let mut frames_draw = 0;
-
-            RenderLoop::<samples::cubes::Cubes>::create(&canvas)
-                .unwrap_log()
-                .on_iter(move || {
-                    let (width, height) = web::window_size();
-                    canvas.set_width(width);
-                    canvas.set_height(height);
-
-                    let curr_time = js_sys::Date::now();
-                    let time_from_last_logic_update = curr_time - prev_logic_update_time;
-                    let time_from_last_render = curr_time - prev_render_time;
-                    let time_from_last_fps_update = curr_time - prev_fps_update_time;
-
-                    let fps_limit_enabled = fps_limiter_ref
-                        .cast::<HtmlInputElement>()
-                        .unwrap()
-                        .checked();
-
-                    let update_fps = time_from_last_fps_update >= FPS_UPDATE_TIME;
-                    let update_logic = time_from_last_logic_update >= LOGIC_UPDATE_TIME;
-                    let render_frame = !fps_limit_enabled || time_from_last_render >= FRAME_TIME;
-
-                    if update_logic {
-                        let time_overhead = time_from_last_logic_update.rem_euclid(LOGIC_UPDATE_TIME);
-                        prev_logic_update_time = curr_time - time_overhead;
-                    }
-
-                    if update_fps {
-                        let fps = frames_draw as f64 * 1000.0 / time_from_last_fps_update + 0.5;
-                        let text = format!("fps:{:03}", fps as usize);
-
-                        let time_overhead = time_from_last_fps_update.rem_euclid(FPS_UPDATE_TIME);
-                        frames_draw = 0;
-                        prev_fps_update_time = curr_time - time_overhead;
-
-                        fps_counter_ref
-                            .cast::<HtmlElement>()
-                            .unwrap()
-                            .set_inner_text(text.as_str());
-                    }
-
-                    if render_frame {
-                        let time_overhead = time_from_last_render.rem_euclid(FRAME_TIME);
-                        prev_render_time = curr_time - time_overhead;
-                        frames_draw += 1;
-                    }
-
-                    OnIterResult {
-                        render_frame,
-                        update_logic,
-                        stop_execution: false,
-                    }
-                })
-                .run();
+            self.input.listen_pointer_lock().unwrap_log();
+            match web::query_param(SAMPLE_QUERY_PARAM).as_deref() {
+                Some(MODEL_VIEWER_SAMPLE) => self.run_sample::<ModelViewer>(),
+                Some(DEFERRED_LIGHTS_SAMPLE) => self.run_sample::<DeferredLights>(),
+                Some(PARTICLES_SAMPLE) => self.run_sample::<Particles>(),
+                Some(SKINNING_SAMPLE) => self.run_sample::<Skinning>(),
+                Some(BLEND_SHAPES_SAMPLE) => self.run_sample::<BlendShapes>(),
+                Some(MESH_PROCESSING_SAMPLE) => self.run_sample::<MeshProcessing>(),
+                _ => self.run_sample::<Cubes>(),
+            }
         }
     }
 }
 
+impl App {
+    fn run_sample<S: Sample + 'static>(&self) {
+        let canvas = self
+            .canvas_ref
+            .cast::<web_sys::HtmlCanvasElement>()
+            .unwrap();
+
+        let fps_counter_ref = self.fps_counter_ref.clone();
+        let fps_limiter_ref = self.fps_limiter_ref.clone();
+        let stats_ref = self.stats_ref.clone();
+
+        let mut prev_render_time = js_sys::Date::now();
+        let mut prev_fps_update_time = prev_render_time;
+        let mut prev_logic_update_time = prev_render_time;
+        let mut frames_draw = 0;
+
+        RenderLoop::<S>::create(&canvas)
+            .unwrap_log()
+            .with_capture(&self.capture)
+            .with_picking(&self.picker)
+            .unwrap_log()
+            .with_input(self.input.queue())
+            .on_stats(move |stats| {
+                if let Some(element) = stats_ref.cast::<HtmlElement>() {
+                    element.set_inner_text(stats);
+                }
+            })
+            .on_iter(move || {
+                let (width, height) = web::window_size();
+                canvas.set_width(width);
+                canvas.set_height(height);
+
+                let curr_time = js_sys::Date::now();
+                let time_from_last_logic_update = curr_time - prev_logic_update_time;
+                let time_from_last_render = curr_time - prev_render_time;
+                let time_from_last_fps_update = curr_time - prev_fps_update_time;
+
+                let fps_limit_enabled = fps_limiter_ref
+                    .cast::<HtmlInputElement>()
+                    .unwrap()
+                    .checked();
+
+                let update_fps = time_from_last_fps_update >= FPS_UPDATE_TIME;
+                let update_logic = time_from_last_logic_update >= LOGIC_UPDATE_TIME;
+                let render_frame = !fps_limit_enabled || time_from_last_render >= FRAME_TIME;
+
+                if update_logic {
+                    let time_overhead = time_from_last_logic_update.rem_euclid(LOGIC_UPDATE_TIME);
+                    prev_logic_update_time = curr_time - time_overhead;
+                }
+
+                if update_fps {
+                    let fps = frames_draw as f64 * 1000.0 / time_from_last_fps_update + 0.5;
+                    let text = format!("fps:{:03}", fps as usize);
+
+                    let time_overhead = time_from_last_fps_update.rem_euclid(FPS_UPDATE_TIME);
+                    frames_draw = 0;
+                    prev_fps_update_time = curr_time - time_overhead;
+
+                    fps_counter_ref
+                        .cast::<HtmlElement>()
+                        .unwrap()
+                        .set_inner_text(text.as_str());
+                }
+
+                if render_frame {
+                    let time_overhead = time_from_last_render.rem_euclid(FRAME_TIME);
+                    prev_render_time = curr_time - time_overhead;
+                    frames_draw += 1;
+                }
+
+                OnIterResult {
+                    render_frame,
+                    update_logic,
+                    stop_execution: false,
+                }
+            })
+            .run();
+    }
+}
+
 fn main() {
     init_logger(log::LevelFilter::Trace).unwrap();
     yew::Renderer::<App>::new().render();
@@ -0,0 +1,193 @@
+use na::{Matrix3xX, Matrix4, Vector3};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    pub min: Vector3<f32>,
+    pub max: Vector3<f32>,
+}
+
+impl Aabb {
+    pub fn new(min: Vector3<f32>, max: Vector3<f32>) -> Self {
+        Self { min, max }
+    }
+
+    pub fn empty() -> Self {
+        Self {
+            min: Vector3::repeat(f32::INFINITY),
+            max: Vector3::repeat(f32::NEG_INFINITY),
+        }
+    }
+
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a Vector3<f32>>) -> Self {
+        let mut aabb = Self::empty();
+        for point in points {
+            aabb.grow(point);
+        }
+        aabb
+    }
+
+    pub fn from_columns(points: &Matrix3xX<f32>) -> Self {
+        let mut aabb = Self::empty();
+        for point in points.column_iter() {
+            aabb.grow(&point.into_owned());
+        }
+        aabb
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    pub fn grow(&mut self, point: &Vector3<f32>) {
+        self.min = self.min.inf(point);
+        self.max = self.max.sup(point);
+    }
+
+    pub fn center(&self) -> Vector3<f32> {
+        (self.min + self.max) * 0.5
+    }
+
+    pub fn size(&self) -> Vector3<f32> {
+        self.max - self.min
+    }
+
+    pub fn longest_axis(&self) -> usize {
+        self.size().imax()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn contains(&self, point: &Vector3<f32>) -> bool {
+        (0..3).all(|i| self.min[i] <= point[i] && point[i] <= self.max[i])
+    }
+
+    pub fn transform(&self, matrix: &Matrix4<f32>) -> Aabb {
+        if self.is_empty() {
+            return *self;
+        }
+
+        let translation = matrix.fixed_slice::<3, 1>(0, 3).into_owned();
+        let mut result = Aabb::new(translation, translation);
+        for i in 0..3 {
+            for j in 0..3 {
+                let a = matrix[(i, j)] * self.min[j];
+                let b = matrix[(i, j)] * self.max[j];
+                result.min[i] += a.min(b);
+                result.max[i] += a.max(b);
+            }
+        }
+        result
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sphere {
+    pub center: Vector3<f32>,
+    pub radius: f32,
+}
+
+impl Sphere {
+    pub fn new(center: Vector3<f32>, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    pub fn from_columns(points: &Matrix3xX<f32>) -> Self {
+        let aabb = Aabb::from_columns(points);
+        if aabb.is_empty() {
+            return Self::new(Vector3::zeros(), 0.0);
+        }
+
+        let center = aabb.center();
+        let radius = points
+            .column_iter()
+            .map(|point| (point - center).norm())
+            .fold(0.0f32, f32::max);
+        Self::new(center, radius)
+    }
+
+    pub fn transform(&self, matrix: &Matrix4<f32>) -> Sphere {
+        let center = matrix.transform_point(&self.center.into()).coords;
+        let scale = (0..3)
+            .map(|i| matrix.fixed_slice::<3, 1>(0, i).norm())
+            .fold(0.0f32, f32::max);
+        Sphere::new(center, self.radius * scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use na::{Rotation3, Translation3};
+
+    const TOLERANCE: f32 = 1e-5;
+
+    fn unit_cube() -> Aabb {
+        Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0))
+    }
+
+    fn corners(aabb: &Aabb) -> Vec<Vector3<f32>> {
+        (0..8)
+            .map(|corner| {
+                Vector3::from_fn(|axis, _| {
+                    if corner & (1 << axis) == 0 {
+                        aabb.min[axis]
+                    } else {
+                        aabb.max[axis]
+                    }
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn transform_encloses_rotated_corners() {
+        let rotation = Rotation3::from_euler_angles(0.0, 0.0, std::f32::consts::FRAC_PI_4);
+        let matrix = Translation3::new(2.0, -1.0, 3.0).to_homogeneous() * rotation.to_homogeneous();
+        let transformed = unit_cube().transform(&matrix);
+
+        let diagonal = 2.0f32.sqrt();
+        assert!(
+            (transformed.min - Vector3::new(2.0 - diagonal, -1.0 - diagonal, 2.0)).norm()
+                < TOLERANCE
+        );
+        assert!(
+            (transformed.max - Vector3::new(2.0 + diagonal, -1.0 + diagonal, 4.0)).norm()
+                < TOLERANCE
+        );
+
+        let aabb = Aabb::new(Vector3::new(0.5, -2.0, 1.0), Vector3::new(3.0, 1.0, 1.5));
+        let rotation = Rotation3::from_euler_angles(0.3, -1.1, 2.0).to_homogeneous();
+        let expected = Aabb::from_points(
+            corners(&aabb)
+                .iter()
+                .map(|corner| rotation.transform_point(&(*corner).into()).coords)
+                .collect::<Vec<_>>()
+                .iter(),
+        );
+        let transformed = aabb.transform(&rotation);
+        assert!((transformed.min - expected.min).norm() < TOLERANCE);
+        assert!((transformed.max - expected.max).norm() < TOLERANCE);
+    }
+
+    #[test]
+    fn empty_aabb_stays_empty() {
+        let empty = Aabb::from_points(std::iter::empty());
+        assert!(empty.is_empty());
+        assert!(empty.transform(&Matrix4::new_scaling(2.0)).is_empty());
+        assert!(!unit_cube().is_empty());
+        assert_eq!(Sphere::from_columns(&Matrix3xX::zeros(0)).radius, 0.0);
+    }
+
+    #[test]
+    fn sphere_bounds_points_and_scales() {
+        let points = Matrix3xX::from_columns(&corners(&unit_cube()));
+        let sphere = Sphere::from_columns(&points);
+        assert!(sphere.center.norm() < TOLERANCE);
+        assert!((sphere.radius - 3.0f32.sqrt()).abs() < TOLERANCE);
+
+        let matrix = Translation3::new(1.0, 0.0, 0.0).to_homogeneous()
+            * Matrix4::new_nonuniform_scaling(&Vector3::new(1.0, 3.0, 2.0));
+        let transformed = sphere.transform(&matrix);
+        assert!((transformed.center - Vector3::new(1.0, 0.0, 0.0)).norm() < TOLERANCE);
+        assert!((transformed.radius - 3.0 * 3.0f32.sqrt()).abs() < TOLERANCE);
+    }
+}
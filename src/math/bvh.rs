@@ -0,0 +1,257 @@
+use na::{Matrix3xX, Vector3};
+
+use super::bounds::Aabb;
+use super::ray::Ray;
+
+const MAX_LEAF_TRIANGLES: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    pub face: usize,
+    pub distance: f32,
+    pub barycentric: Vector3<f32>,
+}
+
+#[derive(Debug, Clone)]
+enum BvhNodeKind {
+    Leaf { start: usize, count: usize },
+    Inner { left: usize, right: usize },
+}
+
+#[derive(Debug, Clone)]
+struct BvhNode {
+    bounds: Aabb,
+    kind: BvhNodeKind,
+}
+
+#[derive(Debug, Clone)]
+struct Triangle {
+    face: usize,
+    vertices: [Vector3<f32>; 3],
+    centroid: Vector3<f32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Bvh {
+    nodes: Vec<BvhNode>,
+    triangles: Vec<Triangle>,
+}
+
+impl Bvh {
+    pub fn new(vertices: &Matrix3xX<f32>, faces: &Matrix3xX<i32>) -> Self {
+        let mut triangles: Vec<Triangle> = faces
+            .column_iter()
+            .enumerate()
+            .map(|(face, indices)| {
+                let vertices = [0, 1, 2].map(|i| vertices.column(indices[i] as usize).into_owned());
+                let centroid = (vertices[0] + vertices[1] + vertices[2]) / 3.0;
+                Triangle {
+                    face,
+                    vertices,
+                    centroid,
+                }
+            })
+            .collect();
+
+        let mut nodes = vec![];
+        if !triangles.is_empty() {
+            let count = triangles.len();
+            build_node(&mut nodes, &mut triangles, 0, count);
+        }
+
+        Self { nodes, triangles }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn bounds(&self) -> Aabb {
+        self.nodes.first().map_or_else(Aabb::empty, |n| n.bounds)
+    }
+
+    pub fn nearest_hit(&self, ray: &Ray) -> Option<RayHit> {
+        let mut best: Option<RayHit> = None;
+        let mut stack = Vec::with_capacity(64);
+        if let Some(root) = self.nodes.first() {
+            if let Some((t_min, _)) = ray.intersect_aabb(&root.bounds) {
+                stack.push((0, t_min));
+            }
+        }
+
+        while let Some((index, t_enter)) = stack.pop() {
+            if best.is_some_and(|hit| hit.distance < t_enter) {
+                continue;
+            }
+
+            match self.nodes[index].kind {
+                BvhNodeKind::Leaf { start, count } => {
+                    for triangle in &self.triangles[start..start + count] {
+                        let [a, b, c] = &triangle.vertices;
+                        if let Some(hit) = ray.intersect_triangle(a, b, c) {
+                            if best.is_none_or(|best| hit.distance < best.distance) {
+                                best = Some(RayHit {
+                                    face: triangle.face,
+                                    distance: hit.distance,
+                                    barycentric: hit.barycentric,
+                                });
+                            }
+                        }
+                    }
+                }
+                BvhNodeKind::Inner { left, right } => {
+                    let left_hit = ray.intersect_aabb(&self.nodes[left].bounds);
+                    let right_hit = ray.intersect_aabb(&self.nodes[right].bounds);
+                    let mut children = [(left, left_hit), (right, right_hit)];
+                    children.sort_by(|a, b| {
+                        let a = a.1.map_or(f32::INFINITY, |t| t.0);
+                        let b = b.1.map_or(f32::INFINITY, |t| t.0);
+                        b.total_cmp(&a)
+                    });
+                    for (child, hit) in children.iter() {
+                        if let Some((t_min, _)) = hit {
+                            stack.push((*child, *t_min));
+                        }
+                    }
+                }
+            }
+        }
+
+        best
+    }
+}
+
+fn build_node(
+    nodes: &mut Vec<BvhNode>,
+    triangles: &mut [Triangle],
+    offset: usize,
+    count: usize,
+) -> usize {
+    let slice = &mut triangles[offset..offset + count];
+    let bounds = slice
+        .iter()
+        .flat_map(|t| t.vertices.iter())
+        .fold(Aabb::empty(), |mut aabb, v| {
+            aabb.grow(v);
+            aabb
+        });
+
+    let index = nodes.len();
+    nodes.push(BvhNode {
+        bounds,
+        kind: BvhNodeKind::Leaf {
+            start: offset,
+            count,
+        },
+    });
+
+    if count <= MAX_LEAF_TRIANGLES {
+        return index;
+    }
+
+    let centroid_bounds = Aabb::from_points(slice.iter().map(|t| &t.centroid));
+    let axis = centroid_bounds.longest_axis();
+    if centroid_bounds.size()[axis] <= 0.0 {
+        return index;
+    }
+
+    let mid = count / 2;
+    slice.select_nth_unstable_by(mid, |a, b| {
+        a.centroid[axis]
+            .total_cmp(&b.centroid[axis])
+            .then(a.face.cmp(&b.face))
+    });
+
+    let left = build_node(nodes, triangles, offset, mid);
+    let right = build_node(nodes, triangles, offset + mid, count - mid);
+    nodes[index].kind = BvhNodeKind::Inner { left, right };
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::random::Random;
+
+    const TRIANGLES: usize = 300;
+    const RAYS: usize = 500;
+
+    fn random_point(random: &mut Random, extent: f32) -> Vector3<f32> {
+        Vector3::new(
+            random.range(-extent, extent),
+            random.range(-extent, extent),
+            random.range(-extent, extent),
+        )
+    }
+
+    fn triangle_soup(random: &mut Random) -> (Matrix3xX<f32>, Matrix3xX<i32>) {
+        let mut vertices = Matrix3xX::zeros(TRIANGLES * 3);
+        for triangle in 0..TRIANGLES {
+            let center = random_point(random, 5.0);
+            for corner in 0..3 {
+                let vertex = center + random_point(random, 0.5);
+                vertices.set_column(triangle * 3 + corner, &vertex);
+            }
+        }
+        let faces = Matrix3xX::from_fn(TRIANGLES, |row, column| (column * 3 + row) as i32);
+        (vertices, faces)
+    }
+
+    fn brute_force(vertices: &Matrix3xX<f32>, faces: &Matrix3xX<i32>, ray: &Ray) -> Option<RayHit> {
+        faces
+            .column_iter()
+            .enumerate()
+            .filter_map(|(face, indices)| {
+                let [a, b, c] =
+                    [0, 1, 2].map(|i| vertices.column(indices[i] as usize).into_owned());
+                ray.intersect_triangle(&a, &b, &c).map(|hit| RayHit {
+                    face,
+                    distance: hit.distance,
+                    barycentric: hit.barycentric,
+                })
+            })
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))
+    }
+
+    #[test]
+    fn nearest_hit_matches_brute_force() {
+        let mut random = Random::new(17);
+        let (vertices, faces) = triangle_soup(&mut random);
+        let bvh = Bvh::new(&vertices, &faces);
+
+        let mut hits = 0;
+        for _ in 0..RAYS {
+            let origin = random_point(&mut random, 8.0);
+            let target = random_point(&mut random, 4.0);
+            let ray = Ray::new(origin, target - origin);
+
+            let expected = brute_force(&vertices, &faces, &ray);
+            let actual = bvh.nearest_hit(&ray);
+            match (expected, actual) {
+                (Some(expected), Some(actual)) => {
+                    hits += 1;
+                    assert!((expected.distance - actual.distance).abs() < 1e-5);
+                    assert_eq!(expected.face, actual.face);
+                }
+                (None, None) => {}
+                (expected, actual) => panic!("expected {:?}, got {:?}", expected, actual),
+            }
+        }
+        assert!(hits > RAYS / 10);
+    }
+
+    #[test]
+    fn bounds_cover_all_vertices() {
+        let mut random = Random::new(3);
+        let (vertices, faces) = triangle_soup(&mut random);
+        let bounds = Bvh::new(&vertices, &faces).bounds();
+        assert!(vertices
+            .column_iter()
+            .all(|vertex| bounds.contains(&vertex.into_owned())));
+    }
+
+    #[test]
+    fn empty_mesh_has_no_hits() {
+        let bvh = Bvh::new(&Matrix3xX::zeros(0), &Matrix3xX::zeros(0));
+        assert!(bvh.bounds().is_empty());
+        let ray = Ray::new(Vector3::zeros(), Vector3::new(0.0, 0.0, -1.0));
+        assert!(bvh.nearest_hit(&ray).is_none());
+    }
+}
@@ -2,30 +2,426 @@ use std::ops::AddAssign;
 
 use na::{Matrix3xX, Vector3};
 
+const DEGENERATE_EPSILON: f32 = 1e-12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NormalWeighting {
+    #[default]
+    Uniform,
+    Area,
+    Angle,
+}
+
+pub struct SmoothedGeometry {
+    pub normals: Matrix3xX<f32>,
+    pub faces: Matrix3xX<i32>,
+    pub source_vertices: Vec<usize>,
+}
+
 pub fn compute_normals(vertices: &Matrix3xX<f32>, faces: &Matrix3xX<i32>) -> Matrix3xX<f32> {
+    compute_weighted_normals(vertices, faces, NormalWeighting::Uniform)
+}
+
+pub fn compute_weighted_normals(
+    vertices: &Matrix3xX<f32>,
+    faces: &Matrix3xX<i32>,
+    weighting: NormalWeighting,
+) -> Matrix3xX<f32> {
     let mut normals_data: Matrix3xX<f32> = Matrix3xX::zeros(vertices.ncols());
 
     for face in faces.column_iter() {
-        let v0 = face[0] as usize;
-        let v1 = face[1] as usize;
-        let v2 = face[2] as usize;
+        let corners = face_corners(&face.into_owned());
+        let (normal, area) = face_normal(vertices, &corners);
+
+        for (i, &corner) in corners.iter().enumerate() {
+            let weight = corner_weight(vertices, &corners, i, area, weighting);
+            normals_data
+                .column_mut(corner)
+                .add_assign(&(normal * weight));
+        }
+    }
 
-        let a = vertices.column(v0);
-        let b = vertices.column(v1);
-        let c = vertices.column(v2);
+    normalize_columns(&mut normals_data);
+    normals_data
+}
 
-        let ab = b - a;
-        let ac = c - a;
-        let normal: Vector3<f32> = ab.cross(&ac).normalize();
+pub fn auto_smooth(
+    vertices: &Matrix3xX<f32>,
+    faces: &Matrix3xX<i32>,
+    crease_angle: f32,
+    weighting: NormalWeighting,
+) -> SmoothedGeometry {
+    let min_cos = crease_angle.cos();
+    let face_data: Vec<([usize; 3], Vector3<f32>, f32)> = faces
+        .column_iter()
+        .map(|face| {
+            let corners = face_corners(&face.into_owned());
+            let (normal, area) = face_normal(vertices, &corners);
+            (corners, normal, area)
+        })
+        .collect();
 
-        normals_data.column_mut(v0).add_assign(&normal);
-        normals_data.column_mut(v1).add_assign(&normal);
-        normals_data.column_mut(v2).add_assign(&normal);
+    let mut incident_faces = vec![vec![]; vertices.ncols()];
+    for (face_index, (corners, _, _)) in face_data.iter().enumerate() {
+        for &corner in corners {
+            incident_faces[corner].push(face_index);
+        }
     }
 
-    for mut column in normals_data.column_iter_mut() {
-        column.copy_from(&column.normalize())
+    let mut source_vertices: Vec<usize> = (0..vertices.ncols()).collect();
+    let mut normals_sum = vec![Vector3::zeros(); vertices.ncols()];
+    let mut smoothed_faces = faces.clone();
+
+    for (vertex, incident) in incident_faces.iter().enumerate() {
+        let mut groups: Vec<usize> = (0..incident.len()).collect();
+        for (i, &face_a) in incident.iter().enumerate() {
+            let (corners_a, normal_a, area_a) = &face_data[face_a];
+            if is_degenerate(*area_a) {
+                continue;
+            }
+            for (j, &face_b) in incident.iter().enumerate().skip(i + 1) {
+                let (corners_b, normal_b, area_b) = &face_data[face_b];
+                if !is_degenerate(*area_b)
+                    && shares_edge(corners_a, corners_b, vertex)
+                    && is_smooth(normal_a, normal_b, min_cos)
+                {
+                    union(&mut groups, i, j);
+                }
+            }
+        }
+
+        let mut group_vertices: Vec<(usize, usize)> = vec![];
+        for (i, &face_index) in incident.iter().enumerate() {
+            let (corners, normal, area) = &face_data[face_index];
+            let corner = corners.iter().position(|&c| c == vertex).unwrap_or(0);
+            if is_degenerate(*area) {
+                smoothed_faces[(corner, face_index)] = vertex as i32;
+                continue;
+            }
+
+            let root = find(&mut groups, i);
+            let target = match group_vertices.iter().find(|(group, _)| *group == root) {
+                Some(&(_, target)) => target,
+                None => {
+                    let target = if group_vertices.is_empty() {
+                        vertex
+                    } else {
+                        source_vertices.push(vertex);
+                        normals_sum.push(Vector3::zeros());
+                        source_vertices.len() - 1
+                    };
+                    group_vertices.push((root, target));
+                    target
+                }
+            };
+
+            let weight = corner_weight(vertices, corners, corner, *area, weighting);
+            normals_sum[target] += normal * weight;
+            smoothed_faces[(corner, face_index)] = target as i32;
+        }
     }
 
-    normals_data
+    let mut normals = Matrix3xX::from_fn(normals_sum.len(), |row, column| normals_sum[column][row]);
+    normalize_columns(&mut normals);
+
+    SmoothedGeometry {
+        normals,
+        faces: smoothed_faces,
+        source_vertices,
+    }
+}
+
+fn face_corners(face: &Vector3<i32>) -> [usize; 3] {
+    [face[0] as usize, face[1] as usize, face[2] as usize]
+}
+
+fn face_normal(vertices: &Matrix3xX<f32>, corners: &[usize; 3]) -> (Vector3<f32>, f32) {
+    let a = vertices.column(corners[0]);
+    let b = vertices.column(corners[1]);
+    let c = vertices.column(corners[2]);
+
+    let cross: Vector3<f32> = (b - a).cross(&(c - a));
+    let length = cross.norm();
+    if length * length < DEGENERATE_EPSILON {
+        (Vector3::zeros(), 0.0)
+    } else {
+        (cross / length, length * 0.5)
+    }
+}
+
+fn corner_weight(
+    vertices: &Matrix3xX<f32>,
+    corners: &[usize; 3],
+    corner: usize,
+    area: f32,
+    weighting: NormalWeighting,
+) -> f32 {
+    match weighting {
+        NormalWeighting::Uniform => 1.0,
+        NormalWeighting::Area => area,
+        NormalWeighting::Angle => {
+            let origin = vertices.column(corners[corner]);
+            let to_next = vertices.column(corners[(corner + 1) % 3]) - origin;
+            let to_prev = vertices.column(corners[(corner + 2) % 3]) - origin;
+            let lengths = to_next.norm() * to_prev.norm();
+            if lengths * lengths < DEGENERATE_EPSILON {
+                0.0
+            } else {
+                (to_next.dot(&to_prev) / lengths).clamp(-1.0, 1.0).acos()
+            }
+        }
+    }
+}
+
+fn normalize_columns(normals: &mut Matrix3xX<f32>) {
+    for mut column in normals.column_iter_mut() {
+        if column.norm_squared() > DEGENERATE_EPSILON {
+            column.copy_from(&column.normalize())
+        } else {
+            column.fill(0.0)
+        }
+    }
+}
+
+fn shares_edge(a: &[usize; 3], b: &[usize; 3], vertex: usize) -> bool {
+    a.iter()
+        .filter(|&&corner| corner != vertex)
+        .any(|corner| b.contains(corner))
+}
+
+fn is_degenerate(area: f32) -> bool {
+    area <= 0.0
+}
+
+fn is_smooth(a: &Vector3<f32>, b: &Vector3<f32>, min_cos: f32) -> bool {
+    a.dot(b) >= min_cos
+}
+
+fn find(groups: &mut [usize], index: usize) -> usize {
+    let mut root = index;
+    while groups[root] != root {
+        root = groups[root];
+    }
+    let mut current = index;
+    while groups[current] != root {
+        let next = groups[current];
+        groups[current] = root;
+        current = next;
+    }
+    root
+}
+
+fn union(groups: &mut [usize], a: usize, b: usize) {
+    let root_a = find(groups, a);
+    let root_b = find(groups, b);
+    if root_a != root_b {
+        groups[root_b] = root_a;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
+
+    const TOLERANCE: f32 = 1e-5;
+    const CREASE_ANGLE: f32 = PI / 6.0;
+
+    fn mesh(vertices: &[[f32; 3]], faces: &[[i32; 3]]) -> (Matrix3xX<f32>, Matrix3xX<i32>) {
+        (
+            Matrix3xX::from_iterator(vertices.len(), vertices.iter().flatten().copied()),
+            Matrix3xX::from_iterator(faces.len(), faces.iter().flatten().copied()),
+        )
+    }
+
+    fn cube() -> (Matrix3xX<f32>, Matrix3xX<i32>) {
+        mesh(
+            &[
+                [-1.0, -1.0, -1.0],
+                [1.0, -1.0, -1.0],
+                [1.0, 1.0, -1.0],
+                [-1.0, 1.0, -1.0],
+                [-1.0, -1.0, 1.0],
+                [1.0, -1.0, 1.0],
+                [1.0, 1.0, 1.0],
+                [-1.0, 1.0, 1.0],
+            ],
+            &[
+                [0, 2, 1],
+                [0, 3, 2],
+                [4, 5, 6],
+                [4, 6, 7],
+                [0, 1, 5],
+                [0, 5, 4],
+                [3, 6, 2],
+                [3, 7, 6],
+                [0, 4, 7],
+                [0, 7, 3],
+                [1, 2, 6],
+                [1, 6, 5],
+            ],
+        )
+    }
+
+    fn shared_uv_sphere(segments: usize, rings: usize) -> (Matrix3xX<f32>, Matrix3xX<i32>) {
+        let mut vertices = vec![[0.0, 1.0, 0.0]];
+        for ring in 1..rings {
+            let (sin_theta, cos_theta) = (ring as f32 / rings as f32 * PI).sin_cos();
+            for segment in 0..segments {
+                let (sin_phi, cos_phi) = (segment as f32 / segments as f32 * TAU).sin_cos();
+                vertices.push([sin_theta * cos_phi, cos_theta, -sin_theta * sin_phi]);
+            }
+        }
+        vertices.push([0.0, -1.0, 0.0]);
+
+        let bottom = (vertices.len() - 1) as i32;
+        let at =
+            |ring: usize, segment: usize| (1 + (ring - 1) * segments + segment % segments) as i32;
+        let mut faces = vec![];
+        for segment in 0..segments {
+            faces.push([0, at(1, segment), at(1, segment + 1)]);
+            faces.push([bottom, at(rings - 1, segment + 1), at(rings - 1, segment)]);
+        }
+        for ring in 1..rings - 1 {
+            for segment in 0..segments {
+                let (a, b) = (at(ring, segment), at(ring, segment + 1));
+                let (c, d) = (at(ring + 1, segment), at(ring + 1, segment + 1));
+                faces.push([a, c, d]);
+                faces.push([a, d, b]);
+            }
+        }
+        mesh(&vertices, &faces)
+    }
+
+    fn assert_consistent(
+        vertices: &Matrix3xX<f32>,
+        faces: &Matrix3xX<i32>,
+        smoothed: &SmoothedGeometry,
+    ) {
+        assert_eq!(smoothed.normals.ncols(), smoothed.source_vertices.len());
+        assert_eq!(smoothed.faces.ncols(), faces.ncols());
+        assert!(smoothed
+            .source_vertices
+            .iter()
+            .enumerate()
+            .all(|(index, &source)| source < vertices.ncols() && source <= index));
+        for (smoothed_face, face) in smoothed.faces.column_iter().zip(faces.column_iter()) {
+            for corner in 0..3 {
+                let source = smoothed.source_vertices[smoothed_face[corner] as usize];
+                assert_eq!(source as i32, face[corner]);
+            }
+        }
+    }
+
+    #[test]
+    fn degenerate_faces_produce_no_nans() {
+        let (vertices, faces) = mesh(
+            &[
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [2.0, 0.0, 0.0],
+                [5.0, 5.0, 5.0],
+            ],
+            &[[0, 1, 2], [0, 1, 3], [0, 1, 4], [5, 5, 5]],
+        );
+        for &weighting in [
+            NormalWeighting::Uniform,
+            NormalWeighting::Area,
+            NormalWeighting::Angle,
+        ]
+        .iter()
+        {
+            let normals = compute_weighted_normals(&vertices, &faces, weighting);
+            assert!(normals.iter().all(|value| value.is_finite()));
+            assert!((normals.column(0) - Vector3::z()).norm() < TOLERANCE);
+            assert_eq!(normals.column(4), Vector3::zeros());
+            assert_eq!(normals.column(5), Vector3::zeros());
+
+            let smoothed = auto_smooth(&vertices, &faces, CREASE_ANGLE, weighting);
+            assert!(smoothed.normals.iter().all(|value| value.is_finite()));
+            assert_consistent(&vertices, &faces, &smoothed);
+        }
+    }
+
+    #[test]
+    fn area_and_angle_weighting_differ_on_asymmetric_fan() {
+        let (vertices, faces) = mesh(
+            &[
+                [0.0, 0.0, 0.0],
+                [3.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 1.0, 1.0],
+            ],
+            &[[0, 1, 2], [0, 2, 3]],
+        );
+        let center = |weighting| {
+            compute_weighted_normals(&vertices, &faces, weighting)
+                .column(0)
+                .into_owned()
+        };
+
+        let uniform = Vector3::new(1.0, 0.0, 1.0).normalize();
+        let area = Vector3::new(0.5, 0.0, 1.5).normalize();
+        let angle = Vector3::new(FRAC_PI_4, 0.0, FRAC_PI_2).normalize();
+        assert!((center(NormalWeighting::Uniform) - uniform).norm() < TOLERANCE);
+        assert!((center(NormalWeighting::Area) - area).norm() < TOLERANCE);
+        assert!((center(NormalWeighting::Angle) - angle).norm() < TOLERANCE);
+        assert!((area - angle).norm() > 0.1);
+    }
+
+    #[test]
+    fn auto_smooth_splits_cube_corners() {
+        let (vertices, faces) = cube();
+        let smoothed = auto_smooth(&vertices, &faces, CREASE_ANGLE, NormalWeighting::Area);
+        assert_eq!(smoothed.source_vertices.len(), 24);
+        assert_consistent(&vertices, &faces, &smoothed);
+
+        for face in smoothed.faces.column_iter() {
+            let corners = face_corners(&face.into_owned());
+            let split_vertices = vertices.select_columns(&smoothed.source_vertices);
+            let (normal, _) = face_normal(&split_vertices, &corners);
+            for &corner in corners.iter() {
+                assert!((smoothed.normals.column(corner) - normal).norm() < TOLERANCE);
+            }
+        }
+    }
+
+    #[test]
+    fn auto_smooth_keeps_uv_sphere_unsplit() {
+        let (vertices, faces) = shared_uv_sphere(32, 16);
+        for &weighting in [NormalWeighting::Area, NormalWeighting::Angle].iter() {
+            let smoothed = auto_smooth(&vertices, &faces, CREASE_ANGLE, weighting);
+            assert_eq!(smoothed.source_vertices.len(), vertices.ncols());
+            assert_eq!(smoothed.faces, faces);
+            assert_consistent(&vertices, &faces, &smoothed);
+            for (normal, position) in smoothed.normals.column_iter().zip(vertices.column_iter()) {
+                assert!(normal.dot(&position) > 0.99);
+            }
+        }
+    }
+
+    #[test]
+    fn degenerate_faces_do_not_bridge_creases() {
+        let (vertices, faces) = mesh(
+            &[
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+                [0.0, 0.0, 0.0],
+            ],
+            &[[0, 1, 2], [1, 0, 3], [0, 2, 4], [0, 4, 3]],
+        );
+        let smoothed = auto_smooth(&vertices, &faces, CREASE_ANGLE, NormalWeighting::Uniform);
+        assert_consistent(&vertices, &faces, &smoothed);
+
+        let first = smoothed.faces[(0, 0)] as usize;
+        let second = smoothed.faces[(1, 1)] as usize;
+        assert_ne!(first, second);
+        assert!((smoothed.normals.column(first) - Vector3::z()).norm() < TOLERANCE);
+        assert!((smoothed.normals.column(second) - Vector3::y()).norm() < TOLERANCE);
+        assert!(smoothed.normals.iter().all(|value| value.is_finite()));
+    }
 }
@@ -0,0 +1,266 @@
+use std::ops::AddAssign;
+
+use na::{Matrix2xX, Matrix3xX, Matrix4xX, Vector2, Vector3, Vector4};
+
+const DEGENERATE_EPSILON: f32 = 1e-12;
+
+struct FaceData {
+    corners: [usize; 3],
+    positions: [Vector3<f32>; 3],
+    tangent: Option<(Vector3<f32>, f32)>,
+}
+
+pub struct TangentGeometry {
+    pub tangents: Matrix4xX<f32>,
+    pub faces: Matrix3xX<i32>,
+    pub source_vertices: Vec<usize>,
+}
+
+pub fn compute_tangents(
+    vertices: &Matrix3xX<f32>,
+    normals: &Matrix3xX<f32>,
+    tex_coords: &Matrix2xX<f32>,
+    faces: &Matrix3xX<i32>,
+) -> TangentGeometry {
+    let vertices_count = vertices.ncols();
+    let face_data: Vec<FaceData> = faces
+        .column_iter()
+        .map(|face| {
+            let corners = [face[0] as usize, face[1] as usize, face[2] as usize];
+            let positions = corners.map(|corner| vertices.column(corner).into_owned());
+            let uvs = corners.map(|corner| tex_coords.column(corner).into_owned());
+            FaceData {
+                corners,
+                positions,
+                tangent: face_tangent(&positions, &uvs),
+            }
+        })
+        .collect();
+
+    let mut orientations = vec![(false, false); vertices_count];
+    for face in face_data.iter() {
+        if let Some((_, orientation)) = face.tangent {
+            for &corner in face.corners.iter() {
+                if orientation < 0.0 {
+                    orientations[corner].1 = true;
+                } else {
+                    orientations[corner].0 = true;
+                }
+            }
+        }
+    }
+
+    let mut source_vertices: Vec<usize> = (0..vertices_count).collect();
+    let mut mirrored_vertices = Vec::with_capacity(vertices_count);
+    for (vertex, &(positive, negative)) in orientations.iter().enumerate() {
+        if positive && negative {
+            source_vertices.push(vertex);
+            mirrored_vertices.push(source_vertices.len() - 1);
+        } else {
+            mirrored_vertices.push(vertex);
+        }
+    }
+
+    let mut split_faces = faces.clone();
+    let mut tangents_sum: Matrix3xX<f32> = Matrix3xX::zeros(source_vertices.len());
+    let mut handedness = vec![1.0f32; source_vertices.len()];
+
+    for (face_index, face) in face_data.iter().enumerate() {
+        let (face_tangent, orientation) = match face.tangent {
+            Some(tangent) => tangent,
+            None => continue,
+        };
+
+        for (i, &corner) in face.corners.iter().enumerate() {
+            let target = if orientation < 0.0 {
+                mirrored_vertices[corner]
+            } else {
+                corner
+            };
+            split_faces[(i, face_index)] = target as i32;
+            handedness[target] = orientation;
+
+            let normal = normals.column(corner).into_owned();
+            let tangent = project_on_plane(&face_tangent, &normal);
+            if tangent.norm_squared() < DEGENERATE_EPSILON {
+                continue;
+            }
+
+            let weight = corner_angle(&face.positions, &normal, i);
+            tangents_sum
+                .column_mut(target)
+                .add_assign(&(tangent.normalize() * weight));
+        }
+    }
+
+    let mut tangents = Matrix4xX::zeros(source_vertices.len());
+    for (target, &source) in source_vertices.iter().enumerate() {
+        let normal = normals.column(source).into_owned();
+        let accumulated = project_on_plane(&tangents_sum.column(target).into_owned(), &normal);
+        let tangent = if accumulated.norm_squared() < DEGENERATE_EPSILON {
+            any_orthogonal(&normal)
+        } else {
+            accumulated.normalize()
+        };
+        tangents.column_mut(target).copy_from(&Vector4::new(
+            tangent.x,
+            tangent.y,
+            tangent.z,
+            handedness[target],
+        ));
+    }
+
+    TangentGeometry {
+        tangents,
+        faces: split_faces,
+        source_vertices,
+    }
+}
+
+fn face_tangent(
+    positions: &[Vector3<f32>; 3],
+    uvs: &[Vector2<f32>; 3],
+) -> Option<(Vector3<f32>, f32)> {
+    let edge1 = positions[1] - positions[0];
+    let edge2 = positions[2] - positions[0];
+    let delta1 = uvs[1] - uvs[0];
+    let delta2 = uvs[2] - uvs[0];
+
+    let signed_area = delta1.x * delta2.y - delta1.y * delta2.x;
+    let orientation = if signed_area > 0.0 { 1.0 } else { -1.0 };
+    let tangent = (edge1 * delta2.y - edge2 * delta1.y) * orientation;
+
+    if signed_area.abs() < DEGENERATE_EPSILON || tangent.norm_squared() < DEGENERATE_EPSILON {
+        return None;
+    }
+    Some((tangent.normalize(), orientation))
+}
+
+fn project_on_plane(vector: &Vector3<f32>, normal: &Vector3<f32>) -> Vector3<f32> {
+    vector - normal * normal.dot(vector)
+}
+
+fn corner_angle(positions: &[Vector3<f32>; 3], normal: &Vector3<f32>, corner: usize) -> f32 {
+    let origin = positions[corner];
+    let to_next = project_on_plane(&(positions[(corner + 1) % 3] - origin), normal);
+    let to_prev = project_on_plane(&(positions[(corner + 2) % 3] - origin), normal);
+
+    if to_next.norm_squared() < DEGENERATE_EPSILON || to_prev.norm_squared() < DEGENERATE_EPSILON {
+        return 0.0;
+    }
+    to_next
+        .normalize()
+        .dot(&to_prev.normalize())
+        .clamp(-1.0, 1.0)
+        .acos()
+}
+
+fn any_orthogonal(normal: &Vector3<f32>) -> Vector3<f32> {
+    let axis = if normal.x.abs() < 0.9 {
+        Vector3::x()
+    } else {
+        Vector3::y()
+    };
+    let tangent = project_on_plane(&axis, normal);
+    if tangent.norm_squared() < DEGENERATE_EPSILON {
+        Vector3::x()
+    } else {
+        tangent.normalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f32 = 1e-5;
+
+    fn quad(tex_coords: [[f32; 2]; 4]) -> TangentGeometry {
+        let vertices = Matrix3xX::from_column_slice(&[
+            0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0,
+        ]);
+        let normals = Matrix3xX::from_fn(4, |row, _| if row == 2 { 1.0 } else { 0.0 });
+        let tex_coords = Matrix2xX::from_iterator(4, tex_coords.iter().flatten().copied());
+        let faces = Matrix3xX::from_column_slice(&[0, 1, 2, 0, 2, 3]);
+        compute_tangents(&vertices, &normals, &tex_coords, &faces)
+    }
+
+    fn assert_tangents(geometry: &TangentGeometry, expected: Vector4<f32>) {
+        for tangent in geometry.tangents.column_iter() {
+            assert!(
+                (tangent - expected).norm() < TOLERANCE,
+                "{:?} != {:?}",
+                tangent,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn planar_quad_tangent_follows_u() {
+        let geometry = quad([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+        assert_eq!(geometry.source_vertices, [0, 1, 2, 3]);
+        assert_tangents(&geometry, Vector4::new(1.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn mirrored_uvs_flip_handedness() {
+        let geometry = quad([[1.0, 0.0], [0.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+        assert_eq!(geometry.source_vertices, [0, 1, 2, 3]);
+        assert_tangents(&geometry, Vector4::new(-1.0, 0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn conflicting_handedness_splits_shared_vertices() {
+        let geometry = quad([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [2.0, 1.0]]);
+        assert_eq!(geometry.source_vertices, [0, 1, 2, 3, 0, 2]);
+        assert_eq!(geometry.faces.column(0).as_slice(), [0, 1, 2]);
+        assert_eq!(geometry.faces.column(1).as_slice(), [4, 5, 3]);
+        for (target, expected) in [
+            (0, 1.0),
+            (1, 1.0),
+            (2, 1.0),
+            (3, -1.0),
+            (4, -1.0),
+            (5, -1.0),
+        ] {
+            assert_eq!(geometry.tangents[(3, target)], expected);
+        }
+    }
+
+    #[test]
+    fn seamed_cube_tangents_are_orthogonal_to_normals() {
+        let mut positions = vec![];
+        let mut normals = vec![];
+        let mut tex_coords = vec![];
+        let mut faces = vec![];
+        for axis in 0..3 {
+            for &sign in [1.0f32, -1.0].iter() {
+                let normal = Vector3::ith(axis, sign);
+                let u = Vector3::ith((axis + 1) % 3, 1.0);
+                let v = normal.cross(&u);
+                let base = positions.len() as i32;
+                for &(s, t) in [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].iter() {
+                    positions.push(normal * 0.5 + u * (s - 0.5) + v * (t - 0.5));
+                    normals.push(normal);
+                    tex_coords.push(Vector2::new(s, t));
+                }
+                faces.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+            }
+        }
+
+        let geometry = compute_tangents(
+            &Matrix3xX::from_columns(&positions),
+            &Matrix3xX::from_columns(&normals),
+            &Matrix2xX::from_columns(&tex_coords),
+            &Matrix3xX::from_column_slice(&faces),
+        );
+        assert_eq!(geometry.source_vertices.len(), positions.len());
+        for (tangent, normal) in geometry.tangents.column_iter().zip(normals.iter()) {
+            let direction = tangent.fixed_rows::<3>(0);
+            assert!(direction.dot(normal).abs() < TOLERANCE);
+            assert!((direction.norm() - 1.0).abs() < TOLERANCE);
+            assert_eq!(tangent[3], 1.0);
+        }
+    }
+}
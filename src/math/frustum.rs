@@ -0,0 +1,169 @@
+use na::{Matrix4, Vector3, Vector4};
+
+use super::bounds::{Aabb, Sphere};
+
+const EPSILON: f32 = 1e-7;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane {
+    pub normal: Vector3<f32>,
+    pub distance: f32,
+}
+
+impl Plane {
+    pub fn from_coefficients(coefficients: &Vector4<f32>) -> Self {
+        let normal = coefficients.xyz();
+        let length = normal.norm();
+        if length < EPSILON {
+            return Self {
+                normal: Vector3::zeros(),
+                distance: f32::INFINITY,
+            };
+        }
+        Self {
+            normal: normal / length,
+            distance: coefficients.w / length,
+        }
+    }
+
+    pub fn signed_distance(&self, point: &Vector3<f32>) -> f32 {
+        self.normal.dot(point) + self.distance
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum {
+    pub planes: [Plane; 6],
+}
+
+impl Frustum {
+    pub fn from_matrix(view_projection: &Matrix4<f32>) -> Self {
+        let row = |i: usize| view_projection.row(i).transpose();
+        let (x, y, z, w) = (row(0), row(1), row(2), row(3));
+        Self {
+            planes: [
+                Plane::from_coefficients(&(w + x)),
+                Plane::from_coefficients(&(w - x)),
+                Plane::from_coefficients(&(w + y)),
+                Plane::from_coefficients(&(w - y)),
+                Plane::from_coefficients(&(w + z)),
+                Plane::from_coefficients(&(w - z)),
+            ],
+        }
+    }
+
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        self.planes
+            .iter()
+            .all(|p| p.signed_distance(&sphere.center) >= -sphere.radius)
+    }
+
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        if aabb.is_empty() {
+            return false;
+        }
+        self.planes.iter().all(|plane| {
+            let positive = Vector3::from_fn(|i, _| {
+                if plane.normal[i] >= 0.0 {
+                    aabb.max[i]
+                } else {
+                    aabb.min[i]
+                }
+            });
+            plane.signed_distance(&positive) >= 0.0
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use na::{Perspective3, Point3};
+
+    const TOLERANCE: f32 = 1e-4;
+    const ASPECT: f32 = 2.0;
+    const FOV_Y: f32 = std::f32::consts::FRAC_PI_2;
+    const NEAR: f32 = 0.5;
+    const FAR: f32 = 50.0;
+
+    fn eye() -> Vector3<f32> {
+        Vector3::new(0.0, 0.0, 5.0)
+    }
+
+    fn frustum() -> Frustum {
+        let projection = Perspective3::new(ASPECT, FOV_Y, NEAR, FAR).to_homogeneous();
+        let view = Matrix4::look_at_rh(&Point3::from(eye()), &Point3::origin(), &Vector3::y_axis());
+        Frustum::from_matrix(&(projection * view))
+    }
+
+    fn view_point(x: f32, y: f32, depth: f32) -> Vector3<f32> {
+        eye() + Vector3::new(x, y, -depth)
+    }
+
+    #[test]
+    fn extracts_inward_planes_from_perspective_view() {
+        let frustum = frustum();
+        for plane in frustum.planes.iter() {
+            assert!((plane.normal.norm() - 1.0).abs() < TOLERANCE);
+            assert!(plane.signed_distance(&view_point(0.0, 0.0, 10.0)) > 0.0);
+        }
+
+        let [left, right, bottom, top, near, far] = frustum.planes;
+        assert!((near.normal - Vector3::new(0.0, 0.0, -1.0)).norm() < TOLERANCE);
+        assert!((far.normal - Vector3::new(0.0, 0.0, 1.0)).norm() < TOLERANCE);
+        assert!(left.normal.x > 0.0 && right.normal.x < 0.0);
+        assert!(bottom.normal.y > 0.0 && top.normal.y < 0.0);
+
+        let depth = 10.0;
+        let half_height = depth * (FOV_Y * 0.5).tan();
+        let half_width = half_height * ASPECT;
+        let on_planes = [
+            (left, view_point(-half_width, 0.0, depth)),
+            (right, view_point(half_width, 0.0, depth)),
+            (bottom, view_point(0.0, -half_height, depth)),
+            (top, view_point(0.0, half_height, depth)),
+            (near, view_point(0.0, 0.0, NEAR)),
+            (far, view_point(0.0, 0.0, FAR)),
+        ];
+        for (plane, point) in on_planes.iter() {
+            assert!(plane.signed_distance(point).abs() < 1e-3, "{:?}", plane);
+        }
+    }
+
+    #[test]
+    fn classifies_spheres() {
+        let frustum = frustum();
+        let inside = Sphere::new(view_point(1.0, 1.0, 10.0), 0.5);
+        let behind = Sphere::new(view_point(0.0, 0.0, -3.0), 1.0);
+        let beyond_far = Sphere::new(view_point(0.0, 0.0, FAR + 2.0), 1.0);
+        let left_of_view = Sphere::new(view_point(-30.0, 0.0, 10.0), 1.0);
+        let crossing_left = Sphere::new(view_point(-20.0, 0.0, 10.0), 1.0);
+        let crossing_near = Sphere::new(view_point(0.0, 0.0, 0.0), 1.0);
+
+        assert!(frustum.intersects_sphere(&inside));
+        assert!(!frustum.intersects_sphere(&behind));
+        assert!(!frustum.intersects_sphere(&beyond_far));
+        assert!(!frustum.intersects_sphere(&left_of_view));
+        assert!(frustum.intersects_sphere(&crossing_left));
+        assert!(frustum.intersects_sphere(&crossing_near));
+    }
+
+    #[test]
+    fn classifies_aabbs() {
+        let frustum = frustum();
+        let around = |center: Vector3<f32>, half: f32| {
+            Aabb::new(
+                center - Vector3::repeat(half),
+                center + Vector3::repeat(half),
+            )
+        };
+
+        assert!(frustum.intersects_aabb(&around(view_point(0.0, 0.0, 10.0), 1.0)));
+        assert!(!frustum.intersects_aabb(&around(view_point(0.0, 0.0, -3.0), 1.0)));
+        assert!(!frustum.intersects_aabb(&around(view_point(0.0, 30.0, 10.0), 1.0)));
+        assert!(frustum.intersects_aabb(&around(view_point(0.0, 10.0, 10.0), 1.0)));
+        assert!(frustum.intersects_aabb(&around(view_point(0.0, 0.0, FAR), 1.0)));
+        assert!(frustum.intersects_aabb(&around(eye(), 100.0)));
+        assert!(!frustum.intersects_aabb(&Aabb::empty()));
+    }
+}
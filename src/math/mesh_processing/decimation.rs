@@ -0,0 +1,462 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use na::{Matrix3xX, Matrix4, Vector3, Vector4};
+
+use super::laplacian::position;
+
+const DEGENERATE_EPSILON: f64 = 1e-12;
+const MIN_NORMAL_COS: f64 = 0.2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Collapse {
+    cost: f64,
+    from: usize,
+    to: usize,
+}
+
+impl Eq for Collapse {}
+
+impl Ord for Collapse {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cost
+            .total_cmp(&other.cost)
+            .then(self.from.cmp(&other.from))
+            .then(self.to.cmp(&other.to))
+    }
+}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+struct Decimator {
+    positions: Vec<Vector3<f64>>,
+    quadrics: Vec<Matrix4<f64>>,
+    locked: Vec<bool>,
+    faces: Vec<[usize; 3]>,
+    alive: Vec<bool>,
+    vertex_faces: Vec<Vec<usize>>,
+    faces_count: usize,
+}
+
+pub fn decimate(
+    vertices: &Matrix3xX<f32>,
+    faces: &Matrix3xX<i32>,
+    target_faces: usize,
+) -> Matrix3xX<i32> {
+    let mut decimator = Decimator::new(vertices, faces);
+    decimator.run(target_faces);
+    decimator.faces()
+}
+
+impl Decimator {
+    fn new(vertices: &Matrix3xX<f32>, faces: &Matrix3xX<i32>) -> Self {
+        let vertices_count = vertices.ncols();
+        let positions: Vec<Vector3<f64>> = (0..vertices_count)
+            .map(|index| position(vertices, index))
+            .collect();
+        let faces: Vec<[usize; 3]> = faces
+            .column_iter()
+            .map(|face| [face[0] as usize, face[1] as usize, face[2] as usize])
+            .collect();
+
+        let mut vertex_faces = vec![vec![]; vertices_count];
+        let mut quadrics = vec![Matrix4::zeros(); vertices_count];
+        for (index, face) in faces.iter().enumerate() {
+            let quadric = face_quadric(&positions, face);
+            for &corner in face {
+                vertex_faces[corner].push(index);
+                quadrics[corner] += quadric;
+            }
+        }
+
+        Self {
+            locked: locked_vertices(vertices, &faces),
+            alive: vec![true; faces.len()],
+            faces_count: faces.len(),
+            positions,
+            quadrics,
+            faces,
+            vertex_faces,
+        }
+    }
+
+    fn run(&mut self, target_faces: usize) {
+        let mut versions = vec![0usize; self.positions.len()];
+        let mut heap = BinaryHeap::new();
+        for vertex in 0..self.positions.len() {
+            self.push_candidates(&mut heap, vertex, &versions);
+        }
+
+        while self.faces_count > target_faces {
+            let Reverse((collapse, from_version, to_version)) = match heap.pop() {
+                Some(entry) => entry,
+                None => break,
+            };
+            if versions[collapse.from] != from_version || versions[collapse.to] != to_version {
+                continue;
+            }
+            if !self.can_collapse(collapse.from, collapse.to) {
+                continue;
+            }
+
+            self.collapse(collapse.from, collapse.to);
+            versions[collapse.from] += 1;
+            versions[collapse.to] += 1;
+            for neighbor in self.neighbors(collapse.to) {
+                versions[neighbor] += 1;
+            }
+            for vertex in std::iter::once(collapse.to).chain(self.neighbors(collapse.to)) {
+                self.push_candidates(&mut heap, vertex, &versions);
+            }
+        }
+    }
+
+    fn faces(&self) -> Matrix3xX<i32> {
+        let faces: Vec<&[usize; 3]> = self
+            .faces
+            .iter()
+            .zip(self.alive.iter())
+            .filter(|(_, alive)| **alive)
+            .map(|(face, _)| face)
+            .collect();
+        Matrix3xX::from_fn(faces.len(), |row, column| faces[column][row] as i32)
+    }
+
+    fn neighbors(&self, vertex: usize) -> Vec<usize> {
+        let mut neighbors: Vec<usize> = self.vertex_faces[vertex]
+            .iter()
+            .flat_map(|&face| self.faces[face])
+            .filter(|&corner| corner != vertex)
+            .collect();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        neighbors
+    }
+
+    fn push_candidates(
+        &self,
+        heap: &mut BinaryHeap<Reverse<(Collapse, usize, usize)>>,
+        vertex: usize,
+        versions: &[usize],
+    ) {
+        for neighbor in self.neighbors(vertex) {
+            for (from, to) in [(vertex, neighbor), (neighbor, vertex)] {
+                if self.locked[from] {
+                    continue;
+                }
+                let collapse = Collapse {
+                    cost: quadric_error(
+                        &(self.quadrics[from] + self.quadrics[to]),
+                        &self.positions[to],
+                    ),
+                    from,
+                    to,
+                };
+                heap.push(Reverse((collapse, versions[from], versions[to])));
+            }
+        }
+    }
+
+    fn can_collapse(&self, from: usize, to: usize) -> bool {
+        let from_neighbors = self.neighbors(from);
+        let to_neighbors: HashSet<usize> = self.neighbors(to).into_iter().collect();
+        let shared_faces: Vec<usize> = self.vertex_faces[from]
+            .iter()
+            .copied()
+            .filter(|&face| self.faces[face].contains(&to))
+            .collect();
+        if shared_faces.is_empty() {
+            return false;
+        }
+
+        let common_neighbors = from_neighbors
+            .iter()
+            .filter(|&&vertex| to_neighbors.contains(&vertex))
+            .count();
+        if common_neighbors != shared_faces.len() {
+            return false;
+        }
+
+        self.vertex_faces[from]
+            .iter()
+            .filter(|&&face| !shared_faces.contains(&face))
+            .all(|&face| {
+                let corners = self.faces[face];
+                let before = face_normal(&self.positions, &corners);
+                let after = face_normal(
+                    &self.positions,
+                    &corners.map(|c| if c == from { to } else { c }),
+                );
+                match (before, after) {
+                    (Some(before), Some(after)) => before.dot(&after) > MIN_NORMAL_COS,
+                    _ => false,
+                }
+            })
+    }
+
+    fn collapse(&mut self, from: usize, to: usize) {
+        let from_faces = std::mem::take(&mut self.vertex_faces[from]);
+        for face in from_faces {
+            if self.faces[face].contains(&to) {
+                self.alive[face] = false;
+                self.faces_count -= 1;
+                for corner in self.faces[face] {
+                    self.vertex_faces[corner].retain(|&other| other != face);
+                }
+            } else {
+                for corner in self.faces[face].iter_mut() {
+                    if *corner == from {
+                        *corner = to;
+                    }
+                }
+                self.vertex_faces[to].push(face);
+            }
+        }
+
+        let quadric = self.quadrics[from];
+        self.quadrics[to] += quadric;
+    }
+}
+
+fn locked_vertices(vertices: &Matrix3xX<f32>, faces: &[[usize; 3]]) -> Vec<bool> {
+    let mut locked = vec![false; vertices.ncols()];
+
+    let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+    for face in faces {
+        for i in 0..3 {
+            let (a, b) = (face[i], face[(i + 1) % 3]);
+            *edges.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+        }
+    }
+    for (&(a, b), &count) in &edges {
+        if count != 2 {
+            locked[a] = true;
+            locked[b] = true;
+        }
+    }
+
+    let mut first_at_position: HashMap<[u32; 3], usize> = HashMap::new();
+    for (index, vertex) in vertices.column_iter().enumerate() {
+        let key = [
+            vertex[0].to_bits(),
+            vertex[1].to_bits(),
+            vertex[2].to_bits(),
+        ];
+        match first_at_position.get(&key) {
+            Some(&first) => {
+                locked[first] = true;
+                locked[index] = true;
+            }
+            None => {
+                first_at_position.insert(key, index);
+            }
+        }
+    }
+    locked
+}
+
+fn face_normal(positions: &[Vector3<f64>], face: &[usize; 3]) -> Option<Vector3<f64>> {
+    let [a, b, c] = face.map(|corner| positions[corner]);
+    let cross = (b - a).cross(&(c - a));
+    let length = cross.norm();
+    if length < DEGENERATE_EPSILON {
+        None
+    } else {
+        Some(cross / length)
+    }
+}
+
+fn face_quadric(positions: &[Vector3<f64>], face: &[usize; 3]) -> Matrix4<f64> {
+    let a = positions[face[0]];
+    let cross = (positions[face[1]] - a).cross(&(positions[face[2]] - a));
+    let length = cross.norm();
+    if length < DEGENERATE_EPSILON {
+        return Matrix4::zeros();
+    }
+
+    let normal = cross / length;
+    let plane = Vector4::new(normal.x, normal.y, normal.z, -normal.dot(&a));
+    plane * plane.transpose() * (length * 0.5)
+}
+
+fn quadric_error(quadric: &Matrix4<f64>, point: &Vector3<f64>) -> f64 {
+    let point = point.push(1.0);
+    (point.transpose() * quadric * point)[(0, 0)].max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::mesh_processing::laplacian::grid;
+
+    const ICOSAHEDRON_FACES: [i32; 60] = [
+        0, 11, 5, 0, 5, 1, 0, 1, 7, 0, 7, 10, 0, 10, 11, 1, 5, 9, 5, 11, 4, 11, 10, 2, 10, 7, 6, 7,
+        1, 8, 3, 9, 4, 3, 4, 2, 3, 2, 6, 3, 6, 8, 3, 8, 9, 4, 9, 5, 2, 4, 11, 6, 2, 10, 8, 6, 7, 9,
+        8, 1,
+    ];
+
+    fn icosphere(subdivisions: usize) -> (Matrix3xX<f32>, Matrix3xX<i32>) {
+        let t = (1.0 + 5.0f32.sqrt()) / 2.0;
+        let mut positions: Vec<Vector3<f32>> = [
+            [-1.0, t, 0.0],
+            [1.0, t, 0.0],
+            [-1.0, -t, 0.0],
+            [1.0, -t, 0.0],
+            [0.0, -1.0, t],
+            [0.0, 1.0, t],
+            [0.0, -1.0, -t],
+            [0.0, 1.0, -t],
+            [t, 0.0, -1.0],
+            [t, 0.0, 1.0],
+            [-t, 0.0, -1.0],
+            [-t, 0.0, 1.0],
+        ]
+        .iter()
+        .map(|&[x, y, z]| Vector3::new(x, y, z).normalize())
+        .collect();
+        let mut faces = ICOSAHEDRON_FACES.to_vec();
+
+        for _ in 0..subdivisions {
+            let mut midpoints: HashMap<(i32, i32), i32> = HashMap::new();
+            let mut midpoint = |a: i32, b: i32| {
+                *midpoints.entry((a.min(b), a.max(b))).or_insert_with(|| {
+                    let middle = positions[a as usize] + positions[b as usize];
+                    positions.push(middle.normalize());
+                    positions.len() as i32 - 1
+                })
+            };
+            faces = faces
+                .chunks(3)
+                .flat_map(|face| {
+                    let (a, b, c) = (face[0], face[1], face[2]);
+                    let (ab, bc, ca) = (midpoint(a, b), midpoint(b, c), midpoint(c, a));
+                    [a, ab, ca, b, bc, ab, c, ca, bc, ab, bc, ca]
+                })
+                .collect();
+        }
+        (
+            Matrix3xX::from_columns(&positions),
+            Matrix3xX::from_vec(faces),
+        )
+    }
+
+    fn seamed_grid() -> (Matrix3xX<f32>, Matrix3xX<i32>, Vec<usize>) {
+        let (grid_vertices, mut faces) = grid(8, 8, 2.0, |x, y| 0.2 * (2.0 * x).sin() * y.cos());
+        let original = grid_vertices.ncols();
+        let seam: Vec<usize> = (0..original).filter(|index| index % 9 == 4).collect();
+        let vertices = Matrix3xX::from_fn(original + seam.len(), |axis, index| {
+            let source = if index < original {
+                index
+            } else {
+                seam[index - original]
+            };
+            grid_vertices[(axis, source)]
+        });
+
+        for mut face in faces.column_iter_mut() {
+            if face.iter().all(|&corner| corner % 9 >= 4) {
+                for corner in face.iter_mut() {
+                    if *corner % 9 == 4 {
+                        *corner = (original + *corner as usize / 9) as i32;
+                    }
+                }
+            }
+        }
+
+        let locked = (0..vertices.ncols())
+            .filter(|&index| {
+                let (column, row) = (index % 9, index / 9);
+                index >= original || [0, 4, 8].contains(&column) || row == 0 || row == 8
+            })
+            .collect();
+        (vertices, faces, locked)
+    }
+
+    fn directed_edges(faces: &Matrix3xX<i32>) -> HashMap<(i32, i32), usize> {
+        let mut edges = HashMap::new();
+        for face in faces.column_iter() {
+            for i in 0..3 {
+                *edges.entry((face[i], face[(i + 1) % 3])).or_insert(0) += 1;
+            }
+        }
+        edges
+    }
+
+    fn boundary_edges(faces: &Matrix3xX<i32>) -> HashSet<(i32, i32)> {
+        let edges = directed_edges(faces);
+        edges
+            .keys()
+            .filter(|(a, b)| !edges.contains_key(&(*b, *a)))
+            .copied()
+            .collect()
+    }
+
+    fn face_cross(vertices: &Matrix3xX<f32>, face: &[i32]) -> Vector3<f32> {
+        let [a, b, c] = [face[0], face[1], face[2]].map(|corner| vertices.column(corner as usize));
+        (b - a).cross(&(c - a))
+    }
+
+    #[test]
+    fn reaches_target_on_subdivided_sphere() {
+        let (vertices, faces) = icosphere(3);
+        assert_eq!(faces.ncols(), 1280);
+        for &target in [1280, 640, 320, 80, 40].iter() {
+            assert_eq!(decimate(&vertices, &faces, target).ncols(), target);
+        }
+        assert_eq!(decimate(&vertices, &faces, 2000), faces);
+    }
+
+    #[test]
+    fn is_deterministic() {
+        let (vertices, faces) = icosphere(3);
+        let first = decimate(&vertices, &faces, 200);
+        for _ in 0..3 {
+            assert_eq!(decimate(&vertices, &faces, 200), first);
+        }
+    }
+
+    #[test]
+    fn keeps_sphere_manifold_and_outward() {
+        let (vertices, faces) = icosphere(3);
+        let decimated = decimate(&vertices, &faces, 80);
+
+        let edges = directed_edges(&decimated);
+        for (&(a, b), &count) in edges.iter() {
+            assert_eq!(count, 1, "edge {:?} is shared by {} faces", (a, b), count);
+            assert_eq!(edges.get(&(b, a)), Some(&1), "edge {:?} is open", (a, b));
+        }
+
+        let mut used: Vec<i32> = decimated.iter().copied().collect();
+        used.sort_unstable();
+        used.dedup();
+        assert_eq!(used.len() + decimated.ncols() - edges.len() / 2, 2);
+
+        for face in decimated.column_iter() {
+            let cross = face_cross(&vertices, face.as_slice());
+            assert!(cross.norm() > 1e-4, "degenerate face {:?}", face);
+            let center = vertices.column(face[0] as usize);
+            assert!(cross.dot(&center) > 0.0, "flipped face {:?}", face);
+        }
+    }
+
+    #[test]
+    fn keeps_boundary_and_seam_vertices() {
+        let (vertices, faces, locked) = seamed_grid();
+        let decimated = decimate(&vertices, &faces, 0);
+        assert!(decimated.ncols() < faces.ncols());
+
+        let used: HashSet<i32> = decimated.iter().copied().collect();
+        for &vertex in locked.iter() {
+            assert!(used.contains(&(vertex as i32)), "vertex {} removed", vertex);
+        }
+        assert_eq!(boundary_edges(&decimated), boundary_edges(&faces));
+        for face in decimated.column_iter() {
+            let cross = face_cross(&vertices, face.as_slice());
+            assert!(cross.z > 1e-4, "flipped or degenerate face {:?}", face);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum MeshProcessingError {
+    #[error("Sparse system matrix is not positive definite")]
+    NotPositiveDefinite,
+    #[error("Vertex {vertex} is out of range for a mesh with {count} vertices")]
+    InvalidVertex { vertex: usize, count: usize },
+    #[error("Mesh has no boundary loop")]
+    NoBoundary,
+}
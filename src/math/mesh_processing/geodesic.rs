@@ -0,0 +1,181 @@
+use na::{DMatrix, Matrix3xX, Vector3};
+use na_sparse::factorization::CscCholesky;
+
+use super::error::MeshProcessingError;
+use super::laplacian::{cotangent, factor, position, triangle_area, Laplacian};
+
+const POISSON_REGULARIZATION: f64 = 1e-6;
+const DEGENERATE_EPSILON: f64 = 1e-12;
+
+struct FaceFrame {
+    corners: [usize; 3],
+    positions: [Vector3<f64>; 3],
+    normal: Vector3<f64>,
+    area: f64,
+}
+
+pub struct HeatGeodesics {
+    faces: Vec<FaceFrame>,
+    vertices_count: usize,
+    heat: CscCholesky<f64>,
+    poisson: CscCholesky<f64>,
+}
+
+impl HeatGeodesics {
+    pub fn try_new(
+        vertices: &Matrix3xX<f32>,
+        faces: &Matrix3xX<i32>,
+    ) -> Result<Self, MeshProcessingError> {
+        let laplacian = Laplacian::cotangent(vertices, faces);
+        let frames: Vec<FaceFrame> = faces
+            .column_iter()
+            .filter_map(|face| {
+                let corners = [face[0] as usize, face[1] as usize, face[2] as usize];
+                let positions = corners.map(|corner| position(vertices, corner));
+                let area = triangle_area(&positions);
+                if area < DEGENERATE_EPSILON {
+                    return None;
+                }
+                let normal = (positions[1] - positions[0])
+                    .cross(&(positions[2] - positions[0]))
+                    .normalize();
+                Some(FaceFrame {
+                    corners,
+                    positions,
+                    normal,
+                    area,
+                })
+            })
+            .collect();
+
+        let time = mean_edge_length(&frames).powi(2);
+        Ok(Self {
+            heat: factor(&laplacian.system(1.0, time))?,
+            poisson: factor(&laplacian.system(POISSON_REGULARIZATION, 1.0))?,
+            faces: frames,
+            vertices_count: vertices.ncols(),
+        })
+    }
+
+    pub fn vertices_count(&self) -> usize {
+        self.vertices_count
+    }
+
+    pub fn distances(&self, source: usize) -> Result<Vec<f32>, MeshProcessingError> {
+        if source >= self.vertices_count {
+            return Err(MeshProcessingError::InvalidVertex {
+                vertex: source,
+                count: self.vertices_count,
+            });
+        }
+
+        let mut impulse = DMatrix::zeros(self.vertices_count, 1);
+        impulse[(source, 0)] = 1.0;
+        let heat = self.heat.solve(&impulse);
+
+        let mut divergence = DMatrix::zeros(self.vertices_count, 1);
+        for face in &self.faces {
+            let gradient = face_gradient(face, &heat);
+            let scale = gradient.amax();
+            if scale == 0.0 {
+                continue;
+            }
+            let direction = -(gradient / scale).normalize();
+
+            for i in 0..3 {
+                let next = (i + 1) % 3;
+                let prev = (i + 2) % 3;
+                let to_next = face.positions[next] - face.positions[i];
+                let to_prev = face.positions[prev] - face.positions[i];
+                divergence[(face.corners[i], 0)] += 0.5
+                    * (cotangent(&face.positions, prev) * to_next.dot(&direction)
+                        + cotangent(&face.positions, next) * to_prev.dot(&direction));
+            }
+        }
+
+        let potential = self.poisson.solve(&(-divergence));
+        let origin = potential[(source, 0)];
+        Ok(potential
+            .iter()
+            .map(|value| (value - origin).max(0.0) as f32)
+            .collect())
+    }
+}
+
+fn face_gradient(face: &FaceFrame, values: &DMatrix<f64>) -> Vector3<f64> {
+    let mut gradient = Vector3::zeros();
+    for i in 0..3 {
+        let opposite_edge = face.positions[(i + 2) % 3] - face.positions[(i + 1) % 3];
+        gradient += face.normal.cross(&opposite_edge) * values[(face.corners[i], 0)];
+    }
+    gradient / (2.0 * face.area)
+}
+
+fn mean_edge_length(faces: &[FaceFrame]) -> f64 {
+    if faces.is_empty() {
+        return 1.0;
+    }
+    let total: f64 = faces
+        .iter()
+        .map(|face| {
+            (0..3)
+                .map(|i| (face.positions[(i + 1) % 3] - face.positions[i]).norm())
+                .sum::<f64>()
+        })
+        .sum();
+    total / (faces.len() * 3) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::mesh_processing::laplacian::grid;
+
+    const COLUMNS: usize = 20;
+
+    #[test]
+    fn flat_grid_distances_match_euclidean() {
+        let (vertices, faces) = grid(COLUMNS, COLUMNS, 2.0, |_, _| 0.0);
+        let geodesics = HeatGeodesics::try_new(&vertices, &faces).unwrap();
+        let source = (COLUMNS + 1) * (COLUMNS + 1) / 2;
+        let distances = geodesics.distances(source).unwrap();
+
+        assert_eq!(distances.len(), vertices.ncols());
+        assert_eq!(distances[source], 0.0);
+        for (index, &distance) in distances.iter().enumerate() {
+            let expected = (vertices.column(index) - vertices.column(source)).norm();
+            assert!(
+                (distance - expected).abs() < 0.05 + 0.05 * expected,
+                "vertex {}: {} vs {}",
+                index,
+                distance,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn distances_grow_away_from_corner_source() {
+        let (vertices, faces) = grid(COLUMNS, COLUMNS, 2.0, |_, _| 0.0);
+        let geodesics = HeatGeodesics::try_new(&vertices, &faces).unwrap();
+        let distances = geodesics.distances(0).unwrap();
+        for pair in distances[..=COLUMNS].windows(2) {
+            assert!(pair[1] > pair[0]);
+        }
+        let opposite = distances[distances.len() - 1];
+        assert!((opposite - 8.0f32.sqrt()).abs() < 0.15, "{}", opposite);
+    }
+
+    #[test]
+    fn rejects_out_of_range_source() {
+        let (vertices, faces) = grid(2, 2, 1.0, |_, _| 0.0);
+        let geodesics = HeatGeodesics::try_new(&vertices, &faces).unwrap();
+        assert!(matches!(
+            geodesics.distances(9),
+            Err(MeshProcessingError::InvalidVertex {
+                vertex: 9,
+                count: 9
+            })
+        ));
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::HashSet;
+
+use na::{DMatrix, DVector, Matrix3xX, Vector3};
+use na_sparse::factorization::CscCholesky;
+use na_sparse::{CooMatrix, CscMatrix, CsrMatrix};
+
+use super::error::MeshProcessingError;
+
+const DEGENERATE_EPSILON: f64 = 1e-12;
+
+pub struct Laplacian {
+    entries: Vec<(usize, usize, f64)>,
+    mass: DVector<f64>,
+    stiffness: CsrMatrix<f64>,
+}
+
+impl Laplacian {
+    pub fn uniform(vertices_count: usize, faces: &Matrix3xX<i32>) -> Self {
+        let mut edges = HashSet::new();
+        for face in faces.column_iter() {
+            for i in 0..3 {
+                let a = face[i] as usize;
+                let b = face[(i + 1) % 3] as usize;
+                if a != b {
+                    edges.insert((a.min(b), a.max(b)));
+                }
+            }
+        }
+
+        let mut entries = Vec::with_capacity(edges.len() * 4);
+        let mut mass = DVector::zeros(vertices_count);
+        for (a, b) in edges {
+            add_edge(&mut entries, a, b, 1.0);
+            mass[a] += 1.0;
+            mass[b] += 1.0;
+        }
+        Self::from_entries(vertices_count, entries, mass)
+    }
+
+    pub fn cotangent(vertices: &Matrix3xX<f32>, faces: &Matrix3xX<i32>) -> Self {
+        let vertices_count = vertices.ncols();
+        let mut entries = Vec::with_capacity(faces.ncols() * 12);
+        let mut mass = DVector::zeros(vertices_count);
+
+        for face in faces.column_iter() {
+            let corners = [face[0] as usize, face[1] as usize, face[2] as usize];
+            let positions = corners.map(|corner| position(vertices, corner));
+            let area = triangle_area(&positions);
+            if area < DEGENERATE_EPSILON {
+                continue;
+            }
+
+            for i in 0..3 {
+                let opposite = (i + 2) % 3;
+                let weight = 0.5 * cotangent(&positions, opposite);
+                add_edge(&mut entries, corners[i], corners[(i + 1) % 3], weight);
+                mass[corners[i]] += area / 3.0;
+            }
+        }
+        Self::from_entries(vertices_count, entries, mass)
+    }
+
+    fn from_entries(
+        vertices_count: usize,
+        entries: Vec<(usize, usize, f64)>,
+        mass: DVector<f64>,
+    ) -> Self {
+        let mut coo = CooMatrix::new(vertices_count, vertices_count);
+        for &(row, column, value) in &entries {
+            coo.push(row, column, value);
+        }
+        Self {
+            stiffness: CsrMatrix::from(&coo),
+            entries,
+            mass,
+        }
+    }
+
+    pub fn vertices_count(&self) -> usize {
+        self.mass.len()
+    }
+
+    pub fn entries(&self) -> &[(usize, usize, f64)] {
+        &self.entries
+    }
+
+    #[cfg(test)]
+    pub(crate) fn stiffness(&self) -> &CsrMatrix<f64> {
+        &self.stiffness
+    }
+
+    #[cfg(test)]
+    pub(crate) fn mass_diagonal(&self) -> &DVector<f64> {
+        &self.mass
+    }
+
+    pub fn system(&self, mass_weight: f64, stiffness_weight: f64) -> CscMatrix<f64> {
+        let indices: Vec<Option<usize>> = (0..self.vertices_count()).map(Some).collect();
+        self.restricted_system(mass_weight, stiffness_weight, &indices)
+    }
+
+    pub fn restricted_system(
+        &self,
+        mass_weight: f64,
+        stiffness_weight: f64,
+        indices: &[Option<usize>],
+    ) -> CscMatrix<f64> {
+        let size = indices.iter().flatten().count();
+        let mut coo = CooMatrix::new(size, size);
+        if mass_weight != 0.0 {
+            for (vertex, &value) in self.mass.iter().enumerate() {
+                if let Some(index) = indices[vertex] {
+                    coo.push(index, index, value * mass_weight);
+                }
+            }
+        }
+        for &(row, column, value) in &self.entries {
+            if let (Some(row), Some(column)) = (indices[row], indices[column]) {
+                coo.push(row, column, value * stiffness_weight);
+            }
+        }
+        CscMatrix::from(&coo)
+    }
+
+    pub fn scale_by_mass(&self, values: &DMatrix<f64>) -> DMatrix<f64> {
+        DMatrix::from_fn(values.nrows(), values.ncols(), |row, column| {
+            values[(row, column)] * self.mass[row]
+        })
+    }
+
+    pub fn apply(&self, values: &DMatrix<f64>) -> DMatrix<f64> {
+        let stiffness_values = &self.stiffness * values;
+        DMatrix::from_fn(values.nrows(), values.ncols(), |row, column| {
+            let mass = self.mass[row];
+            if mass > DEGENERATE_EPSILON {
+                -stiffness_values[(row, column)] / mass
+            } else {
+                0.0
+            }
+        })
+    }
+}
+
+pub fn factor(system: &CscMatrix<f64>) -> Result<CscCholesky<f64>, MeshProcessingError> {
+    CscCholesky::factor(system).map_err(|_| MeshProcessingError::NotPositiveDefinite)
+}
+
+pub fn positions_matrix(vertices: &Matrix3xX<f32>) -> DMatrix<f64> {
+    DMatrix::from_fn(vertices.ncols(), 3, |row, column| {
+        vertices[(column, row)] as f64
+    })
+}
+
+pub fn vertices_from_positions(positions: &DMatrix<f64>) -> Matrix3xX<f32> {
+    Matrix3xX::from_fn(positions.nrows(), |row, column| {
+        positions[(column, row)] as f32
+    })
+}
+
+pub(super) fn position(vertices: &Matrix3xX<f32>, index: usize) -> Vector3<f64> {
+    vertices.column(index).map(|value| value as f64)
+}
+
+pub(super) fn triangle_area(positions: &[Vector3<f64>; 3]) -> f64 {
+    0.5 * (positions[1] - positions[0])
+        .cross(&(positions[2] - positions[0]))
+        .norm()
+}
+
+pub(super) fn cotangent(positions: &[Vector3<f64>; 3], corner: usize) -> f64 {
+    let origin = positions[corner];
+    let to_next = positions[(corner + 1) % 3] - origin;
+    let to_prev = positions[(corner + 2) % 3] - origin;
+    let sine = to_next.cross(&to_prev).norm();
+    if sine < DEGENERATE_EPSILON {
+        0.0
+    } else {
+        to_next.dot(&to_prev) / sine
+    }
+}
+
+fn add_edge(entries: &mut Vec<(usize, usize, f64)>, a: usize, b: usize, weight: f64) {
+    entries.push((a, b, -weight));
+    entries.push((b, a, -weight));
+    entries.push((a, a, weight));
+    entries.push((b, b, weight));
+}
+
+#[cfg(test)]
+pub(super) fn grid(
+    columns: usize,
+    rows: usize,
+    size: f32,
+    height: impl Fn(f32, f32) -> f32,
+) -> (Matrix3xX<f32>, Matrix3xX<i32>) {
+    let vertices = Matrix3xX::from_fn((columns + 1) * (rows + 1), |axis, index| {
+        let x = size * ((index % (columns + 1)) as f32 / columns as f32 - 0.5);
+        let y = size * ((index / (columns + 1)) as f32 / rows as f32 - 0.5);
+        [x, y, height(x, y)][axis]
+    });
+    let mut faces = Vec::with_capacity(columns * rows * 6);
+    for row in 0..rows {
+        for column in 0..columns {
+            let corner = (row * (columns + 1) + column) as i32;
+            let above = corner + columns as i32 + 1;
+            faces.extend_from_slice(&[corner, corner + 1, above + 1]);
+            faces.extend_from_slice(&[corner, above + 1, above]);
+        }
+    }
+    (vertices, Matrix3xX::from_vec(faces))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f64 = 1e-9;
+
+    fn bumpy_grid() -> (Matrix3xX<f32>, Matrix3xX<i32>) {
+        grid(6, 5, 2.0, |x, y| 0.3 * (3.0 * x).sin() * (2.0 * y).cos())
+    }
+
+    fn dense(laplacian: &Laplacian) -> DMatrix<f64> {
+        DMatrix::from(laplacian.stiffness())
+    }
+
+    #[test]
+    fn stiffness_is_symmetric_with_zero_row_sums() {
+        let (vertices, faces) = bumpy_grid();
+        let laplacians = [
+            Laplacian::uniform(vertices.ncols(), &faces),
+            Laplacian::cotangent(&vertices, &faces),
+        ];
+        for (index, laplacian) in laplacians.iter().enumerate() {
+            let stiffness = dense(laplacian);
+            assert_eq!(stiffness.nrows(), vertices.ncols());
+            assert!((&stiffness - stiffness.transpose()).amax() < TOLERANCE);
+            for row in stiffness.row_iter() {
+                assert!(row.sum().abs() < TOLERANCE, "laplacian {}", index);
+            }
+        }
+    }
+
+    #[test]
+    fn cotangent_weights_reproduce_linear_functions() {
+        let (vertices, faces) = grid(4, 4, 2.0, |x, y| 0.1 * x + 0.2 * y);
+        let laplacian = Laplacian::cotangent(&vertices, &faces);
+        let linear = DMatrix::from_fn(vertices.ncols(), 1, |row, _| {
+            (2.0 * vertices[(0, row)] - vertices[(1, row)]) as f64
+        });
+        let applied = laplacian.apply(&linear);
+
+        let interior = [6, 7, 8, 11, 12, 13, 16, 17, 18];
+        for &vertex in interior.iter() {
+            assert!(applied[(vertex, 0)].abs() < 1e-5, "vertex {}", vertex);
+        }
+        assert!(applied[(0, 0)].abs() > 1e-3);
+    }
+
+    #[test]
+    fn mass_sums_to_surface_area() {
+        let (vertices, faces) = grid(3, 7, 2.0, |_, _| 1.0);
+        let laplacian = Laplacian::cotangent(&vertices, &faces);
+        assert!((laplacian.mass_diagonal().sum() - 4.0).abs() < 1e-6);
+        assert!(laplacian.mass_diagonal().iter().all(|&mass| mass > 0.0));
+    }
+
+    #[test]
+    fn skips_degenerate_faces() {
+        let vertices = Matrix3xX::from_column_slice(&[
+            0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 1.0, 0.0,
+        ]);
+        let faces = Matrix3xX::from_column_slice(&[0, 1, 3, 0, 1, 2]);
+        let laplacian = Laplacian::cotangent(&vertices, &faces);
+        assert_eq!(laplacian.mass_diagonal()[2], 0.0);
+        assert!(laplacian.entries().iter().all(|entry| entry.0 != 2));
+        assert!(laplacian.entries().iter().all(|entry| entry.2.is_finite()));
+    }
+}
@@ -0,0 +1,8 @@
+pub mod decimation;
+pub mod error;
+pub mod geodesic;
+pub mod laplacian;
+pub mod parameterization;
+pub mod scalar_field;
+pub mod smoothing;
+pub mod weld;
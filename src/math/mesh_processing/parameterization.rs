@@ -0,0 +1,160 @@
+use std::collections::{HashMap, HashSet};
+
+use na::{DMatrix, Matrix2xX, Matrix3xX};
+
+use super::error::MeshProcessingError;
+use super::laplacian::{factor, position, Laplacian};
+
+pub fn boundary_loop(faces: &Matrix3xX<i32>) -> Vec<usize> {
+    let mut edges = HashSet::new();
+    for face in faces.column_iter() {
+        for i in 0..3 {
+            edges.insert((face[i] as usize, face[(i + 1) % 3] as usize));
+        }
+    }
+
+    let mut next: HashMap<usize, usize> = edges
+        .iter()
+        .filter(|(a, b)| !edges.contains(&(*b, *a)))
+        .map(|&(a, b)| (a, b))
+        .collect();
+
+    let mut longest = vec![];
+    while let Some(&start) = next.keys().min() {
+        let mut current_loop = vec![start];
+        let mut current = start;
+        while let Some(following) = next.remove(&current) {
+            if following == start {
+                break;
+            }
+            current_loop.push(following);
+            current = following;
+        }
+        if current_loop.len() > longest.len() {
+            longest = current_loop;
+        }
+    }
+    longest
+}
+
+pub fn harmonic_parameterization(
+    vertices: &Matrix3xX<f32>,
+    faces: &Matrix3xX<i32>,
+) -> Result<Matrix2xX<f32>, MeshProcessingError> {
+    let boundary = boundary_loop(faces);
+    if boundary.len() < 3 {
+        return Err(MeshProcessingError::NoBoundary);
+    }
+
+    let vertices_count = vertices.ncols();
+    let mut tex_coords = Matrix2xX::from_element(vertices_count, 0.5);
+    let mut perimeter = Vec::with_capacity(boundary.len());
+    let mut total_length: f64 = 0.0;
+    for (index, &vertex) in boundary.iter().enumerate() {
+        perimeter.push(total_length);
+        let next = boundary[(index + 1) % boundary.len()];
+        total_length += (position(vertices, next) - position(vertices, vertex)).norm();
+    }
+    let total_length = total_length.max(f64::EPSILON);
+
+    let mut is_boundary = vec![false; vertices_count];
+    for (&vertex, &length) in boundary.iter().zip(perimeter.iter()) {
+        let angle = std::f64::consts::TAU * length / total_length;
+        tex_coords[(0, vertex)] = (0.5 + 0.5 * angle.cos()) as f32;
+        tex_coords[(1, vertex)] = (0.5 + 0.5 * angle.sin()) as f32;
+        is_boundary[vertex] = true;
+    }
+
+    let mut interior_count = 0;
+    let indices: Vec<Option<usize>> = is_boundary
+        .iter()
+        .map(|&on_boundary| {
+            if on_boundary {
+                None
+            } else {
+                interior_count += 1;
+                Some(interior_count - 1)
+            }
+        })
+        .collect();
+    if interior_count == 0 {
+        return Ok(tex_coords);
+    }
+
+    let laplacian = Laplacian::cotangent(vertices, faces);
+    let mut rhs = DMatrix::zeros(interior_count, 2);
+    for &(row, column, value) in laplacian.entries() {
+        if let (Some(row), None) = (indices[row], indices[column]) {
+            for axis in 0..2 {
+                rhs[(row, axis)] -= value * tex_coords[(axis, column)] as f64;
+            }
+        }
+    }
+
+    let cholesky = factor(&laplacian.restricted_system(0.0, 1.0, &indices))?;
+    let solved = cholesky.solve(&rhs);
+    for (vertex, index) in indices.iter().enumerate() {
+        if let Some(index) = index {
+            tex_coords[(0, vertex)] = solved[(*index, 0)] as f32;
+            tex_coords[(1, vertex)] = solved[(*index, 1)] as f32;
+        }
+    }
+    Ok(tex_coords)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::mesh_processing::laplacian::grid;
+
+    const TOLERANCE: f32 = 1e-5;
+    const COLUMNS: usize = 8;
+
+    fn signed_area(tex_coords: &Matrix2xX<f32>, face: &[usize; 3]) -> f32 {
+        let [a, b, c] = face.map(|corner| tex_coords.column(corner).into_owned());
+        let (ab, ac) = (b - a, c - a);
+        ab.x * ac.y - ab.y * ac.x
+    }
+
+    #[test]
+    fn boundary_loop_follows_face_winding() {
+        let (_, faces) = grid(COLUMNS, COLUMNS, 1.0, |_, _| 0.0);
+        let boundary = boundary_loop(&faces);
+        assert_eq!(boundary.len(), 4 * COLUMNS);
+        assert_eq!(&boundary[..3], &[0, 1, 2]);
+    }
+
+    #[test]
+    fn maps_boundary_to_circle_without_flips() {
+        let (vertices, faces) = grid(COLUMNS, COLUMNS, 2.0, |x, y| 0.5 * (x * x - y * y));
+        let tex_coords = harmonic_parameterization(&vertices, &faces).unwrap();
+        let boundary = boundary_loop(&faces);
+
+        for &vertex in boundary.iter() {
+            let radius = (tex_coords.column(vertex).add_scalar(-0.5) * 2.0).norm();
+            assert!((radius - 1.0).abs() < TOLERANCE, "vertex {}", vertex);
+        }
+        for vertex in 0..vertices.ncols() {
+            if !boundary.contains(&vertex) {
+                let radius = (tex_coords.column(vertex).add_scalar(-0.5) * 2.0).norm();
+                assert!(radius < 1.0, "vertex {}", vertex);
+            }
+        }
+        for face in faces.column_iter() {
+            let corners = [face[0] as usize, face[1] as usize, face[2] as usize];
+            assert!(signed_area(&tex_coords, &corners) > 0.0, "{:?}", corners);
+        }
+    }
+
+    #[test]
+    fn rejects_closed_mesh() {
+        let vertices = Matrix3xX::from_column_slice(&[
+            0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0,
+        ]);
+        let faces = Matrix3xX::from_column_slice(&[0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]);
+        assert!(matches!(
+            harmonic_parameterization(&vertices, &faces),
+            Err(MeshProcessingError::NoBoundary)
+        ));
+    }
+}
@@ -0,0 +1,46 @@
+use na::{Matrix3xX, Vector3};
+
+const COLOR_STOPS: [(f32, [f32; 3]); 5] = [
+    (0.0, [0.05, 0.1, 0.6]),
+    (0.25, [0.0, 0.6, 0.9]),
+    (0.5, [0.2, 0.8, 0.3]),
+    (0.75, [0.95, 0.85, 0.1]),
+    (1.0, [0.85, 0.15, 0.1]),
+];
+const ISOLINE_WIDTH: f32 = 0.08;
+const ISOLINE_SHADE: f32 = 0.35;
+
+pub fn normalize_field(values: &[f32]) -> Vec<f32> {
+    let min = values.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let range = (max - min).max(f32::EPSILON);
+    values.iter().map(|value| (value - min) / range).collect()
+}
+
+pub fn color_ramp(t: f32) -> Vector3<f32> {
+    let t = t.clamp(0.0, 1.0);
+    for window in COLOR_STOPS.windows(2) {
+        let (start, from) = window[0];
+        let (end, to) = window[1];
+        if t <= end {
+            let local = (t - start) / (end - start);
+            return Vector3::from(from).lerp(&Vector3::from(to), local);
+        }
+    }
+    Vector3::from(COLOR_STOPS[COLOR_STOPS.len() - 1].1)
+}
+
+pub fn colorize(values: &[f32], isolines: usize) -> Matrix3xX<f32> {
+    let normalized = normalize_field(values);
+    let mut colors = Matrix3xX::zeros(values.len());
+    for (t, mut color) in normalized.iter().zip(colors.column_iter_mut()) {
+        let band = (t * isolines as f32).fract();
+        let shade = if isolines > 0 && band < ISOLINE_WIDTH {
+            ISOLINE_SHADE
+        } else {
+            1.0
+        };
+        color.copy_from(&(color_ramp(*t) * shade));
+    }
+    colors
+}
@@ -0,0 +1,84 @@
+use na::Matrix3xX;
+
+use super::error::MeshProcessingError;
+use super::laplacian::{factor, positions_matrix, vertices_from_positions, Laplacian};
+
+pub fn laplacian_smooth(
+    vertices: &Matrix3xX<f32>,
+    faces: &Matrix3xX<i32>,
+    time_step: f32,
+    iterations: usize,
+) -> Result<Matrix3xX<f32>, MeshProcessingError> {
+    let mut smoothed = vertices.clone();
+    for _ in 0..iterations {
+        let laplacian = Laplacian::cotangent(&smoothed, faces);
+        let cholesky = factor(&laplacian.system(1.0, time_step as f64))?;
+        let positions = positions_matrix(&smoothed);
+        let solved = cholesky.solve(&laplacian.scale_by_mass(&positions));
+        smoothed = vertices_from_positions(&solved);
+    }
+    Ok(smoothed)
+}
+
+pub fn taubin_smooth(
+    vertices: &Matrix3xX<f32>,
+    faces: &Matrix3xX<i32>,
+    lambda: f32,
+    mu: f32,
+    iterations: usize,
+) -> Matrix3xX<f32> {
+    let laplacian = Laplacian::uniform(vertices.ncols(), faces);
+    let mut positions = positions_matrix(vertices);
+    for _ in 0..iterations {
+        for step in [lambda, mu] {
+            let delta = laplacian.apply(&positions);
+            positions += delta * step as f64;
+        }
+    }
+    vertices_from_positions(&positions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::mesh_processing::laplacian::grid;
+
+    const TOLERANCE: f32 = 1e-4;
+
+    fn noisy_plane() -> (Matrix3xX<f32>, Matrix3xX<i32>) {
+        let (mut vertices, faces) = grid(10, 10, 2.0, |_, _| 0.0);
+        for (index, mut vertex) in vertices.column_iter_mut().enumerate() {
+            let jitter = 0.05 * (index as f32 * 12.9898).sin();
+            let (x, y) = (vertex.x + jitter, vertex.y - jitter);
+            vertex.x = x;
+            vertex.y = y;
+            vertex.z = 0.5 * x - 0.25 * y + 1.0;
+        }
+        (vertices, faces)
+    }
+
+    fn plane_distance(vertices: &Matrix3xX<f32>) -> f32 {
+        vertices
+            .column_iter()
+            .map(|vertex| (vertex.z - 0.5 * vertex.x + 0.25 * vertex.y - 1.0).abs())
+            .fold(0.0, f32::max)
+    }
+
+    #[test]
+    fn laplacian_smoothing_keeps_plane_flat() {
+        let (vertices, faces) = noisy_plane();
+        let smoothed = laplacian_smooth(&vertices, &faces, 0.01, 3).unwrap();
+        assert_eq!(smoothed.ncols(), vertices.ncols());
+        assert!(plane_distance(&smoothed) < TOLERANCE);
+        assert!((&smoothed - &vertices).amax() > TOLERANCE);
+    }
+
+    #[test]
+    fn taubin_smoothing_keeps_plane_flat() {
+        let (vertices, faces) = noisy_plane();
+        let smoothed = taubin_smooth(&vertices, &faces, 0.5, -0.53, 10);
+        assert_eq!(smoothed.ncols(), vertices.ncols());
+        assert!(plane_distance(&smoothed) < TOLERANCE);
+        assert!((&smoothed - &vertices).amax() > TOLERANCE);
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use na::Matrix3xX;
+
+pub struct WeldedGeometry {
+    pub vertices: Matrix3xX<f32>,
+    pub faces: Matrix3xX<i32>,
+    pub sources: Vec<usize>,
+}
+
+pub fn weld(vertices: &Matrix3xX<f32>, faces: &Matrix3xX<i32>, epsilon: f32) -> WeldedGeometry {
+    let scale = 1.0 / epsilon.max(f32::EPSILON);
+    let mut keys: HashMap<[i64; 3], usize> = HashMap::new();
+    let mut sources = vec![];
+    let remap: Vec<usize> = vertices
+        .column_iter()
+        .enumerate()
+        .map(|(index, vertex)| {
+            let key = [0, 1, 2].map(|axis| (vertex[axis] * scale).round() as i64);
+            *keys.entry(key).or_insert_with(|| {
+                sources.push(index);
+                sources.len() - 1
+            })
+        })
+        .collect();
+
+    let faces = Matrix3xX::from_fn(faces.ncols(), |row, column| {
+        remap[faces[(row, column)] as usize] as i32
+    });
+    WeldedGeometry {
+        vertices: vertices.select_columns(&sources),
+        faces,
+        sources,
+    }
+}
+
+pub fn remove_unreferenced(vertices: &Matrix3xX<f32>, faces: &Matrix3xX<i32>) -> WeldedGeometry {
+    let mut remap = vec![usize::MAX; vertices.ncols()];
+    let mut sources = vec![];
+    for &vertex in faces.iter() {
+        let vertex = vertex as usize;
+        if remap[vertex] == usize::MAX {
+            remap[vertex] = sources.len();
+            sources.push(vertex);
+        }
+    }
+
+    let faces = faces.map(|vertex| remap[vertex as usize] as i32);
+    WeldedGeometry {
+        vertices: vertices.select_columns(&sources),
+        faces,
+        sources,
+    }
+}
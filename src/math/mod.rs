@@ -1 +1,9 @@
+pub mod bounds;
+pub mod bvh;
 pub mod compute_normals;
+pub mod compute_tangents;
+pub mod frustum;
+pub mod mesh_processing;
+pub mod random;
+pub mod ray;
+pub mod ssao;
@@ -0,0 +1,87 @@
+const DEFAULT_SEED: u32 = 0x9e37_79b9;
+
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: u32,
+}
+
+impl Random {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            state: if seed == 0 { DEFAULT_SEED } else { seed },
+        }
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x
+    }
+
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+}
+
+impl Default for Random {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLES: usize = 1000;
+
+    fn sequence(random: &mut Random) -> Vec<u32> {
+        (0..SAMPLES).map(|_| random.next_u32()).collect()
+    }
+
+    #[test]
+    fn is_deterministic_for_a_seed() {
+        assert_eq!(
+            sequence(&mut Random::new(42)),
+            sequence(&mut Random::new(42))
+        );
+        assert_ne!(
+            sequence(&mut Random::new(42)),
+            sequence(&mut Random::new(43))
+        );
+
+        let mut random = Random::new(42);
+        random.next_u32();
+        let mut forked = random.clone();
+        assert_eq!(sequence(&mut random), sequence(&mut forked));
+    }
+
+    #[test]
+    fn zero_seed_falls_back_to_default() {
+        assert_eq!(
+            sequence(&mut Random::new(0)),
+            sequence(&mut Random::default())
+        );
+        assert!(sequence(&mut Random::new(0))
+            .iter()
+            .all(|&value| value != 0));
+    }
+
+    #[test]
+    fn floats_stay_in_range() {
+        let mut random = Random::new(5);
+        for _ in 0..SAMPLES {
+            let value = random.next_f32();
+            assert!((0.0..1.0).contains(&value));
+            let value = random.range(-2.0, 3.0);
+            assert!((-2.0..3.0).contains(&value));
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use na::{Matrix4, Vector2, Vector3, Vector4};
+
+use super::bounds::Aabb;
+#[cfg(test)]
+use super::bounds::Sphere;
+
+const EPSILON: f32 = 1e-7;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    pub origin: Vector3<f32>,
+    pub direction: Vector3<f32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TriangleHit {
+    pub distance: f32,
+    pub barycentric: Vector3<f32>,
+}
+
+impl Ray {
+    pub fn new(origin: Vector3<f32>, direction: Vector3<f32>) -> Self {
+        Self {
+            origin,
+            direction: direction.normalize(),
+        }
+    }
+
+    pub fn unproject(
+        inverse_view_projection: &Matrix4<f32>,
+        ndc: &Vector2<f32>,
+        near_z: f32,
+        far_z: f32,
+    ) -> Option<Self> {
+        let unproject = |z: f32| {
+            let point = inverse_view_projection * Vector4::new(ndc.x, ndc.y, z, 1.0);
+            (point.w.abs() > EPSILON).then(|| point.xyz() / point.w)
+        };
+
+        let near = unproject(near_z)?;
+        let far = unproject(far_z)?;
+        let direction = far - near;
+        (direction.norm() > EPSILON).then(|| Self::new(near, direction))
+    }
+
+    #[cfg(test)]
+    pub(crate) fn at(&self, distance: f32) -> Vector3<f32> {
+        self.origin + self.direction * distance
+    }
+
+    pub fn transform(&self, matrix: &Matrix4<f32>) -> Self {
+        let origin = matrix.transform_point(&self.origin.into()).coords;
+        let direction = matrix.transform_vector(&self.direction);
+        Self { origin, direction }
+    }
+
+    pub fn intersect_triangle(
+        &self,
+        a: &Vector3<f32>,
+        b: &Vector3<f32>,
+        c: &Vector3<f32>,
+    ) -> Option<TriangleHit> {
+        let ab = b - a;
+        let ac = c - a;
+        let p = self.direction.cross(&ac);
+        let det = ab.dot(&p);
+        if det.abs() < EPSILON {
+            return None;
+        }
+
+        let inv_det = 1.0 / det;
+        let ao = self.origin - a;
+        let u = ao.dot(&p) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q = ao.cross(&ab);
+        let v = self.direction.dot(&q) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let distance = ac.dot(&q) * inv_det;
+        (distance > EPSILON).then(|| TriangleHit {
+            distance,
+            barycentric: Vector3::new(1.0 - u - v, u, v),
+        })
+    }
+
+    pub fn intersect_aabb(&self, aabb: &Aabb) -> Option<(f32, f32)> {
+        let mut t_min = 0.0f32;
+        let mut t_max = f32::INFINITY;
+        for axis in 0..3 {
+            let inv_direction = 1.0 / self.direction[axis];
+            let mut t0 = (aabb.min[axis] - self.origin[axis]) * inv_direction;
+            let mut t1 = (aabb.max[axis] - self.origin[axis]) * inv_direction;
+            if inv_direction < 0.0 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+            if !t0.is_nan() {
+                t_min = t_min.max(t0);
+            }
+            if !t1.is_nan() {
+                t_max = t_max.min(t1);
+            }
+            if t_max < t_min {
+                return None;
+            }
+        }
+        Some((t_min, t_max))
+    }
+
+    #[cfg(test)]
+    pub(crate) fn intersect_sphere(&self, sphere: &Sphere) -> Option<f32> {
+        let oc = self.origin - sphere.center;
+        let a = self.direction.norm_squared();
+        let half_b = oc.dot(&self.direction);
+        let c = oc.norm_squared() - sphere.radius * sphere.radius;
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < 0.0 || a < EPSILON {
+            return None;
+        }
+
+        let sqrt_d = discriminant.sqrt();
+        let near = (-half_b - sqrt_d) / a;
+        let far = (-half_b + sqrt_d) / a;
+        if near >= 0.0 {
+            Some(near)
+        } else if far >= 0.0 {
+            Some(far)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f32 = 1e-5;
+
+    fn triangle() -> [Vector3<f32>; 3] {
+        [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+        ]
+    }
+
+    fn ray(origin: [f32; 3], direction: [f32; 3]) -> Ray {
+        Ray::new(Vector3::from(origin), Vector3::from(direction))
+    }
+
+    #[test]
+    fn hits_triangle_with_barycentrics() {
+        let [a, b, c] = triangle();
+        let hit = ray([0.25, 0.5, 2.0], [0.0, 0.0, -1.0])
+            .intersect_triangle(&a, &b, &c)
+            .unwrap();
+        assert!((hit.distance - 2.0).abs() < TOLERANCE);
+        assert!((hit.barycentric - Vector3::new(0.25, 0.25, 0.5)).norm() < TOLERANCE);
+    }
+
+    #[test]
+    fn misses_triangle_outside_edges_and_behind_origin() {
+        let [a, b, c] = triangle();
+        assert!(ray([0.8, 0.8, 1.0], [0.0, 0.0, -1.0])
+            .intersect_triangle(&a, &b, &c)
+            .is_none());
+        assert!(ray([-0.1, 0.5, 1.0], [0.0, 0.0, -1.0])
+            .intersect_triangle(&a, &b, &c)
+            .is_none());
+        assert!(ray([0.25, 0.25, 1.0], [0.0, 0.0, 1.0])
+            .intersect_triangle(&a, &b, &c)
+            .is_none());
+    }
+
+    #[test]
+    fn ignores_rays_parallel_to_triangle() {
+        let [a, b, c] = triangle();
+        assert!(ray([-1.0, 0.25, 0.0], [1.0, 0.0, 0.0])
+            .intersect_triangle(&a, &b, &c)
+            .is_none());
+    }
+
+    #[test]
+    fn hits_back_faces() {
+        let [a, b, c] = triangle();
+        let hit = ray([0.25, 0.25, -3.0], [0.0, 0.0, 1.0])
+            .intersect_triangle(&a, &b, &c)
+            .unwrap();
+        assert!((hit.distance - 3.0).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn intersects_aabb_slabs() {
+        let aabb = Aabb::new(Vector3::repeat(-1.0), Vector3::repeat(1.0));
+
+        let (near, far) = ray([-5.0, 0.0, 0.0], [1.0, 0.0, 0.0])
+            .intersect_aabb(&aabb)
+            .unwrap();
+        assert!((near - 4.0).abs() < TOLERANCE);
+        assert!((far - 6.0).abs() < TOLERANCE);
+
+        let (near, far) = ray([0.0, 0.0, 0.0], [0.0, 1.0, 0.0])
+            .intersect_aabb(&aabb)
+            .unwrap();
+        assert_eq!(near, 0.0);
+        assert!((far - 1.0).abs() < TOLERANCE);
+
+        assert!(ray([-5.0, 2.0, 0.0], [1.0, 0.0, 0.0])
+            .intersect_aabb(&aabb)
+            .is_none());
+        assert!(ray([5.0, 0.0, 0.0], [1.0, 0.0, 0.0])
+            .intersect_aabb(&aabb)
+            .is_none());
+    }
+
+    #[test]
+    fn intersects_sphere_from_outside_and_inside() {
+        let sphere = Sphere::new(Vector3::new(0.0, 0.0, -5.0), 1.0);
+
+        let outside = ray([0.0, 0.0, 0.0], [0.0, 0.0, -1.0]);
+        assert!((outside.intersect_sphere(&sphere).unwrap() - 4.0).abs() < TOLERANCE);
+
+        let inside = ray([0.0, 0.0, -5.0], [1.0, 0.0, 0.0]);
+        assert!((inside.intersect_sphere(&sphere).unwrap() - 1.0).abs() < TOLERANCE);
+
+        assert!(ray([0.0, 2.0, 0.0], [0.0, 0.0, -1.0])
+            .intersect_sphere(&sphere)
+            .is_none());
+        assert!(ray([0.0, 0.0, 0.0], [0.0, 0.0, 1.0])
+            .intersect_sphere(&sphere)
+            .is_none());
+    }
+
+    #[test]
+    fn transformed_ray_keeps_distances_of_source_space() {
+        let world = ray([0.0, 0.0, 10.0], [0.0, 0.0, -1.0]);
+        let model =
+            Matrix4::new_translation(&Vector3::new(0.0, 0.0, 2.0)) * Matrix4::new_scaling(4.0);
+        let local = world.transform(&model.try_inverse().unwrap());
+
+        let sphere = Sphere::new(Vector3::zeros(), 1.0);
+        let distance = local.intersect_sphere(&sphere).unwrap();
+        assert!((distance - 4.0).abs() < TOLERANCE);
+        assert!(
+            (model.transform_point(&local.at(distance).into()).coords - world.at(distance)).norm()
+                < TOLERANCE
+        );
+    }
+}
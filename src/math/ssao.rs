@@ -0,0 +1,78 @@
+use na::Vector3;
+
+use super::random::Random;
+
+const EPSILON: f32 = 1e-6;
+const MIN_SAMPLE_SCALE: f32 = 0.1;
+
+pub fn hemisphere_kernel(size: usize, random: &mut Random) -> Vec<Vector3<f32>> {
+    (0..size)
+        .map(|i| {
+            let direction = Vector3::new(
+                random.range(-1.0, 1.0),
+                random.range(-1.0, 1.0),
+                random.next_f32(),
+            )
+            .try_normalize(EPSILON)
+            .unwrap_or_else(Vector3::z);
+            let t = i as f32 / size as f32;
+            let scale = MIN_SAMPLE_SCALE + (1.0 - MIN_SAMPLE_SCALE) * t * t;
+            direction * random.next_f32() * scale
+        })
+        .collect()
+}
+
+pub fn rotation_noise(size: usize, random: &mut Random) -> Vec<Vector3<f32>> {
+    (0..size * size)
+        .map(|_| {
+            Vector3::new(random.range(-1.0, 1.0), random.range(-1.0, 1.0), 0.0)
+                .try_normalize(EPSILON)
+                .unwrap_or_else(Vector3::x)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f32 = 1e-5;
+    const KERNEL_SIZE: usize = 64;
+    const NOISE_SIZE: usize = 4;
+
+    fn mean_length(samples: &[Vector3<f32>]) -> f32 {
+        samples.iter().map(|sample| sample.norm()).sum::<f32>() / samples.len() as f32
+    }
+
+    #[test]
+    fn kernel_samples_lie_in_unit_hemisphere() {
+        let kernel = hemisphere_kernel(KERNEL_SIZE, &mut Random::new(7));
+        assert_eq!(kernel.len(), KERNEL_SIZE);
+        for (i, sample) in kernel.iter().enumerate() {
+            let t = i as f32 / KERNEL_SIZE as f32;
+            let scale = MIN_SAMPLE_SCALE + (1.0 - MIN_SAMPLE_SCALE) * t * t;
+            assert!(sample.z >= 0.0, "sample {} below the surface", i);
+            assert!(sample.norm() <= 1.0 + TOLERANCE);
+            assert!(sample.norm() <= scale + TOLERANCE);
+        }
+    }
+
+    #[test]
+    fn kernel_scale_grows_toward_the_end() {
+        let kernel = hemisphere_kernel(KERNEL_SIZE, &mut Random::new(7));
+        let quarter = KERNEL_SIZE / 4;
+        let head = mean_length(&kernel[..quarter]);
+        let tail = mean_length(&kernel[KERNEL_SIZE - quarter..]);
+        assert!(tail > 2.0 * head, "head {} tail {}", head, tail);
+    }
+
+    #[test]
+    fn rotation_noise_is_unit_length_in_tangent_plane() {
+        let noise = rotation_noise(NOISE_SIZE, &mut Random::new(11));
+        assert_eq!(noise.len(), NOISE_SIZE * NOISE_SIZE);
+        for vector in noise.iter() {
+            assert_eq!(vector.z, 0.0);
+            assert!((vector.norm() - 1.0).abs() < TOLERANCE);
+        }
+    }
+}
@@ -3,6 +3,10 @@ use std::{cell::RefCell, rc::Rc};
 use wasm_bindgen::{prelude::Closure, JsCast};
 use web_sys::HtmlCanvasElement;
 
+use crate::capture::frame_capture::FrameCapture;
+use crate::gl::camera::input::{InputEvent, InputQueue};
+use crate::gl::picking::picker::Picker;
+use crate::gl::picking::picking_buffer::PickingBuffer;
 use crate::utils::web;
 use crate::{
     gl::{core::instance::GL, error::GLError},
@@ -26,7 +30,13 @@ where
     S: Sample + 'static,
 {
     sample: S,
+    gl: Rc<GL>,
+    capture: Option<FrameCapture>,
+    picking: Option<(Picker, PickingBuffer)>,
+    input: Option<InputQueue>,
+    viewport: (i32, i32),
     on_error: Box<dyn FnMut(GLError) -> OnErrorResult + 'static>,
+    on_stats: Box<dyn FnMut(&str) + 'static>,
     on_iter: Box<dyn FnMut() -> OnIterResult + 'static>,
     last_update_time: f64,
 }
@@ -38,8 +48,14 @@ where
     pub fn create(canvas: &HtmlCanvasElement) -> Result<Self, GLError> {
         let gl = GL::new_for_canvas(canvas);
         Ok(Self {
-            sample: S::try_new(gl)?,
+            sample: S::try_new(gl.clone())?,
+            gl: Rc::new(gl),
+            capture: None,
+            picking: None,
+            input: None,
+            viewport: (0, 0),
             on_error: Box::new(|_| OnErrorResult::Stop),
+            on_stats: Box::new(|_| {}),
             on_iter: Box::new(|| OnIterResult {
                 render_frame: true,
                 update_logic: true,
@@ -59,6 +75,65 @@ where
         self
     }
 
+    pub fn with_capture(mut self, capture: &FrameCapture) -> Self {
+        self.capture = Some(capture.clone());
+        self
+    }
+
+    pub fn with_picking(mut self, picker: &Picker) -> Result<Self, GLError> {
+        self.picking = Some((picker.clone(), PickingBuffer::try_new(&self.gl)?));
+        Ok(self)
+    }
+
+    pub fn with_input(mut self, input: &InputQueue) -> Self {
+        self.input = Some(input.clone());
+        self
+    }
+
+    fn dispatch_input(&mut self) -> Result<(), GLError> {
+        let viewport = (
+            self.gl.drawing_buffer_width(),
+            self.gl.drawing_buffer_height(),
+        );
+        if viewport != self.viewport {
+            self.viewport = viewport;
+            self.sample.on_input(&InputEvent::Resize {
+                width: viewport.0 as f32,
+                height: viewport.1 as f32,
+            })?;
+        }
+
+        let events = self.input.as_ref().map(InputQueue::take_events);
+        for event in events.iter().flatten() {
+            self.sample.on_input(event)?;
+        }
+        Ok(())
+    }
+
+    pub fn on_stats(mut self, cb: impl FnMut(&str) + 'static) -> Self {
+        self.on_stats = Box::new(cb);
+        self
+    }
+
+    fn pick(&mut self) -> Result<(), GLError> {
+        let (picker, buffer) = match &mut self.picking {
+            Some(picking) => picking,
+            None => return Ok(()),
+        };
+
+        let requests = picker.take_requests();
+        if requests.is_empty() {
+            return Ok(());
+        }
+
+        let sample = &mut self.sample;
+        let events = buffer.pick(&requests, |pass| sample.render_picking(pass))?;
+        for event in events.iter() {
+            self.sample.on_pick(event)?;
+        }
+        Ok(())
+    }
+
     pub fn run(mut self) {
         let callback = Rc::new(RefCell::new(None));
         let closure = Closure::wrap(Box::new({
@@ -75,7 +150,23 @@ where
                     return;
                 }
 
-                if update_logic {
+                if let Err(e) = self.dispatch_input() {
+                    if (self.on_error)(e) == OnErrorResult::Stop {
+                        return;
+                    }
+                }
+
+                let capture_frame = self.capture.as_ref().and_then(FrameCapture::next_frame);
+                let fixed_time_step = capture_frame.as_ref().and_then(|frame| frame.time_step);
+
+                if let Some(d_time) = fixed_time_step {
+                    self.last_update_time = js_sys::Date::now();
+                    if let Err(e) = self.sample.update(d_time) {
+                        if (self.on_error)(e) == OnErrorResult::Stop {
+                            return;
+                        }
+                    }
+                } else if update_logic {
                     let curr_time = js_sys::Date::now();
                     let d_time = curr_time - self.last_update_time;
                     self.last_update_time = curr_time;
@@ -86,12 +177,32 @@ where
                     }
                 }
 
-                if render {
+                let render_frame = render || capture_frame.is_some();
+                if render_frame {
                     if let Err(e) = self.sample.render() {
                         if (self.on_error)(e) == OnErrorResult::Stop {
                             return;
                         }
                     }
+                    if let Some(stats) = self.sample.stats() {
+                        (self.on_stats)(&stats);
+                    }
+                }
+
+                if let (Some(capture), Some(frame)) = (&self.capture, &capture_frame) {
+                    if let Err(e) = capture.capture(&self.gl, frame) {
+                        if (self.on_error)(e.into()) == OnErrorResult::Stop {
+                            return;
+                        }
+                    }
+                }
+
+                if render_frame {
+                    if let Err(e) = self.pick() {
+                        if (self.on_error)(e) == OnErrorResult::Stop {
+                            return;
+                        }
+                    }
                 }
 
                 request_animation_frame(callback.borrow().as_ref().unwrap());
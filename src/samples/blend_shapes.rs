@@ -0,0 +1,180 @@
+use std::rc::Rc;
+
+use super::sample::Sample;
+use crate::animation::clip::Track;
+use crate::animation::interpolation::Interpolation;
+use crate::animation::morph::MorphAnimation;
+use crate::gl;
+use crate::gl::camera::controller::CameraController;
+use crate::gl::camera::input::InputEvent;
+use crate::gl::camera::orbit::OrbitController;
+use crate::gl::camera::projection::Projection;
+use crate::gl::camera::Camera;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::lighting::light::Light;
+use crate::gl::lighting::light_buffer::LightBuffer;
+use crate::gl::materials::material::Material;
+use crate::gl::mesh::{Mesh, Vertices};
+use crate::gl::morph::morph_targets::MorphTarget;
+use crate::gl::morph::shaders::morph_vertex_shader;
+use crate::gl::pbr::pbr_material::PbrMaterial;
+use crate::gl::pbr::pbr_pipeline::PbrPipeline;
+use crate::gl::pbr::shaders::pbr_fragment_shader;
+use crate::gl::primitives::sphere::uv_sphere;
+use crate::gl::shader::program::ShaderProgram;
+use crate::gl::shader::shader_type::ShaderType;
+use glm::{Mat4x4, Vec3};
+
+const MAX_LIGHTS: usize = 2;
+
+const SPHERE_SEGMENTS: usize = 96;
+const SPHERE_RINGS: usize = 48;
+
+const STRETCH_AMOUNT: f32 = 0.6;
+const BUMPS_AMOUNT: f32 = 0.15;
+const BUMPS_FREQUENCY: f32 = 6.0;
+const ANIMATION_DURATION: f32 = 4.0;
+
+const FIELD_OF_VIEW: f32 = std::f32::consts::FRAC_PI_4;
+const NEAR_PLANE: f32 = 0.1;
+const FAR_PLANE: f32 = 100.0;
+
+pub struct BlendShapes {
+    gl: Rc<GL>,
+    mesh: Mesh,
+    material: Material,
+    animation: MorphAnimation,
+    time: f32,
+    lights: LightBuffer,
+    camera: Camera,
+    orbit: OrbitController,
+}
+
+fn stretch_target(vertices: &Vertices) -> MorphTarget {
+    let mut deltas = Vertices::zeros(vertices.ncols());
+    for (vertex, mut delta) in vertices.column_iter().zip(deltas.column_iter_mut()) {
+        delta.copy_from(&Vec3::new(
+            -vertex[0] * STRETCH_AMOUNT * 0.3,
+            vertex[1] * STRETCH_AMOUNT,
+            -vertex[2] * STRETCH_AMOUNT * 0.3,
+        ));
+    }
+    MorphTarget::new(deltas)
+}
+
+fn bumps_target(vertices: &Vertices) -> MorphTarget {
+    let mut deltas = Vertices::zeros(vertices.ncols());
+    for (vertex, mut delta) in vertices.column_iter().zip(deltas.column_iter_mut()) {
+        let theta = vertex[1].clamp(-1.0, 1.0).acos();
+        let phi = vertex[2].atan2(vertex[0]);
+        let height = (BUMPS_FREQUENCY * theta).sin() * (BUMPS_FREQUENCY * phi).sin();
+        delta.copy_from(&(vertex * (height * BUMPS_AMOUNT)));
+    }
+    MorphTarget::new(deltas)
+}
+
+fn weights_animation() -> Result<MorphAnimation, GLError> {
+    let times: Vec<f32> = (0..=4)
+        .map(|key| key as f32 * ANIMATION_DURATION / 4.0)
+        .collect();
+    let stretch = Track::try_new(
+        times.clone(),
+        vec![0.0, 1.0, 0.0, -0.5, 0.0],
+        Interpolation::Linear,
+    )?;
+    let bumps = Track::try_new(times, vec![0.0, 0.0, 1.0, 1.0, 0.0], Interpolation::Linear)?;
+    Ok(MorphAnimation::new("breathe")
+        .with_track(stretch)
+        .with_track(bumps))
+}
+
+impl Sample for BlendShapes {
+    fn try_new(gl: GL) -> Result<Self, GLError> {
+        let gl = Rc::new(gl);
+
+        let builder = uv_sphere(1.0, SPHERE_SEGMENTS, SPHERE_RINGS);
+        let targets = vec![
+            stretch_target(builder.vertices()),
+            bumps_target(builder.vertices()),
+        ];
+        let mesh = builder.add_morph_targets(targets).build(&gl)?;
+
+        let mut lights = LightBuffer::try_new(&gl, MAX_LIGHTS)?;
+        lights.upload(&[
+            Light::directional(Vec3::new(-0.5, -0.7, -0.6), Vec3::new(1.0, 0.95, 0.9), 3.0),
+            Light::directional(Vec3::new(0.6, -0.2, 0.7), Vec3::new(0.4, 0.5, 0.7), 1.0),
+        ]);
+
+        let pbr = PbrPipeline::try_new(&gl, &lights)?;
+        let program = Rc::new(
+            ShaderProgram::builder(&gl)
+                .add_source(ShaderType::Vertex, morph_vertex_shader())
+                .add_source(ShaderType::Fragment, pbr_fragment_shader(MAX_LIGHTS))
+                .build()?,
+        );
+        lights.attach(&program);
+
+        let mut material = pbr
+            .material(&PbrMaterial::new(Vec3::new(0.2, 0.6, 0.9), 0.1, 0.35))
+            .with_program(&program);
+        if let Some(morph_targets) = mesh.morph_targets() {
+            morph_targets.attach(&mut material);
+        }
+
+        let orbit = OrbitController::new(Vec3::zeros(), 5.0);
+        let mut camera = Camera::new(Projection::Perspective {
+            fov_y: FIELD_OF_VIEW,
+            near: NEAR_PLANE,
+            far: FAR_PLANE,
+        });
+        camera.view = orbit.view();
+
+        Ok(Self {
+            gl,
+            mesh,
+            material,
+            animation: weights_animation()?,
+            time: 0.0,
+            lights,
+            camera,
+            orbit,
+        })
+    }
+
+    fn update(&mut self, d_time: f64) -> Result<(), GLError> {
+        let duration = self.animation.duration().max(f32::EPSILON);
+        self.time = (self.time + d_time as f32 / 1000.0) % duration;
+
+        let weights = self.animation.sample(self.time);
+        if let Some(morph_targets) = self.mesh.morph_targets() {
+            morph_targets.set_weights(&mut self.material, &weights);
+        }
+
+        self.orbit.update(d_time);
+        self.orbit.apply(&mut self.camera);
+        Ok(())
+    }
+
+    fn on_input(&mut self, event: &InputEvent) -> Result<(), GLError> {
+        self.camera.handle_event(event);
+        self.orbit.handle_event(event);
+        Ok(())
+    }
+
+    fn render(&mut self) -> Result<(), GLError> {
+        let width = self.gl.drawing_buffer_width();
+        let height = self.gl.drawing_buffer_height();
+
+        self.gl.viewport(0, 0, width, height);
+        self.gl.clear_color(0.1, 0.1, 0.12, 1.0);
+        self.gl
+            .clear_depth(self.camera.projection().depth_clear_value());
+        self.gl.clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
+
+        self.lights.bind();
+        self.mesh
+            .render(&self.material, &self.camera.with_model(Mat4x4::identity()));
+        Ok(())
+    }
+}
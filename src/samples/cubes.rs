@@ -1,81 +1,450 @@
 use std::rc::Rc;
 
-use super::resources::simple_shaders::{FRAGMENT_SHADER, VERTEX_SHADER};
 use super::sample::Sample;
+use crate::animation::binding::{AnimationTargets, Binding};
+use crate::animation::player::AnimationPlayer;
+use crate::animation::timeline::Timeline;
+use crate::animation::tween::{spin_track, Curve};
+use crate::gl::camera::controller::CameraController;
+use crate::gl::camera::fly::FlyController;
+use crate::gl::camera::input::InputEvent;
+use crate::gl::camera::orbit::OrbitController;
+use crate::gl::camera::pan_zoom::PanZoomController;
+use crate::gl::camera::projection::Projection;
 use crate::gl::core::instance::GL;
+use crate::gl::culling::{CullingStats, FrustumCuller};
 use crate::gl::error::GLError;
+use crate::gl::lighting::light::Light;
+use crate::gl::lighting::light_buffer::LightBuffer;
+use crate::gl::lighting::shaders::{blinn_phong_fragment_shader, LIT_VERTEX_SHADER};
+use crate::gl::materials::material::Material;
 use crate::gl::mesh::Mesh;
+use crate::gl::picking::object_id::ObjectId;
+use crate::gl::picking::picker::{PickEvent, PickKind};
+use crate::gl::picking::picking_pass::PickingPass;
+use crate::gl::queue::render_queue::RenderQueue;
+use crate::gl::queue::render_stats::RenderStats;
 use crate::gl::shader::program::ShaderProgram;
 use crate::gl::shader::shader_type::ShaderType;
+use crate::gl::shadows::settings::ShadowSettings;
+use crate::gl::shadows::shadow_renderer::{ShadowRenderer, SHADOW_MAP_UNIFORM};
+use crate::gl::skybox::procedural_sky::ProceduralSky;
+use crate::gl::skybox::skybox_renderer::Skybox;
+use crate::gl::textures::texture::Texture;
 use crate::gl::{self, camera::Camera};
 use crate::samples::resources::cube_mesh::build_cube_mesh;
-use glm::{look_at, perspective, Vec3};
+use crate::samples::resources::plane_mesh::build_plane_mesh;
+use crate::scene::component::{Component, Renderable};
+use crate::scene::graph::{LightItem, RenderItem, RenderList, SceneGraph};
+use crate::scene::node::{Node, NodeId};
+use crate::scene::transform::Transform;
+use crate::utils::web;
+use glm::{Mat4x4, Vec2, Vec3};
+
+pub const CAMERA_QUERY_PARAM: &str = "camera";
+pub const FLY_CAMERA: &str = "fly";
+pub const PAN_ZOOM_CAMERA: &str = "pan-zoom";
+
+const GRID_HALF_SIZE: i32 = 4;
+
+const CUBE_SCALE: f32 = 0.25;
+
+const FIELD_OF_VIEW: f32 = std::f32::consts::FRAC_PI_3 * 2.0;
+const NEAR_PLANE: f32 = 0.1;
+const FAR_PLANE: f32 = 10.0;
+
+const CAMERA_DISTANCE: f32 = 3.0;
+const PAN_ZOOM_HALF_HEIGHT: f32 = GRID_HALF_SIZE as f32 + 1.0;
+
+const MAX_LIGHTS: usize = 8;
+
+const SHADOW_MAP_SIZE: i32 = 1024;
+const SHADOW_DISTANCE: f32 = FAR_PLANE;
+
+const GROUND_HALF_SIZE: f32 = GRID_HALF_SIZE as f32 + 1.0;
+const GROUND_HEIGHT: f32 = -0.5;
+const GROUND_COLOR: [f32; 3] = [0.55, 0.6, 0.55];
+
+const DIFFUSE_COLOR: [f32; 3] = [0.8, 0.8, 0.8];
+const SPECULAR_COLOR: [f32; 3] = [0.5, 0.5, 0.5];
+const AMBIENT_COLOR: [f32; 3] = [0.1, 0.15, 0.1];
+const SHININESS: f32 = 32.0;
+
+const SUN_DIRECTION: [f32; 3] = [-0.3, -0.5, -1.0];
+const SUN_COLOR: [f32; 3] = [1.0, 0.95, 0.85];
+const SKY_SUN_INTENSITY: f32 = 4.0;
+
+const ORBIT_LIGHT_RADIUS: f32 = 3.0;
+const ORBIT_LIGHT_HEIGHT: f32 = 1.0;
+
+const SPIN_PERIOD: f32 = 16.0 * std::f32::consts::PI;
+const LIGHT_ORBIT_PERIOD: f32 = SPIN_PERIOD / 4.0;
+
+const HIGHLIGHT_COLOR: [f32; 3] = [0.9, 0.4, 0.3];
+const HOVER_HIGHLIGHT: f32 = 0.4;
+const SELECT_HIGHLIGHT: f32 = 1.0;
 
 pub struct Cubes {
     gl: Rc<GL>,
-    mesh: Mesh,
-    shader: ShaderProgram,
+    scene: SceneGraph,
+    animation: AnimationPlayer,
+    lights: LightBuffer,
+    shadows: ShadowRenderer,
+    skybox: Skybox,
+    hovered_material: Rc<Material>,
+    selected_material: Rc<Material>,
     camera: Camera,
+    controller: Box<dyn CameraController>,
+    cubes: Vec<NodeId>,
+    carrier: NodeId,
+    hovered: Option<ObjectId>,
+    selected: Option<ObjectId>,
+    queue: RenderQueue,
+    culling_stats: CullingStats,
+    render_stats: RenderStats,
+}
+
+impl Cubes {
+    fn visible_items(&mut self, mut items: Vec<RenderItem>) -> Vec<RenderItem> {
+        let mut culler = FrustumCuller::new(&self.camera);
+        items.retain(|item| culler.is_mesh_visible(&item.renderable.mesh, &item.world));
+        self.culling_stats = culler.stats();
+        items
+    }
+
+    fn cube_of(&self, id: ObjectId) -> Option<NodeId> {
+        self.cubes
+            .iter()
+            .copied()
+            .find(|&cube| object_id(cube) == id)
+    }
+
+    fn selected_name(&self) -> &str {
+        self.selected
+            .and_then(|id| self.cube_of(id))
+            .and_then(|cube| self.scene.node(cube))
+            .map_or("none", |node| node.name.as_str())
+    }
+
+    fn click(&mut self, cube: NodeId) {
+        let carried = self.scene.node(cube).and_then(Node::parent) == Some(self.carrier);
+        if carried {
+            self.scene.remove_node(cube);
+            self.cubes.retain(|&other| other != cube);
+            self.selected = None;
+        } else {
+            self.scene.set_parent(cube, Some(self.carrier));
+        }
+    }
+
+    fn highlight_of(&self, id: ObjectId) -> Option<&Rc<Material>> {
+        if self.selected == Some(id) {
+            Some(&self.selected_material)
+        } else if self.hovered == Some(id) {
+            Some(&self.hovered_material)
+        } else {
+            None
+        }
+    }
+}
+
+fn lit_material(
+    gl: &Rc<GL>,
+    shader: &Rc<ShaderProgram>,
+    shadow_map: &Rc<Texture>,
+    diffuse_color: Vec3,
+) -> Rc<Material> {
+    Rc::new(
+        Material::new(gl, shader)
+            .with_param("u_material.diffuse_color", diffuse_color)
+            .with_param("u_material.specular_color", Vec3::from(SPECULAR_COLOR))
+            .with_param("u_material.ambient_color", Vec3::from(AMBIENT_COLOR))
+            .with_param("u_material.shininess", SHININESS)
+            .with_param(SHADOW_MAP_UNIFORM, Rc::clone(shadow_map)),
+    )
+}
+
+fn cube_material(
+    gl: &Rc<GL>,
+    shader: &Rc<ShaderProgram>,
+    shadow_map: &Rc<Texture>,
+    highlight: f32,
+) -> Rc<Material> {
+    let diffuse_color = Vec3::from(DIFFUSE_COLOR).lerp(&Vec3::from(HIGHLIGHT_COLOR), highlight);
+    lit_material(gl, shader, shadow_map, diffuse_color)
+}
+
+fn add_lights(scene: &mut SceneGraph) -> (NodeId, NodeId) {
+    let sun = scene.add_node("sun", Transform::identity(), None);
+    scene.add_component(
+        sun,
+        Component::Light(
+            Light::directional(Vec3::from(SUN_DIRECTION), Vec3::from(SUN_COLOR), 0.6)
+                .with_shadows(),
+        ),
+    );
+
+    let spot = scene.add_node(
+        "spot",
+        Transform::from_translation(Vec3::new(0.0, 0.0, 4.0)),
+        None,
+    );
+    scene.add_component(
+        spot,
+        Component::Light(
+            Light::spot(
+                Vec3::zeros(),
+                -Vec3::z(),
+                Vec3::new(0.4, 0.6, 1.0),
+                12.0,
+                10.0,
+                0.25,
+                0.4,
+            )
+            .with_shadows(),
+        ),
+    );
+
+    let pivot = scene.add_node("light_pivot", Transform::identity(), None);
+    let orbiting = scene.add_node(
+        "orbiting_light",
+        Transform::from_translation(Vec3::new(ORBIT_LIGHT_RADIUS, 0.0, ORBIT_LIGHT_HEIGHT)),
+        Some(pivot),
+    );
+    scene.add_component(
+        orbiting,
+        Component::Light(Light::point(
+            Vec3::zeros(),
+            Vec3::new(1.0, 0.6, 0.3),
+            8.0,
+            6.0,
+        )),
+    );
+    (pivot, orbiting)
+}
+
+fn motion(spinners: &[NodeId], light_pivot: NodeId) -> Timeline {
+    let spin = Curve::from(spin_track(&Vec3::y(), SPIN_PERIOD));
+    let mut motions: Vec<Timeline> = spinners
+        .iter()
+        .map(|&cube| Timeline::tween(Binding::NodeRotation(cube), spin.clone()).looped())
+        .collect();
+    motions.push(
+        Timeline::tween(
+            Binding::NodeRotation(light_pivot),
+            Curve::from(spin_track(&Vec3::z(), LIGHT_ORBIT_PERIOD)),
+        )
+        .looped(),
+    );
+    Timeline::parallel(motions)
+}
+
+fn object_id(node: NodeId) -> ObjectId {
+    ObjectId::from_index(node.index())
 }
 
 impl Sample for Cubes {
     fn try_new(gl: GL) -> Result<Self, GLError> {
         let gl = Rc::new(gl);
-        let mesh = build_cube_mesh(&gl)?;
-        let shader = ShaderProgram::builder(&gl)
-            .add_source(ShaderType::Vertex, VERTEX_SHADER.into())
-            .add_source(ShaderType::Fragment, FRAGMENT_SHADER.into())
-            .build()?;
-
-        let model = glm::Mat4::new_scaling(0.25);
-        let view = look_at(
-            &Vec3::new(0.0, 0.0, 3.0),
-            &Vec3::new(0.0, 0.0, 0.0),
-            &Vec3::new(0.0, 1.0, 0.0),
+        let mesh = Rc::new(build_cube_mesh(&gl)?);
+        let shadows = ShadowRenderer::try_new(
+            &gl,
+            ShadowSettings {
+                map_size: SHADOW_MAP_SIZE,
+                max_distance: SHADOW_DISTANCE,
+                ..ShadowSettings::default()
+            },
+        )?;
+        let shader = Rc::new(
+            ShaderProgram::builder(&gl)
+                .add_source(ShaderType::Vertex, LIT_VERTEX_SHADER.into())
+                .add_source(
+                    ShaderType::Fragment,
+                    blinn_phong_fragment_shader(MAX_LIGHTS, Some(shadows.max_shadow_maps())),
+                )
+                .build()?,
+        );
+        let lights = LightBuffer::try_new(&gl, MAX_LIGHTS)?;
+        lights.attach(&shader);
+        shadows.attach(&shader);
+        let shadow_map = shadows.shadow_map();
+        let material = cube_material(&gl, &shader, shadow_map, 0.0);
+
+        let mut scene = SceneGraph::new();
+        let mut cubes = vec![];
+        for i in -GRID_HALF_SIZE..=GRID_HALF_SIZE {
+            for j in -GRID_HALF_SIZE..=GRID_HALF_SIZE {
+                let translation = Vec3::new(i as f32, j as f32, 0.0);
+                let anchor =
+                    scene.add_node("anchor", Transform::from_translation(translation), None);
+                let cube = scene.add_node(
+                    &format!("cube_{}_{}", i, j),
+                    Transform::identity().with_uniform_scale(CUBE_SCALE),
+                    Some(anchor),
+                );
+                scene.add_component(
+                    cube,
+                    Component::Renderable(Renderable {
+                        mesh: Rc::clone(&mesh),
+                        material: Rc::clone(&material),
+                    }),
+                );
+                cubes.push(cube);
+            }
+        }
+
+        let ground = scene.add_node(
+            "ground",
+            Transform::from_translation(Vec3::new(0.0, 0.0, GROUND_HEIGHT)),
+            None,
+        );
+        scene.add_component(
+            ground,
+            Component::Renderable(Renderable {
+                mesh: Rc::new(build_plane_mesh(&gl, GROUND_HALF_SIZE)?),
+                material: lit_material(&gl, &shader, shadow_map, Vec3::from(GROUND_COLOR)),
+            }),
         );
-        let projection = perspective(0.74, std::f32::consts::FRAC_PI_3 * 2.0, 0.01, 10.0);
-        let camera = Camera {
-            model,
-            view,
-            projection,
+
+        let (light_pivot, orbiting_light) = add_lights(&mut scene);
+
+        let controller: Box<dyn CameraController> = match web::query_param(CAMERA_QUERY_PARAM)
+            .as_deref()
+        {
+            Some(FLY_CAMERA) => Box::new(FlyController::new(Vec3::new(0.0, 0.0, CAMERA_DISTANCE))),
+            Some(PAN_ZOOM_CAMERA) => {
+                Box::new(PanZoomController::new(Vec2::zeros(), PAN_ZOOM_HALF_HEIGHT))
+            }
+            _ => Box::new(OrbitController::new(Vec3::zeros(), CAMERA_DISTANCE)),
         };
+        let mut camera = Camera::new(Projection::Perspective {
+            fov_y: FIELD_OF_VIEW,
+            near: NEAR_PLANE,
+            far: FAR_PLANE,
+        });
+        controller.apply(&mut camera);
+
+        let sky = ProceduralSky::default().with_up(Vec3::z()).with_sun(
+            -Vec3::from(SUN_DIRECTION),
+            Vec3::from(SUN_COLOR) * SKY_SUN_INTENSITY,
+        );
 
         Ok(Self {
+            queue: RenderQueue::new(&gl),
+            skybox: Skybox::procedural(&gl, sky)?,
+            hovered_material: cube_material(&gl, &shader, shadow_map, HOVER_HIGHLIGHT),
+            selected_material: cube_material(&gl, &shader, shadow_map, SELECT_HIGHLIGHT),
             gl,
-            mesh,
-            shader,
+            scene,
+            animation: AnimationPlayer::new(motion(&cubes, light_pivot)),
+            lights,
+            shadows,
             camera,
+            controller,
+            cubes,
+            carrier: orbiting_light,
+            hovered: None,
+            selected: None,
+            culling_stats: CullingStats::default(),
+            render_stats: RenderStats::default(),
         })
     }
 
     fn update(&mut self, d_time: f64) -> Result<(), GLError> {
-        self.camera.model = glm::rotate(&self.camera.model, d_time as f32 / 8000.0, &glm::Vec3::y());
+        self.animation.update(d_time);
+        self.animation
+            .apply(&mut AnimationTargets::new().with_scene(&mut self.scene));
+
+        self.controller.update(d_time);
+        self.controller.apply(&mut self.camera);
+        Ok(())
+    }
+
+    fn on_input(&mut self, event: &InputEvent) -> Result<(), GLError> {
+        self.camera.handle_event(event);
+        self.controller.handle_event(event);
         Ok(())
     }
 
     fn render(&mut self) -> Result<(), GLError> {
         let width = self.gl.drawing_buffer_width();
         let height = self.gl.drawing_buffer_height();
-        self.gl.viewport(0, 0, width, height);
 
-        let aspect = width as f32 / height as f32;
-        self.camera.projection =
-            glm::perspective(aspect, std::f32::consts::FRAC_PI_3 * 2.0, 0.1, 10.0);
+        let RenderList {
+            renderables,
+            lights,
+            ..
+        } = self.scene.collect();
+
+        let lights: Vec<Light> = lights.iter().map(LightItem::world_light).collect();
+        let shadow_layers = self.shadows.prepare(&self.camera, &lights);
+        let casters: Vec<(Rc<Mesh>, Mat4x4)> = renderables
+            .iter()
+            .map(|item| (Rc::clone(&item.renderable.mesh), item.world))
+            .collect();
+        self.shadows.render(&casters)?;
+        self.shadows.bind();
+        self.lights.upload_with_shadows(&lights, &shadow_layers);
+        self.lights.bind();
 
-        self.gl.clear(gl::COLOR_BUFFER_BIT);
+        self.gl.viewport(0, 0, width, height);
+        self.gl
+            .clear_depth(self.camera.projection().depth_clear_value());
         self.gl.clear(gl::DEPTH_BUFFER_BIT);
-        self.gl.clear_color(0.8, 0.9, 0.9, 1.0);
-
-        let base_model = self.camera.model;
-        for i in -4..=4 {
-            for j in -4..=4 {
-                let translation = glm::vec3(i as f32, j as f32, 0.0);
-                self.camera.model = glm::Mat4::new_translation(&translation) * base_model;
-                self.mesh.render(&self.shader, &self.camera);
-            }
+
+        for item in self.visible_items(renderables) {
+            let RenderItem {
+                node,
+                world,
+                renderable: Renderable { mesh, material },
+            } = item;
+
+            let material = self
+                .highlight_of(object_id(node))
+                .cloned()
+                .unwrap_or(material);
+            self.queue.push(&mesh, &material, world);
         }
-        self.camera.model = base_model;
+        let opaque_stats = self.queue.submit_opaque(&self.camera);
+        self.skybox.render(&self.camera);
+        let transparent_stats = self.queue.submit_transparent(&self.camera);
+        self.render_stats = opaque_stats.merge(&transparent_stats);
 
         Ok(())
     }
+
+    fn render_picking(&mut self, pass: &PickingPass) -> Result<(), GLError> {
+        let renderables = self.scene.collect().renderables;
+        for item in self.visible_items(renderables) {
+            let camera = self.camera.with_model(item.world);
+            pass.draw(&item.renderable.mesh, &camera, object_id(item.node));
+        }
+        Ok(())
+    }
+
+    fn on_pick(&mut self, event: &PickEvent) -> Result<(), GLError> {
+        match event.kind {
+            PickKind::Hover => self.hovered = event.object,
+            PickKind::Click => {
+                let clicked = event.object.filter(|&id| self.selected == Some(id));
+                if let Some(cube) = clicked.and_then(|id| self.cube_of(id)) {
+                    self.click(cube);
+                } else {
+                    self.selected = event.object;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn stats(&self) -> Option<String> {
+        Some(format!(
+            "drawn:{} culled:{} draws:{} state changes:{} selected:{}",
+            self.culling_stats.drawn,
+            self.culling_stats.culled,
+            self.render_stats.draw_calls,
+            self.render_stats.state_changes(),
+            self.selected_name(),
+        ))
+    }
 }
@@ -0,0 +1,263 @@
+use std::rc::Rc;
+
+use super::resources::cube_mesh::build_cube_mesh;
+use super::resources::plane_mesh::build_plane_mesh;
+use super::sample::Sample;
+use crate::gl;
+use crate::gl::camera::controller::CameraController;
+use crate::gl::camera::input::InputEvent;
+use crate::gl::camera::orbit::OrbitController;
+use crate::gl::camera::projection::Projection;
+use crate::gl::camera::Camera;
+use crate::gl::core::instance::GL;
+use crate::gl::deferred::deferred_renderer::{DeferredRenderer, RenderPath};
+use crate::gl::error::GLError;
+use crate::gl::lighting::light::Light;
+use crate::gl::lighting::light_buffer::LightBuffer;
+use crate::gl::lighting::shaders::{blinn_phong_fragment_shader, LIT_VERTEX_SHADER};
+use crate::gl::materials::material::Material;
+use crate::gl::materials::pipeline_state::{BlendMode, PipelineState};
+use crate::gl::mesh::Mesh;
+use crate::gl::queue::render_queue::RenderQueue;
+use crate::gl::queue::render_stats::RenderStats;
+use crate::gl::shader::program::ShaderProgram;
+use crate::gl::shader::shader_type::ShaderType;
+use crate::utils::web;
+use glm::{Mat4x4, Vec3};
+
+pub const RENDER_PATH_QUERY_PARAM: &str = "path";
+pub const FORWARD_PATH: &str = "forward";
+pub const SSAO_QUERY_PARAM: &str = "ssao";
+pub const SSAO_OFF: &str = "off";
+
+const MAX_FORWARD_LIGHTS: usize = 8;
+const POINT_LIGHTS_COUNT: usize = 64;
+
+const GRID_HALF_SIZE: i32 = 6;
+const CUBE_SCALE: f32 = 0.3;
+const GROUND_HALF_SIZE: f32 = GRID_HALF_SIZE as f32 + 2.0;
+const GROUND_HEIGHT: f32 = -0.3;
+
+const LIGHT_RANGE: f32 = 2.5;
+const LIGHT_INTENSITY: f32 = 4.0;
+const LIGHT_HEIGHT: f32 = 0.4;
+const LIGHT_ORBIT_SPEED: f32 = 0.0003;
+const MARKER_SCALE: f32 = 0.05;
+
+const SPECULAR_COLOR: [f32; 3] = [0.4, 0.4, 0.4];
+const AMBIENT_COLOR: [f32; 3] = [0.05, 0.05, 0.08];
+const SHININESS: f32 = 24.0;
+
+const FIELD_OF_VIEW: f32 = std::f32::consts::FRAC_PI_3;
+const NEAR_PLANE: f32 = 0.1;
+const FAR_PLANE: f32 = 100.0;
+
+struct Prop {
+    mesh: Rc<Mesh>,
+    world: Mat4x4,
+    forward: Rc<Material>,
+    deferred: Rc<Material>,
+}
+
+pub struct DeferredLights {
+    gl: Rc<GL>,
+    render_path: RenderPath,
+    deferred: DeferredRenderer,
+    forward_lights: LightBuffer,
+    props: Vec<Prop>,
+    marker_mesh: Rc<Mesh>,
+    marker_material: Rc<Material>,
+    lights: Vec<Light>,
+    light_angle: f32,
+    camera: Camera,
+    orbit: OrbitController,
+    queue: RenderQueue,
+    render_stats: RenderStats,
+}
+
+impl DeferredLights {
+    fn push_items(&mut self) {
+        for prop in self.props.iter() {
+            let material = match self.render_path {
+                RenderPath::Forward => &prop.forward,
+                RenderPath::Deferred => &prop.deferred,
+            };
+            self.queue.push(&prop.mesh, material, prop.world);
+        }
+        for light in self.lights.iter().filter(|light| light.range().is_some()) {
+            let world =
+                glm::translation(&light.position) * glm::scaling(&Vec3::repeat(MARKER_SCALE));
+            self.queue
+                .push(&self.marker_mesh, &self.marker_material, world);
+        }
+    }
+}
+
+fn blinn_phong_material(
+    gl: &Rc<GL>,
+    shader: &Rc<ShaderProgram>,
+    diffuse_color: Vec3,
+    ambient_color: Vec3,
+) -> Material {
+    Material::new(gl, shader)
+        .with_param("u_material.diffuse_color", diffuse_color)
+        .with_param("u_material.specular_color", Vec3::from(SPECULAR_COLOR))
+        .with_param("u_material.ambient_color", ambient_color)
+        .with_param("u_material.shininess", SHININESS)
+}
+
+fn light_color(index: usize) -> Vec3 {
+    let hue = index as f32 / POINT_LIGHTS_COUNT as f32 * std::f32::consts::TAU;
+    let channel = |offset: f32| 0.5 + 0.5 * (hue + offset).cos();
+    Vec3::new(
+        channel(0.0),
+        channel(std::f32::consts::TAU / 3.0),
+        channel(2.0 * std::f32::consts::TAU / 3.0),
+    )
+}
+
+fn point_lights(angle: f32) -> Vec<Light> {
+    (0..POINT_LIGHTS_COUNT)
+        .map(|i| {
+            let ring = 1.0 + (i % 4) as f32 * GRID_HALF_SIZE as f32 / 4.0;
+            let direction = if i % 2 == 0 { 1.0 } else { -1.0 };
+            let phase = i as f32 / POINT_LIGHTS_COUNT as f32 * std::f32::consts::TAU;
+            let theta = phase + angle * direction / ring;
+            let position = Vec3::new(ring * theta.cos(), ring * theta.sin(), LIGHT_HEIGHT);
+            Light::point(position, light_color(i), LIGHT_INTENSITY, LIGHT_RANGE)
+        })
+        .collect()
+}
+
+fn scene_lights(angle: f32) -> Vec<Light> {
+    let moon = Light::directional(Vec3::new(0.2, 0.4, -1.0), Vec3::new(0.6, 0.7, 1.0), 0.15);
+    std::iter::once(moon).chain(point_lights(angle)).collect()
+}
+
+impl Sample for DeferredLights {
+    fn try_new(gl: GL) -> Result<Self, GLError> {
+        let gl = Rc::new(gl);
+        let render_path = match web::query_param(RENDER_PATH_QUERY_PARAM).as_deref() {
+            Some(FORWARD_PATH) => RenderPath::Forward,
+            _ => RenderPath::Deferred,
+        };
+
+        let mut deferred = DeferredRenderer::try_new(&gl, MAX_FORWARD_LIGHTS)?;
+        deferred.set_ssao_enabled(web::query_param(SSAO_QUERY_PARAM).as_deref() != Some(SSAO_OFF));
+        let forward_lights = LightBuffer::try_new(&gl, MAX_FORWARD_LIGHTS)?;
+        let shader = Rc::new(
+            ShaderProgram::builder(&gl)
+                .add_source(ShaderType::Vertex, LIT_VERTEX_SHADER.into())
+                .add_source(
+                    ShaderType::Fragment,
+                    blinn_phong_fragment_shader(MAX_FORWARD_LIGHTS, None),
+                )
+                .build()?,
+        );
+        forward_lights.attach(&shader);
+
+        let ambient = Vec3::from(AMBIENT_COLOR);
+        let prop = |mesh: &Rc<Mesh>, world: Mat4x4, diffuse: Vec3| {
+            let forward = blinn_phong_material(&gl, &shader, diffuse, ambient);
+            Prop {
+                mesh: Rc::clone(mesh),
+                world,
+                deferred: Rc::new(deferred.material(&forward)),
+                forward: Rc::new(forward),
+            }
+        };
+
+        let mut props = vec![];
+        let ground = Rc::new(build_plane_mesh(&gl, GROUND_HALF_SIZE)?);
+        let ground_world = glm::translation(&Vec3::new(0.0, 0.0, GROUND_HEIGHT));
+        props.push(prop(&ground, ground_world, Vec3::new(0.5, 0.5, 0.5)));
+
+        let cube = Rc::new(build_cube_mesh(&gl)?);
+        for i in -GRID_HALF_SIZE..=GRID_HALF_SIZE {
+            for j in -GRID_HALF_SIZE..=GRID_HALF_SIZE {
+                let translation = Vec3::new(i as f32, j as f32, 0.0);
+                let world =
+                    glm::translation(&translation) * glm::scaling(&Vec3::repeat(CUBE_SCALE));
+                props.push(prop(&cube, world, Vec3::new(0.8, 0.8, 0.8)));
+            }
+        }
+
+        let marker_material =
+            blinn_phong_material(&gl, &shader, Vec3::new(1.0, 0.95, 0.8), Vec3::repeat(1.0))
+                .with_state(PipelineState::transparent(BlendMode::Additive));
+
+        let orbit = OrbitController::new(Vec3::zeros(), 12.0);
+        let mut camera = Camera::new(Projection::Perspective {
+            fov_y: FIELD_OF_VIEW,
+            near: NEAR_PLANE,
+            far: FAR_PLANE,
+        });
+        camera.view = orbit.view();
+
+        Ok(Self {
+            queue: RenderQueue::new(&gl),
+            gl,
+            render_path,
+            deferred,
+            forward_lights,
+            props,
+            marker_mesh: cube,
+            marker_material: Rc::new(marker_material),
+            lights: scene_lights(0.0),
+            light_angle: 0.0,
+            camera,
+            orbit,
+            render_stats: RenderStats::default(),
+        })
+    }
+
+    fn update(&mut self, d_time: f64) -> Result<(), GLError> {
+        self.light_angle += d_time as f32 * LIGHT_ORBIT_SPEED;
+        self.lights = scene_lights(self.light_angle);
+
+        self.orbit.update(d_time);
+        self.orbit.apply(&mut self.camera);
+        Ok(())
+    }
+
+    fn on_input(&mut self, event: &InputEvent) -> Result<(), GLError> {
+        self.camera.handle_event(event);
+        self.orbit.handle_event(event);
+        Ok(())
+    }
+
+    fn render(&mut self) -> Result<(), GLError> {
+        let width = self.gl.drawing_buffer_width();
+        let height = self.gl.drawing_buffer_height();
+        self.gl.clear_color(0.02, 0.02, 0.04, 1.0);
+        self.gl
+            .clear_depth(self.camera.projection().depth_clear_value());
+
+        self.push_items();
+        self.render_stats = match self.render_path {
+            RenderPath::Deferred => {
+                self.deferred
+                    .render(&self.camera, &self.lights, &mut self.queue, None)?
+            }
+            RenderPath::Forward => {
+                self.gl.viewport(0, 0, width, height);
+                self.gl.clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
+                let count = self.lights.len().min(MAX_FORWARD_LIGHTS);
+                self.forward_lights.upload(&self.lights[..count]);
+                self.forward_lights.bind();
+                self.queue.submit(&self.camera)
+            }
+        };
+        Ok(())
+    }
+
+    fn stats(&self) -> Option<String> {
+        Some(format!(
+            "path:{:?} lights:{} draws:{} state changes:{}",
+            self.render_path,
+            self.lights.len(),
+            self.render_stats.draw_calls,
+            self.render_stats.state_changes(),
+        ))
+    }
+}
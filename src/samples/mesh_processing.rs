@@ -0,0 +1,326 @@
+use std::rc::Rc;
+
+use super::sample::Sample;
+use crate::gl;
+use crate::gl::camera::controller::CameraController;
+use crate::gl::camera::input::{InputEvent, MouseButton};
+use crate::gl::camera::orbit::OrbitController;
+use crate::gl::camera::projection::Projection;
+use crate::gl::camera::Camera;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::lighting::light::Light;
+use crate::gl::lighting::light_buffer::LightBuffer;
+use crate::gl::lighting::shaders::{vertex_color_fragment_shader, LIT_VERTEX_SHADER};
+use crate::gl::lod_mesh::LodMesh;
+use crate::gl::materials::material::Material;
+use crate::gl::mesh::{Colors, Faces, Mesh, Vertices};
+use crate::gl::primitives::icosphere::icosphere;
+use crate::gl::primitives::torus::torus;
+use crate::gl::shader::program::ShaderProgram;
+use crate::gl::shader::shader_type::ShaderType;
+use crate::math::bvh::Bvh;
+use crate::math::compute_normals::{compute_weighted_normals, NormalWeighting};
+use crate::math::mesh_processing::geodesic::HeatGeodesics;
+use crate::math::mesh_processing::parameterization::harmonic_parameterization;
+use crate::math::mesh_processing::scalar_field::colorize;
+use crate::math::mesh_processing::smoothing::{laplacian_smooth, taubin_smooth};
+use crate::math::mesh_processing::weld::{remove_unreferenced, weld};
+use crate::math::random::Random;
+use glm::Vec3;
+
+const MAX_LIGHTS: usize = 2;
+const AMBIENT: f32 = 0.25;
+
+const SPHERE_SUBDIVISIONS: usize = 4;
+const NORMAL_WEIGHTING: NormalWeighting = NormalWeighting::Area;
+const WELD_EPSILON: f32 = 1e-4;
+const NOISE_AMPLITUDE: f32 = 0.06;
+const NOISE_SEED: u32 = 7;
+const CAP_CUT_HEIGHT: f32 = -0.4;
+
+const TAUBIN_LAMBDA: f32 = 0.5;
+const TAUBIN_MU: f32 = -0.53;
+const IMPLICIT_TIME_STEP: f32 = 2e-3;
+const SMOOTHING_INTERVAL: f64 = 150.0;
+const SMOOTHING_STEPS: usize = 24;
+const GEODESIC_ISOLINES: usize = 12;
+const CHECKER_SIZE: f32 = 8.0;
+
+const MESH_OFFSET: f32 = 1.4;
+const CLICK_DISTANCE: f32 = 4.0;
+
+const LOD_LEVELS: [(f32, f32); 3] = [(0.5, 240.0), (0.2, 120.0), (0.05, 50.0)];
+const LOD_INSTANCES: usize = 8;
+const LOD_SPACING: f32 = 3.0;
+const LOD_ROW_HEIGHT: f32 = -1.6;
+const LOD_COLOR: [f32; 3] = [0.9, 0.6, 0.3];
+
+const FIELD_OF_VIEW: f32 = std::f32::consts::FRAC_PI_4;
+const NEAR_PLANE: f32 = 0.1;
+const FAR_PLANE: f32 = 100.0;
+
+pub struct MeshProcessing {
+    gl: Rc<GL>,
+    noisy_vertices: Vertices,
+    faces: Faces,
+    geodesics: HeatGeodesics,
+    bvh: Bvh,
+    source: usize,
+    press: Option<(f32, f32)>,
+    smoothing_steps: usize,
+    smoothing_time: f64,
+    implicit_smoothing: bool,
+    sphere: Mesh,
+    cap: Mesh,
+    lod: LodMesh,
+    material: Material,
+    lights: LightBuffer,
+    camera: Camera,
+    orbit: OrbitController,
+}
+
+fn noisy_sphere() -> (Vertices, Faces) {
+    let builder = icosphere(1.0, SPHERE_SUBDIVISIONS);
+    let welded = weld(builder.vertices(), builder.faces(), WELD_EPSILON);
+
+    let mut random = Random::new(NOISE_SEED);
+    let mut vertices = welded.vertices;
+    for mut vertex in vertices.column_iter_mut() {
+        let scale = 1.0 + random.range(-NOISE_AMPLITUDE, NOISE_AMPLITUDE);
+        vertex.copy_from(&(vertex.clone_owned() * scale));
+    }
+    (vertices, welded.faces)
+}
+
+fn sphere_cap() -> (Vertices, Faces) {
+    let builder = icosphere(1.0, SPHERE_SUBDIVISIONS);
+    let welded = weld(builder.vertices(), builder.faces(), WELD_EPSILON);
+
+    let kept: Vec<usize> = welded
+        .faces
+        .column_iter()
+        .enumerate()
+        .filter(|(_, face)| {
+            let height: f32 = face.iter().map(|&v| welded.vertices[(1, v as usize)]).sum();
+            height / 3.0 > CAP_CUT_HEIGHT
+        })
+        .map(|(index, _)| index)
+        .collect();
+    let cap = remove_unreferenced(&welded.vertices, &welded.faces.select_columns(&kept));
+    (cap.vertices, cap.faces)
+}
+
+fn checker_colors(vertices: &Vertices, faces: &Faces) -> Result<Colors, GLError> {
+    let tex_coords = harmonic_parameterization(vertices, faces)?;
+    let mut colors = Colors::zeros(tex_coords.ncols());
+    for (uv, mut color) in tex_coords.column_iter().zip(colors.column_iter_mut()) {
+        let cell = (uv[0] * CHECKER_SIZE).floor() + (uv[1] * CHECKER_SIZE).floor();
+        let shade = if cell as i32 % 2 == 0 { 1.0 } else { 0.6 };
+        color.copy_from(&(Vec3::new(uv[0], uv[1], 0.8) * shade));
+    }
+    Ok(colors)
+}
+
+fn sphere_offset() -> Vec3 {
+    Vec3::new(-MESH_OFFSET, 0.0, 0.0)
+}
+
+impl MeshProcessing {
+    fn next_source(&self) -> usize {
+        let vertices_count = self.geodesics.vertices_count();
+        (self.source + vertices_count / 7) % vertices_count
+    }
+
+    fn pick_source(&self, x: f32, y: f32) -> Option<usize> {
+        let camera = self.camera.with_model(glm::translation(&sphere_offset()));
+        let hit = self.bvh.nearest_hit(&camera.model_ray(x, y)?)?;
+        Some(self.faces[(hit.barycentric.imax(), hit.face)] as usize)
+    }
+
+    fn reset(&mut self, source: usize) -> Result<(), GLError> {
+        self.source = source;
+        self.smoothing_steps = 0;
+        self.smoothing_time = 0.0;
+
+        let distances = self.geodesics.distances(self.source)?;
+        let noisy_vertices = &self.noisy_vertices;
+        let faces = &self.faces;
+        self.sphere.update(|vertices, normals, _| {
+            vertices.copy_from(noisy_vertices);
+            *normals = compute_weighted_normals(vertices, faces, NORMAL_WEIGHTING);
+        });
+        self.sphere
+            .update_colors(|colors| *colors = colorize(&distances, GEODESIC_ISOLINES));
+        Ok(())
+    }
+
+    fn smooth_step(&mut self) -> Result<(), GLError> {
+        let faces = &self.faces;
+        let smoothed = if self.implicit_smoothing {
+            laplacian_smooth(self.sphere.vertices(), faces, IMPLICIT_TIME_STEP, 1)?
+        } else {
+            taubin_smooth(self.sphere.vertices(), faces, TAUBIN_LAMBDA, TAUBIN_MU, 1)
+        };
+        self.sphere.update(|vertices, normals, _| {
+            *vertices = smoothed;
+            *normals = compute_weighted_normals(vertices, faces, NORMAL_WEIGHTING);
+        });
+        self.smoothing_steps += 1;
+        Ok(())
+    }
+}
+
+impl Sample for MeshProcessing {
+    fn try_new(gl: GL) -> Result<Self, GLError> {
+        let gl = Rc::new(gl);
+
+        let (noisy_vertices, faces) = noisy_sphere();
+        let geodesics = HeatGeodesics::try_new(&noisy_vertices, &faces)?;
+        let bvh = Bvh::new(&noisy_vertices, &faces);
+        let source = 0;
+        let distances = geodesics.distances(source)?;
+        let sphere = Mesh::new_builder(noisy_vertices.clone(), faces.clone())
+            .build_weighted_normals(NORMAL_WEIGHTING)
+            .add_colors(colorize(&distances, GEODESIC_ISOLINES))
+            .build(&gl)?;
+
+        let (cap_vertices, cap_faces) = sphere_cap();
+        let cap_colors = checker_colors(&cap_vertices, &cap_faces)?;
+        let cap = Mesh::new_builder(cap_vertices, cap_faces)
+            .build_normals()
+            .add_colors(cap_colors)
+            .build(&gl)?;
+
+        let ring = torus(0.5, 0.2, 96, 48);
+        let ring_colors = Colors::from_fn(ring.vertices().ncols(), |row, _| LOD_COLOR[row]);
+        let lod = LOD_LEVELS
+            .iter()
+            .fold(
+                LodMesh::builder(ring.add_colors(ring_colors)),
+                |builder, &(face_ratio, max_screen_size)| {
+                    builder.add_level(face_ratio, max_screen_size)
+                },
+            )
+            .build(&gl)?;
+
+        let mut lights = LightBuffer::try_new(&gl, MAX_LIGHTS)?;
+        lights.upload(&[
+            Light::directional(Vec3::new(-0.5, -0.7, -0.6), Vec3::new(1.0, 0.95, 0.9), 0.8),
+            Light::directional(Vec3::new(0.6, -0.2, 0.7), Vec3::new(0.4, 0.5, 0.7), 0.3),
+        ]);
+
+        let program = Rc::new(
+            ShaderProgram::builder(&gl)
+                .add_source(ShaderType::Vertex, LIT_VERTEX_SHADER.into())
+                .add_source(
+                    ShaderType::Fragment,
+                    vertex_color_fragment_shader(MAX_LIGHTS),
+                )
+                .build()?,
+        );
+        lights.attach(&program);
+        let material = Material::new(&gl, &program).with_param("u_ambient", AMBIENT);
+
+        let orbit = OrbitController::new(Vec3::zeros(), 6.0);
+        let mut camera = Camera::new(Projection::Perspective {
+            fov_y: FIELD_OF_VIEW,
+            near: NEAR_PLANE,
+            far: FAR_PLANE,
+        });
+        camera.view = orbit.view();
+
+        Ok(Self {
+            gl,
+            noisy_vertices,
+            faces,
+            geodesics,
+            bvh,
+            source,
+            press: None,
+            smoothing_steps: 0,
+            smoothing_time: 0.0,
+            implicit_smoothing: false,
+            sphere,
+            cap,
+            lod,
+            material,
+            lights,
+            camera,
+            orbit,
+        })
+    }
+
+    fn update(&mut self, d_time: f64) -> Result<(), GLError> {
+        self.smoothing_time += d_time;
+        if self.smoothing_time >= SMOOTHING_INTERVAL {
+            self.smoothing_time = 0.0;
+            if self.smoothing_steps < SMOOTHING_STEPS {
+                self.smooth_step()?;
+            } else {
+                self.implicit_smoothing = !self.implicit_smoothing;
+                self.reset(self.next_source())?;
+            }
+        }
+
+        self.orbit.update(d_time);
+        self.orbit.apply(&mut self.camera);
+        Ok(())
+    }
+
+    fn on_input(&mut self, event: &InputEvent) -> Result<(), GLError> {
+        self.camera.handle_event(event);
+        self.orbit.handle_event(event);
+
+        match *event {
+            InputEvent::PointerDown {
+                button: MouseButton::Left,
+                x,
+                y,
+            } => self.press = Some((x, y)),
+            InputEvent::PointerUp {
+                button: MouseButton::Left,
+                x,
+                y,
+            } => {
+                let clicked = self.press.take().is_some_and(|(press_x, press_y)| {
+                    (x - press_x).hypot(y - press_y) < CLICK_DISTANCE
+                });
+                if let Some(source) = clicked.then(|| self.pick_source(x, y)).flatten() {
+                    self.reset(source)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn render(&mut self) -> Result<(), GLError> {
+        let width = self.gl.drawing_buffer_width();
+        let height = self.gl.drawing_buffer_height();
+
+        self.gl.viewport(0, 0, width, height);
+        self.gl.clear_color(0.1, 0.1, 0.12, 1.0);
+        self.gl
+            .clear_depth(self.camera.projection().depth_clear_value());
+        self.gl.clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
+
+        self.lights.bind();
+        self.sphere.render(
+            &self.material,
+            &self.camera.with_model(glm::translation(&sphere_offset())),
+        );
+        self.cap.render(
+            &self.material,
+            &self.camera.with_model(glm::translation(&-sphere_offset())),
+        );
+        for instance in 0..LOD_INSTANCES {
+            let position = Vec3::new(0.0, LOD_ROW_HEIGHT, -(instance as f32) * LOD_SPACING);
+            self.lod.render(
+                &self.material,
+                &self.camera.with_model(glm::translation(&position)),
+            );
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,10 @@
+pub mod blend_shapes;
 pub mod cubes;
+pub mod deferred_lights;
+pub mod mesh_processing;
+pub mod model_viewer;
+pub mod particles;
 pub mod sample;
+pub mod skinning;
 
 mod resources;
@@ -0,0 +1,202 @@
+use std::rc::Rc;
+
+use super::sample::Sample;
+use crate::gl::camera::controller::CameraController;
+use crate::gl::camera::input::InputEvent;
+use crate::gl::camera::orbit::OrbitController;
+use crate::gl::camera::projection::Projection;
+use crate::gl::camera::Camera;
+use crate::gl::core::instance::GL;
+use crate::gl::culling::FrustumCuller;
+use crate::gl::error::GLError;
+use crate::gl::ibl::environment::{Environment, EnvironmentSettings};
+use crate::gl::ibl::hdr_image::HdrImage;
+use crate::gl::lighting::light::Light;
+use crate::gl::lighting::light_buffer::LightBuffer;
+use crate::gl::pbr::pbr_material::PbrMaterial;
+use crate::gl::pbr::pbr_pipeline::PbrPipeline;
+use crate::gl::postprocess::post_chain::PostProcessChain;
+use crate::gl::primitives::sphere::uv_sphere;
+use crate::gl::queue::render_queue::RenderQueue;
+use crate::gl::queue::render_stats::RenderStats;
+use crate::gl::skybox::skybox_renderer::{SkySource, Skybox};
+use crate::scene::component::{Component, Renderable};
+use crate::scene::graph::{LightItem, RenderList, SceneGraph};
+use crate::scene::transform::Transform;
+use glm::Vec3;
+
+const MAX_LIGHTS: usize = 4;
+
+const GRID_SIZE: usize = 7;
+const SPHERE_SPACING: f32 = 1.25;
+const SPHERE_SEGMENTS: usize = 48;
+const SPHERE_RINGS: usize = 24;
+
+const BASE_COLOR: [f32; 3] = [0.9, 0.2, 0.15];
+
+const SKY_WIDTH: usize = 256;
+const SKY_HEIGHT: usize = 128;
+const ENVIRONMENT_INTENSITY: f32 = 1.0;
+
+const FIELD_OF_VIEW: f32 = std::f32::consts::FRAC_PI_4;
+const NEAR_PLANE: f32 = 0.1;
+const FAR_PLANE: f32 = 100.0;
+
+pub struct ModelViewer {
+    gl: Rc<GL>,
+    scene: SceneGraph,
+    camera: Camera,
+    orbit: OrbitController,
+    lights: LightBuffer,
+    queue: RenderQueue,
+    post: PostProcessChain,
+    skybox: Skybox,
+    render_stats: RenderStats,
+}
+
+fn add_material_grid(
+    gl: &Rc<GL>,
+    scene: &mut SceneGraph,
+    pbr: &PbrPipeline,
+) -> Result<(), GLError> {
+    let mesh = Rc::new(
+        uv_sphere(1.0, SPHERE_SEGMENTS, SPHERE_RINGS)
+            .build_tangents()
+            .build(gl)?,
+    );
+    let offset = (GRID_SIZE - 1) as f32 * SPHERE_SPACING * 0.5;
+
+    for row in 0..GRID_SIZE {
+        for column in 0..GRID_SIZE {
+            let metallic = row as f32 / (GRID_SIZE - 1) as f32;
+            let roughness = column as f32 / (GRID_SIZE - 1) as f32;
+            let material = pbr.material(&PbrMaterial::new(
+                Vec3::from(BASE_COLOR),
+                metallic,
+                roughness,
+            ));
+
+            let translation = Vec3::new(
+                column as f32 * SPHERE_SPACING - offset,
+                row as f32 * SPHERE_SPACING - offset,
+                0.0,
+            );
+            let node = scene.add_node("sphere", Transform::from_translation(translation), None);
+            scene.add_component(
+                node,
+                Component::Renderable(Renderable {
+                    mesh: Rc::clone(&mesh),
+                    material: Rc::new(material),
+                }),
+            );
+        }
+    }
+    Ok(())
+}
+
+impl Sample for ModelViewer {
+    fn try_new(gl: GL) -> Result<Self, GLError> {
+        let gl = Rc::new(gl);
+
+        let sun_direction = Vec3::new(-0.4, -0.6, -0.7);
+        let sky = HdrImage::gradient_sky(SKY_WIDTH, SKY_HEIGHT, &-sun_direction);
+        let environment = Environment::bake(&gl, &sky, &EnvironmentSettings::default())?;
+        let skybox = Skybox::try_new(&gl, SkySource::Cubemap(Rc::clone(environment.cubemap())))?;
+
+        let lights = LightBuffer::try_new(&gl, MAX_LIGHTS)?;
+        let mut pbr = PbrPipeline::try_new(&gl, &lights)?;
+        pbr.set_environment(Some(Rc::new(environment)), ENVIRONMENT_INTENSITY);
+
+        let mut scene = SceneGraph::new();
+        add_material_grid(&gl, &mut scene, &pbr)?;
+
+        let sun = scene.add_node("sun", Transform::identity(), None);
+        scene.add_component(
+            sun,
+            Component::Light(Light::directional(
+                sun_direction,
+                Vec3::new(1.0, 0.95, 0.9),
+                3.0,
+            )),
+        );
+
+        let orbit = OrbitController::new(Vec3::zeros(), 12.0);
+        let mut camera = Camera::new(Projection::Perspective {
+            fov_y: FIELD_OF_VIEW,
+            near: NEAR_PLANE,
+            far: FAR_PLANE,
+        });
+        camera.view = orbit.view();
+
+        Ok(Self {
+            queue: RenderQueue::new(&gl),
+            post: PostProcessChain::standard(&gl)?,
+            skybox,
+            gl,
+            scene,
+            camera,
+            orbit,
+            lights,
+            render_stats: RenderStats::default(),
+        })
+    }
+
+    fn update(&mut self, d_time: f64) -> Result<(), GLError> {
+        self.orbit.update(d_time);
+        self.orbit.apply(&mut self.camera);
+        Ok(())
+    }
+
+    fn on_input(&mut self, event: &InputEvent) -> Result<(), GLError> {
+        self.camera.handle_event(event);
+        self.orbit.handle_event(event);
+        Ok(())
+    }
+
+    fn render(&mut self) -> Result<(), GLError> {
+        self.gl.clear_color(0.1, 0.1, 0.12, 1.0);
+        self.gl
+            .clear_depth(self.camera.projection().depth_clear_value());
+        let scene_target = self.post.begin()?;
+
+        let RenderList {
+            renderables,
+            lights,
+            ..
+        } = self.scene.collect();
+
+        let lights: Vec<Light> = lights.iter().map(LightItem::world_light).collect();
+        self.lights.upload(&lights);
+        self.lights.bind();
+
+        let mut culler = FrustumCuller::new(&self.camera);
+        for item in renderables {
+            if culler.is_mesh_visible(&item.renderable.mesh, &item.world) {
+                self.queue
+                    .push(&item.renderable.mesh, &item.renderable.material, item.world);
+            }
+        }
+        let opaque_stats = self.queue.submit_opaque(&self.camera);
+        self.skybox.render(&self.camera);
+        let transparent_stats = self.queue.submit_transparent(&self.camera);
+        self.render_stats = opaque_stats.merge(&transparent_stats);
+        drop(scene_target);
+
+        self.post.finish()
+    }
+
+    fn stats(&self) -> Option<String> {
+        let passes: Vec<&str> = self
+            .post
+            .pass_names()
+            .into_iter()
+            .filter(|name| self.post.is_enabled(name))
+            .collect();
+        Some(format!(
+            "draws:{} state changes:{} post:{}",
+            self.render_stats.draw_calls,
+            self.render_stats.state_changes(),
+            passes.join(","),
+        ))
+    }
+}
@@ -0,0 +1,169 @@
+use std::rc::Rc;
+
+use super::sample::Sample;
+use crate::gl;
+use crate::gl::camera::controller::CameraController;
+use crate::gl::camera::input::InputEvent;
+use crate::gl::camera::orbit::OrbitController;
+use crate::gl::camera::projection::Projection;
+use crate::gl::camera::Camera;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::materials::pipeline_state::BlendMode;
+use crate::gl::particles::appearance::{ParticleAppearance, ParticleRenderMode};
+use crate::gl::particles::emitter::{Emitter, EmitterShape};
+use crate::gl::particles::forces::ParticleForces;
+use crate::gl::particles::particle_system::ParticleSystem;
+use crate::gl::skybox::procedural_sky::ProceduralSky;
+use crate::gl::skybox::skybox_renderer::Skybox;
+use glm::{Vec3, Vec4};
+
+const FOUNTAIN_CAPACITY: usize = 8192;
+const SPARKS_CAPACITY: usize = 4096;
+const SMOKE_CAPACITY: usize = 1024;
+
+const SPARKS_ORBIT_RADIUS: f32 = 2.5;
+const SPARKS_ORBIT_SPEED: f32 = 0.8;
+
+const FIELD_OF_VIEW: f32 = std::f32::consts::FRAC_PI_3;
+const NEAR_PLANE: f32 = 0.1;
+const FAR_PLANE: f32 = 100.0;
+
+pub struct Particles {
+    gl: Rc<GL>,
+    systems: Vec<ParticleSystem>,
+    skybox: Skybox,
+    time: f32,
+    camera: Camera,
+    orbit: OrbitController,
+}
+
+fn fountain(gl: &Rc<GL>) -> Result<ParticleSystem, GLError> {
+    let emitter = Emitter::new(
+        EmitterShape::Cone {
+            angle: 0.25,
+            radius: 0.1,
+        },
+        Vec3::zeros(),
+    )
+    .with_direction(Vec3::y())
+    .with_rate(2000.0)
+    .with_lifetime(2.0, 3.0)
+    .with_speed(4.5, 5.5);
+    let appearance = ParticleAppearance::default()
+        .with_mode(ParticleRenderMode::Quads)
+        .with_blend(BlendMode::Alpha)
+        .with_size(0.06, 0.12)
+        .with_colors(Vec4::new(0.6, 0.8, 1.0, 0.9), Vec4::new(0.2, 0.4, 0.9, 0.0));
+    Ok(ParticleSystem::try_new(gl, FOUNTAIN_CAPACITY, emitter)?
+        .with_forces(ParticleForces::default().with_drag(0.2))
+        .with_appearance(appearance))
+}
+
+fn sparks(gl: &Rc<GL>) -> Result<ParticleSystem, GLError> {
+    let emitter = Emitter::new(EmitterShape::Sphere { radius: 0.2 }, Vec3::zeros())
+        .with_rate(1200.0)
+        .with_lifetime(1.5, 3.0)
+        .with_speed(0.2, 0.6);
+    let appearance = ParticleAppearance::default()
+        .with_mode(ParticleRenderMode::PointSprites)
+        .with_blend(BlendMode::Additive)
+        .with_size(0.08, 0.01);
+    Ok(ParticleSystem::try_new(gl, SPARKS_CAPACITY, emitter)?
+        .with_forces(
+            ParticleForces::none()
+                .with_drag(0.5)
+                .with_curl_noise(1.5, 0.8),
+        )
+        .with_appearance(appearance))
+}
+
+fn smoke(gl: &Rc<GL>) -> Result<ParticleSystem, GLError> {
+    let emitter = Emitter::new(EmitterShape::Point, Vec3::new(-3.0, 0.0, 0.0))
+        .with_rate(60.0)
+        .with_lifetime(4.0, 6.0)
+        .with_speed(0.1, 0.3);
+    let appearance = ParticleAppearance::default()
+        .with_mode(ParticleRenderMode::Quads)
+        .with_blend(BlendMode::Alpha)
+        .with_size(0.3, 1.5)
+        .with_colors(Vec4::new(0.5, 0.5, 0.5, 0.4), Vec4::new(0.8, 0.8, 0.8, 0.0));
+    Ok(ParticleSystem::try_new(gl, SMOKE_CAPACITY, emitter)?
+        .with_forces(
+            ParticleForces::none()
+                .with_gravity(Vec3::new(0.1, 0.4, 0.0))
+                .with_drag(0.3)
+                .with_curl_noise(0.2, 0.5),
+        )
+        .with_appearance(appearance))
+}
+
+impl Sample for Particles {
+    fn try_new(gl: GL) -> Result<Self, GLError> {
+        let gl = Rc::new(gl);
+        let systems = vec![fountain(&gl)?, sparks(&gl)?, smoke(&gl)?];
+
+        let sky = ProceduralSky::gradient(
+            Vec3::new(0.02, 0.03, 0.08),
+            Vec3::new(0.1, 0.1, 0.15),
+            Vec3::new(0.03, 0.03, 0.03),
+        );
+
+        let orbit = OrbitController::new(Vec3::new(0.0, 1.5, 0.0), 10.0);
+        let mut camera = Camera::new(Projection::Perspective {
+            fov_y: FIELD_OF_VIEW,
+            near: NEAR_PLANE,
+            far: FAR_PLANE,
+        });
+        camera.view = orbit.view();
+
+        Ok(Self {
+            skybox: Skybox::procedural(&gl, sky)?,
+            gl,
+            systems,
+            time: 0.0,
+            camera,
+            orbit,
+        })
+    }
+
+    fn update(&mut self, d_time: f64) -> Result<(), GLError> {
+        let delta_time = d_time as f32 / 1000.0;
+        self.time += delta_time;
+
+        let angle = self.time * SPARKS_ORBIT_SPEED;
+        self.systems[1].emitter_mut().position = Vec3::new(
+            SPARKS_ORBIT_RADIUS * angle.cos(),
+            2.0 + 0.5 * (angle * 2.0).sin(),
+            SPARKS_ORBIT_RADIUS * angle.sin(),
+        );
+        for system in self.systems.iter_mut() {
+            system.update(delta_time);
+        }
+
+        self.orbit.update(d_time);
+        self.orbit.apply(&mut self.camera);
+        Ok(())
+    }
+
+    fn on_input(&mut self, event: &InputEvent) -> Result<(), GLError> {
+        self.camera.handle_event(event);
+        self.orbit.handle_event(event);
+        Ok(())
+    }
+
+    fn render(&mut self) -> Result<(), GLError> {
+        let width = self.gl.drawing_buffer_width();
+        let height = self.gl.drawing_buffer_height();
+
+        self.gl.viewport(0, 0, width, height);
+        self.gl
+            .clear_depth(self.camera.projection().depth_clear_value());
+        self.gl.clear(gl::DEPTH_BUFFER_BIT);
+        self.skybox.render(&self.camera);
+        for system in self.systems.iter_mut() {
+            system.render(&self.camera);
+        }
+        Ok(())
+    }
+}
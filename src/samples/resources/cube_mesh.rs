@@ -5,28 +5,28 @@ use na::Matrix3xX;
 use crate::{
     faces,
     gl::{core::instance::GL, error::GLError, mesh::Mesh},
+    math::compute_normals::NormalWeighting,
     vertices,
 };
 
+const CREASE_ANGLE: f32 = std::f32::consts::FRAC_PI_4;
+
 pub fn get_cube_vertices() -> Matrix3xX<f32> {
     vertices![
-        -1.0, -1.0, -1.0, -1.0, 1.0, -1.0, 1.0, 1.0, -1.0, 1.0, -1.0, -1.0, -1.0, -1.0, 1.0, 1.0,
-        -1.0, 1.0, 1.0, 1.0, 1.0, -1.0, 1.0, 1.0, -1.0, -1.0, -1.0, -1.0, -1.0, 1.0, -1.0, 1.0,
-        1.0, -1.0, 1.0, -1.0, 1.0, -1.0, -1.0, 1.0, 1.0, -1.0, 1.0, 1.0, 1.0, 1.0, -1.0, 1.0, -1.0,
-        -1.0, -1.0, 1.0, -1.0, -1.0, 1.0, -1.0, 1.0, -1.0, -1.0, 1.0, -1.0, 1.0, -1.0, -1.0, 1.0,
-        1.0, 1.0, 1.0, 1.0, 1.0, 1.0, -1.0,
+        -1.0, -1.0, -1.0, 1.0, -1.0, -1.0, -1.0, 1.0, -1.0, 1.0, 1.0, -1.0, -1.0, -1.0, 1.0, 1.0,
+        -1.0, 1.0, -1.0, 1.0, 1.0, 1.0, 1.0, 1.0,
     ]
 }
 
 pub fn get_cube_faces() -> Matrix3xX<i32> {
     faces![
-        0, 1, 2, 2, 3, 0, 4, 5, 6, 6, 7, 4, 8, 9, 10, 10, 11, 8, 12, 13, 14, 14, 15, 12, 16, 17,
-        18, 18, 19, 16, 20, 21, 22, 22, 23, 20,
+        0, 2, 3, 3, 1, 0, 4, 5, 7, 7, 6, 4, 0, 4, 6, 6, 2, 0, 1, 3, 7, 7, 5, 1, 0, 1, 5, 5, 4, 0,
+        2, 6, 7, 7, 3, 2,
     ]
 }
 
 pub fn build_cube_mesh(gl: &Rc<GL>) -> Result<Mesh, GLError> {
     Mesh::new_builder(get_cube_vertices(), get_cube_faces())
-        .build_normals()
+        .build_smooth_normals(CREASE_ANGLE, NormalWeighting::Angle)
         .build(gl)
 }
@@ -1,2 +1,3 @@
 pub mod cube_mesh;
-pub mod simple_shaders;
+pub mod plane_mesh;
+pub mod tentacle_mesh;
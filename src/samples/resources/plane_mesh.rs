@@ -0,0 +1,17 @@
+use std::rc::Rc;
+
+use crate::{
+    faces,
+    gl::{core::instance::GL, error::GLError, mesh::Mesh},
+    vertices,
+};
+
+pub fn build_plane_mesh(gl: &Rc<GL>, half_size: f32) -> Result<Mesh, GLError> {
+    let s = half_size;
+    Mesh::new_builder(
+        vertices![-s, -s, 0.0, s, -s, 0.0, s, s, 0.0, -s, s, 0.0],
+        faces![0, 1, 2, 2, 3, 0],
+    )
+    .build_normals()
+    .build(gl)
+}
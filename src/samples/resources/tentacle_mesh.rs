@@ -0,0 +1,70 @@
+use std::f32::consts::TAU;
+use std::rc::Rc;
+
+use na::{Matrix2xX, Matrix3xX};
+
+use crate::animation::skin::SkinWeights;
+use crate::gl::{core::instance::GL, error::GLError, mesh::Mesh};
+
+const TIP_RADIUS_SCALE: f32 = 0.3;
+
+pub fn build_tentacle_mesh(
+    gl: &Rc<GL>,
+    segments: usize,
+    rings: usize,
+    height: f32,
+    radius: f32,
+    joints: usize,
+) -> Result<Mesh, GLError> {
+    let columns = segments + 1;
+    let count = columns * (rings + 1);
+    let mut vertices = Matrix3xX::<f32>::zeros(count);
+    let mut normals = Matrix3xX::<f32>::zeros(count);
+    let mut tex_coords = Matrix2xX::<f32>::zeros(count);
+    let mut influences = Vec::with_capacity(count);
+    let bone_length = height / joints as f32;
+
+    for ring in 0..=rings {
+        let v = ring as f32 / rings as f32;
+        let y = v * height;
+        let ring_radius = radius * (1.0 - (1.0 - TIP_RADIUS_SCALE) * v);
+
+        let bone = y / bone_length - 0.5;
+        let lower = (bone.floor().max(0.0) as usize).min(joints - 1);
+        let upper = (lower + 1).min(joints - 1);
+        let t = (bone - lower as f32).clamp(0.0, 1.0);
+
+        for segment in 0..=segments {
+            let u = segment as f32 / segments as f32;
+            let phi = u * TAU;
+            let index = ring * columns + segment;
+            let direction = na::Vector3::new(phi.cos(), 0.0, -phi.sin());
+            vertices.set_column(
+                index,
+                &(direction * ring_radius + na::Vector3::new(0.0, y, 0.0)),
+            );
+            normals.set_column(index, &direction);
+            tex_coords.set_column(index, &na::Vector2::new(u, v));
+            influences.push(vec![(lower, 1.0 - t), (upper, t)]);
+        }
+    }
+
+    let mut faces = Matrix3xX::<i32>::zeros(segments * rings * 2);
+    let mut face = 0;
+    for ring in 0..rings {
+        for segment in 0..segments {
+            let a = (ring * columns + segment) as i32;
+            let b = a + columns as i32;
+            faces.set_column(face, &na::Vector3::new(a, a + 1, b));
+            faces.set_column(face + 1, &na::Vector3::new(a + 1, b + 1, b));
+            face += 2;
+        }
+    }
+
+    let skin = SkinWeights::from_influences(&influences);
+    Mesh::new_builder(vertices, faces)
+        .add_normals(normals)
+        .add_tex_coords(tex_coords)
+        .add_skin(skin.joints, skin.weights)
+        .build(gl)
+}
@@ -1,3 +1,5 @@
+use crate::gl::camera::input::InputEvent;
+use crate::gl::picking::{picker::PickEvent, picking_pass::PickingPass};
 use crate::gl::{core::instance::GL, error::GLError};
 
 pub trait Sample: Sized {
@@ -6,4 +8,20 @@ pub trait Sample: Sized {
     fn update(&mut self, d_time: f64) -> Result<(), GLError>;
 
     fn render(&mut self) -> Result<(), GLError>;
+
+    fn on_input(&mut self, _event: &InputEvent) -> Result<(), GLError> {
+        Ok(())
+    }
+
+    fn render_picking(&mut self, _pass: &PickingPass) -> Result<(), GLError> {
+        Ok(())
+    }
+
+    fn on_pick(&mut self, _event: &PickEvent) -> Result<(), GLError> {
+        Ok(())
+    }
+
+    fn stats(&self) -> Option<String> {
+        None
+    }
 }
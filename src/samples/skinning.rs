@@ -0,0 +1,246 @@
+use std::rc::Rc;
+
+use super::resources::tentacle_mesh::build_tentacle_mesh;
+use super::sample::Sample;
+use crate::animation::blender::AnimationBlender;
+use crate::animation::clip::{AnimationClip, Track};
+use crate::animation::interpolation::Interpolation;
+use crate::animation::skeleton::{Joint, Skeleton};
+use crate::gl;
+use crate::gl::camera::controller::CameraController;
+use crate::gl::camera::input::InputEvent;
+use crate::gl::camera::orbit::OrbitController;
+use crate::gl::camera::projection::Projection;
+use crate::gl::camera::Camera;
+use crate::gl::core::instance::GL;
+use crate::gl::error::GLError;
+use crate::gl::lighting::light::Light;
+use crate::gl::lighting::light_buffer::LightBuffer;
+use crate::gl::materials::material::Material;
+use crate::gl::mesh::Mesh;
+use crate::gl::pbr::pbr_material::PbrMaterial;
+use crate::gl::pbr::pbr_pipeline::PbrPipeline;
+use crate::gl::pbr::shaders::pbr_fragment_shader;
+use crate::gl::shader::program::ShaderProgram;
+use crate::gl::shader::shader_type::ShaderType;
+use crate::gl::skinning::joint_palette::{JointPalette, JointStorage, DEFAULT_MAX_UNIFORM_JOINTS};
+use crate::scene::transform::Transform;
+use crate::utils::web;
+use glm::{Mat4x4, Vec3};
+use na::UnitQuaternion;
+
+pub const JOINT_STORAGE_QUERY_PARAM: &str = "joints";
+pub const JOINT_TEXTURE_STORAGE: &str = "texture";
+
+const MAX_LIGHTS: usize = 2;
+
+const JOINT_COUNT: usize = 6;
+const TENTACLE_HEIGHT: f32 = 4.0;
+const TENTACLE_RADIUS: f32 = 0.35;
+const TENTACLE_SEGMENTS: usize = 32;
+const TENTACLE_RINGS: usize = 64;
+
+const SWAY_ANGLE: f32 = 0.3;
+const CURL_ANGLE: f32 = 0.45;
+const CLIP_DURATION: f32 = 2.0;
+const CLIP_SWITCH_SECS: f32 = 4.0;
+const CROSS_FADE_SECS: f32 = 0.8;
+
+const FIELD_OF_VIEW: f32 = std::f32::consts::FRAC_PI_4;
+const NEAR_PLANE: f32 = 0.1;
+const FAR_PLANE: f32 = 100.0;
+
+pub struct Skinning {
+    gl: Rc<GL>,
+    mesh: Mesh,
+    material: Material,
+    palette: JointPalette,
+    skeleton: Skeleton,
+    clips: Vec<Rc<AnimationClip>>,
+    blender: AnimationBlender,
+    active_clip: usize,
+    clip_time: f32,
+    lights: LightBuffer,
+    camera: Camera,
+    orbit: OrbitController,
+}
+
+fn build_skeleton() -> Result<Skeleton, GLError> {
+    let bone_length = TENTACLE_HEIGHT / JOINT_COUNT as f32;
+    let joints = (0..JOINT_COUNT)
+        .map(|index| {
+            let offset = if index == 0 { 0.0 } else { bone_length };
+            Joint::new(
+                &format!("joint_{}", index),
+                index.checked_sub(1),
+                Transform::from_translation(Vec3::new(0.0, offset, 0.0)),
+            )
+        })
+        .collect();
+    Ok(Skeleton::try_new(joints)?.with_rest_bind_pose())
+}
+
+fn wave_clip(
+    name: &str,
+    axis: &Vec3,
+    angle: f32,
+    interpolation: Interpolation,
+) -> Result<AnimationClip, GLError> {
+    let axis = na::Unit::new_normalize(*axis);
+    let times = vec![
+        0.0,
+        CLIP_DURATION * 0.25,
+        CLIP_DURATION * 0.75,
+        CLIP_DURATION,
+    ];
+    let zero_tangent = UnitQuaternion::new_unchecked(na::Quaternion::new(0.0, 0.0, 0.0, 0.0));
+    let mut clip = AnimationClip::new(name);
+
+    for joint in 1..JOINT_COUNT {
+        let phase = joint as f32 / JOINT_COUNT as f32;
+        let keys = [0.0, angle, -angle, 0.0];
+        let keyframes = keys
+            .iter()
+            .map(|&key| UnitQuaternion::from_axis_angle(&axis, key * (0.5 + phase)));
+        let values = match interpolation {
+            Interpolation::CubicSpline => keyframes
+                .flat_map(|value| vec![zero_tangent, value, zero_tangent])
+                .collect(),
+            Interpolation::Step | Interpolation::Linear => keyframes.collect(),
+        };
+        clip = clip.with_rotation(joint, Track::try_new(times.clone(), values, interpolation)?);
+    }
+    Ok(clip)
+}
+
+impl Sample for Skinning {
+    fn try_new(gl: GL) -> Result<Self, GLError> {
+        let gl = Rc::new(gl);
+
+        let skeleton = build_skeleton()?;
+        let storage = match web::query_param(JOINT_STORAGE_QUERY_PARAM).as_deref() {
+            Some(JOINT_TEXTURE_STORAGE) => JointStorage::Texture,
+            _ => JointStorage::Uniforms {
+                max_joints: DEFAULT_MAX_UNIFORM_JOINTS,
+            },
+        };
+        let palette = JointPalette::try_new(&gl, storage, skeleton.len())?;
+
+        let mut lights = LightBuffer::try_new(&gl, MAX_LIGHTS)?;
+        lights.upload(&[
+            Light::directional(Vec3::new(-0.5, -0.7, -0.6), Vec3::new(1.0, 0.95, 0.9), 3.0),
+            Light::directional(Vec3::new(0.6, -0.2, 0.7), Vec3::new(0.4, 0.5, 0.7), 1.0),
+        ]);
+
+        let pbr = PbrPipeline::try_new(&gl, &lights)?;
+        let program = Rc::new(
+            ShaderProgram::builder(&gl)
+                .add_source(ShaderType::Vertex, palette.vertex_shader())
+                .add_source(ShaderType::Fragment, pbr_fragment_shader(MAX_LIGHTS))
+                .build()?,
+        );
+        lights.attach(&program);
+
+        let mut material = pbr
+            .material(&PbrMaterial::new(Vec3::new(0.8, 0.3, 0.5), 0.0, 0.45))
+            .with_program(&program);
+        palette.attach(&mut material);
+
+        let clips = vec![
+            Rc::new(wave_clip(
+                "sway",
+                &Vec3::z(),
+                SWAY_ANGLE,
+                Interpolation::Linear,
+            )?),
+            Rc::new(wave_clip(
+                "curl",
+                &Vec3::x(),
+                CURL_ANGLE,
+                Interpolation::CubicSpline,
+            )?),
+        ];
+        let mut blender = AnimationBlender::new();
+        blender.play(Rc::clone(&clips[0]));
+
+        let orbit = OrbitController::new(Vec3::new(0.0, TENTACLE_HEIGHT * 0.5, 0.0), 9.0);
+        let mut camera = Camera::new(Projection::Perspective {
+            fov_y: FIELD_OF_VIEW,
+            near: NEAR_PLANE,
+            far: FAR_PLANE,
+        });
+        camera.view = orbit.view();
+
+        Ok(Self {
+            mesh: build_tentacle_mesh(
+                &gl,
+                TENTACLE_SEGMENTS,
+                TENTACLE_RINGS,
+                TENTACLE_HEIGHT,
+                TENTACLE_RADIUS,
+                JOINT_COUNT,
+            )?,
+            gl,
+            material,
+            palette,
+            skeleton,
+            clips,
+            blender,
+            active_clip: 0,
+            clip_time: 0.0,
+            lights,
+            camera,
+            orbit,
+        })
+    }
+
+    fn update(&mut self, d_time: f64) -> Result<(), GLError> {
+        let delta_time = d_time as f32 / 1000.0;
+        self.clip_time += delta_time;
+        if self.clip_time >= CLIP_SWITCH_SECS {
+            self.clip_time = 0.0;
+            self.active_clip = (self.active_clip + 1) % self.clips.len();
+            self.blender
+                .cross_fade(Rc::clone(&self.clips[self.active_clip]), CROSS_FADE_SECS);
+        }
+        self.blender.update(delta_time);
+
+        let pose = self.blender.evaluate(&self.skeleton);
+        let matrices = self.skeleton.skinning_matrices(&pose);
+        self.palette.upload(&mut self.material, &matrices)?;
+
+        self.orbit.update(d_time);
+        self.orbit.apply(&mut self.camera);
+        Ok(())
+    }
+
+    fn on_input(&mut self, event: &InputEvent) -> Result<(), GLError> {
+        self.camera.handle_event(event);
+        self.orbit.handle_event(event);
+        Ok(())
+    }
+
+    fn render(&mut self) -> Result<(), GLError> {
+        let width = self.gl.drawing_buffer_width();
+        let height = self.gl.drawing_buffer_height();
+
+        self.gl.viewport(0, 0, width, height);
+        self.gl.clear_color(0.1, 0.1, 0.12, 1.0);
+        self.gl
+            .clear_depth(self.camera.projection().depth_clear_value());
+        self.gl.clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
+
+        self.lights.bind();
+        self.mesh
+            .render(&self.material, &self.camera.with_model(Mat4x4::identity()));
+        Ok(())
+    }
+
+    fn stats(&self) -> Option<String> {
+        Some(format!(
+            "clip:{} fading:{}",
+            self.clips[self.active_clip].name(),
+            self.blender.is_fading(),
+        ))
+    }
+}
@@ -0,0 +1,17 @@
+use std::rc::Rc;
+
+use crate::gl::lighting::light::Light;
+use crate::gl::materials::material::Material;
+use crate::gl::mesh::Mesh;
+
+#[derive(Clone)]
+pub struct Renderable {
+    pub mesh: Rc<Mesh>,
+    pub material: Rc<Material>,
+}
+
+#[derive(Clone)]
+pub enum Component {
+    Renderable(Renderable),
+    Light(Light),
+}
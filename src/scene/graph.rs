@@ -0,0 +1,455 @@
+use glm::Mat4x4;
+
+use super::component::{Component, Renderable};
+use super::node::{Node, NodeId};
+use super::transform::Transform;
+use crate::gl::lighting::light::Light;
+
+struct Slot {
+    generation: u32,
+    node: Option<Node>,
+}
+
+pub struct RenderItem {
+    pub node: NodeId,
+    pub world: Mat4x4,
+    pub renderable: Renderable,
+}
+
+pub struct LightItem {
+    pub world: Mat4x4,
+    pub light: Light,
+}
+
+impl LightItem {
+    pub fn world_light(&self) -> Light {
+        self.light.transform(&self.world)
+    }
+}
+
+#[derive(Default)]
+pub struct RenderList {
+    pub renderables: Vec<RenderItem>,
+    pub lights: Vec<LightItem>,
+}
+
+#[derive(Default)]
+pub struct SceneGraph {
+    slots: Vec<Slot>,
+    free: Vec<usize>,
+    roots: Vec<NodeId>,
+}
+
+impl SceneGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self, name: &str, local: Transform, parent: Option<NodeId>) -> NodeId {
+        let parent = parent.filter(|&p| self.contains(p));
+        let node = Node::new(name, local, parent);
+        let id = match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index];
+                slot.node = Some(node);
+                NodeId::new(index, slot.generation)
+            }
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    node: Some(node),
+                });
+                NodeId::new(self.slots.len() - 1, 0)
+            }
+        };
+
+        self.attach(id, parent);
+        id
+    }
+
+    pub fn remove_node(&mut self, id: NodeId) {
+        if !self.contains(id) {
+            return;
+        }
+
+        self.detach(id);
+        let mut stack = vec![id];
+        while let Some(current) = stack.pop() {
+            let slot = &mut self.slots[current.index()];
+            if let Some(node) = slot.node.take() {
+                stack.extend(node.children);
+            }
+            slot.generation += 1;
+            self.free.push(current.index());
+        }
+    }
+
+    pub fn contains(&self, id: NodeId) -> bool {
+        self.slots
+            .get(id.index())
+            .is_some_and(|slot| slot.generation == id.generation() && slot.node.is_some())
+    }
+
+    pub fn node(&self, id: NodeId) -> Option<&Node> {
+        self.slots
+            .get(id.index())
+            .filter(|slot| slot.generation == id.generation())
+            .and_then(|slot| slot.node.as_ref())
+    }
+
+    pub fn node_mut(&mut self, id: NodeId) -> Option<&mut Node> {
+        self.slots
+            .get_mut(id.index())
+            .filter(|slot| slot.generation == id.generation())
+            .and_then(|slot| slot.node.as_mut())
+    }
+
+    pub fn add_component(&mut self, id: NodeId, component: Component) {
+        if let Some(node) = self.node_mut(id) {
+            node.components.push(component);
+        }
+    }
+
+    pub fn update_local(&mut self, id: NodeId, update: impl FnOnce(&mut Transform)) {
+        if let Some(node) = self.node_mut(id) {
+            update(&mut node.local);
+            self.mark_dirty(id);
+        }
+    }
+
+    pub fn set_parent(&mut self, id: NodeId, parent: Option<NodeId>) {
+        if !self.contains(id)
+            || parent.is_some_and(|p| !self.contains(p) || self.is_ancestor(id, p))
+        {
+            return;
+        }
+
+        self.detach(id);
+        if let Some(node) = self.node_mut_unchecked(id) {
+            node.parent = parent;
+        }
+        self.attach(id, parent);
+        self.mark_dirty(id);
+    }
+
+    fn is_ancestor(&self, ancestor: NodeId, id: NodeId) -> bool {
+        let mut current = Some(id);
+        while let Some(node) = current {
+            if node == ancestor {
+                return true;
+            }
+            current = self.node(node).and_then(Node::parent);
+        }
+        false
+    }
+
+    #[cfg(test)]
+    pub(crate) fn world_matrix(&mut self, id: NodeId) -> Option<Mat4x4> {
+        let node = self.node(id)?;
+        if !node.dirty {
+            return Some(node.world);
+        }
+
+        let parent = node.parent;
+        let parent_world = match parent {
+            Some(parent) => self.world_matrix(parent)?,
+            None => Mat4x4::identity(),
+        };
+        let node = self.node_mut_unchecked(id)?;
+        node.world = parent_world * node.local.matrix();
+        node.dirty = false;
+        Some(node.world)
+    }
+
+    pub fn update_world_matrices(&mut self) {
+        let mut stack: Vec<(NodeId, Mat4x4, bool)> = self
+            .roots
+            .iter()
+            .rev()
+            .map(|&root| (root, Mat4x4::identity(), false))
+            .collect();
+
+        while let Some((id, parent_world, parent_changed)) = stack.pop() {
+            let node = match self.node_mut_unchecked(id) {
+                Some(node) => node,
+                None => continue,
+            };
+            let changed = parent_changed || node.dirty;
+            if changed {
+                node.world = parent_world * node.local.matrix();
+                node.dirty = false;
+            }
+            let world = node.world;
+            for &child in node.children.iter().rev() {
+                stack.push((child, world, changed));
+            }
+        }
+    }
+
+    pub fn collect(&mut self) -> RenderList {
+        self.update_world_matrices();
+
+        let mut list = RenderList::default();
+        let mut stack: Vec<NodeId> = self.roots.iter().rev().copied().collect();
+        while let Some(id) = stack.pop() {
+            let node = match self.node_mut_unchecked(id) {
+                Some(node) => node,
+                None => continue,
+            };
+            for component in node.components.iter() {
+                match component {
+                    Component::Renderable(renderable) => list.renderables.push(RenderItem {
+                        node: id,
+                        world: node.world,
+                        renderable: renderable.clone(),
+                    }),
+                    Component::Light(light) => list.lights.push(LightItem {
+                        world: node.world,
+                        light: *light,
+                    }),
+                }
+            }
+            stack.extend(node.children.iter().rev());
+        }
+        list
+    }
+
+    fn mark_dirty(&mut self, id: NodeId) {
+        let mut stack = vec![id];
+        while let Some(current) = stack.pop() {
+            let node = match self.node_mut_unchecked(current) {
+                Some(node) => node,
+                None => continue,
+            };
+            if node.dirty && current != id {
+                continue;
+            }
+            node.dirty = true;
+            stack.extend(node.children.iter());
+        }
+    }
+
+    fn attach(&mut self, id: NodeId, parent: Option<NodeId>) {
+        match parent.and_then(|parent| self.node_mut_unchecked(parent)) {
+            Some(parent) => parent.children.push(id),
+            None => self.roots.push(id),
+        }
+    }
+
+    fn detach(&mut self, id: NodeId) {
+        let parent = self
+            .node_mut_unchecked(id)
+            .and_then(|node| node.parent.take());
+        match parent.and_then(|parent| self.node_mut_unchecked(parent)) {
+            Some(parent) => parent.children.retain(|&child| child != id),
+            None => self.roots.retain(|&root| root != id),
+        }
+    }
+
+    fn node_mut_unchecked(&mut self, id: NodeId) -> Option<&mut Node> {
+        self.slots
+            .get_mut(id.index())
+            .and_then(|slot| slot.node.as_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glm::Vec3;
+    use na::UnitQuaternion;
+    use std::f32::consts::FRAC_PI_2;
+
+    const TOLERANCE: f32 = 1e-5;
+
+    fn world_origin(graph: &mut SceneGraph, id: NodeId) -> Vec3 {
+        let world = graph.world_matrix(id).unwrap();
+        world.transform_point(&Vec3::zeros().into()).coords
+    }
+
+    fn assert_near(actual: Vec3, expected: Vec3) {
+        assert!(
+            (actual - expected).norm() < TOLERANCE,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn propagates_parent_transform_to_children() {
+        let mut graph = SceneGraph::new();
+        let root = graph.add_node(
+            "root",
+            Transform::from_translation(Vec3::new(1.0, 0.0, 0.0)).with_uniform_scale(2.0),
+            None,
+        );
+        let child = graph.add_node(
+            "child",
+            Transform {
+                rotation: UnitQuaternion::from_axis_angle(&Vec3::z_axis(), FRAC_PI_2),
+                ..Transform::from_translation(Vec3::new(0.0, 2.0, 0.0))
+            },
+            Some(root),
+        );
+        let grandchild = graph.add_node(
+            "grandchild",
+            Transform::from_translation(Vec3::new(1.0, 0.0, 0.0)),
+            Some(child),
+        );
+
+        graph.update_world_matrices();
+        assert!(!graph.node(grandchild).unwrap().dirty);
+        assert_near(world_origin(&mut graph, root), Vec3::new(1.0, 0.0, 0.0));
+        assert_near(world_origin(&mut graph, child), Vec3::new(1.0, 4.0, 0.0));
+        assert_near(
+            world_origin(&mut graph, grandchild),
+            Vec3::new(1.0, 6.0, 0.0),
+        );
+        assert_eq!(graph.roots, [root]);
+        assert_eq!(graph.node(root).unwrap().children, [child]);
+    }
+
+    #[test]
+    fn world_matrix_resolves_dirty_ancestors_lazily() {
+        let mut graph = SceneGraph::new();
+        let root = graph.add_node(
+            "root",
+            Transform::from_translation(Vec3::new(0.0, 0.0, 3.0)),
+            None,
+        );
+        let child = graph.add_node(
+            "child",
+            Transform::from_translation(Vec3::new(0.0, 1.0, 0.0)),
+            Some(root),
+        );
+
+        assert_near(world_origin(&mut graph, child), Vec3::new(0.0, 1.0, 3.0));
+        assert!(!graph.node(root).unwrap().dirty);
+        assert!(!graph.node(child).unwrap().dirty);
+    }
+
+    #[test]
+    fn update_local_repropagates_to_descendants() {
+        let mut graph = SceneGraph::new();
+        let root = graph.add_node("root", Transform::identity(), None);
+        let child = graph.add_node(
+            "child",
+            Transform::from_translation(Vec3::new(1.0, 0.0, 0.0)),
+            Some(root),
+        );
+        let grandchild = graph.add_node(
+            "grandchild",
+            Transform::from_translation(Vec3::new(0.0, 1.0, 0.0)),
+            Some(child),
+        );
+        graph.update_world_matrices();
+
+        graph.update_local(root, |local| local.translation.z = 5.0);
+        assert!(graph.node(root).unwrap().dirty);
+        assert!(graph.node(child).unwrap().dirty);
+        assert!(graph.node(grandchild).unwrap().dirty);
+
+        graph.update_world_matrices();
+        assert_near(
+            world_origin(&mut graph, grandchild),
+            Vec3::new(1.0, 1.0, 5.0),
+        );
+
+        graph.update_local(child, |local| local.translation.x = 2.0);
+        assert!(!graph.node(root).unwrap().dirty);
+        assert!(graph.node(grandchild).unwrap().dirty);
+        assert_near(
+            world_origin(&mut graph, grandchild),
+            Vec3::new(2.0, 1.0, 5.0),
+        );
+    }
+
+    #[test]
+    fn set_parent_repropagates_and_rejects_cycles() {
+        let mut graph = SceneGraph::new();
+        let left = graph.add_node(
+            "left",
+            Transform::from_translation(Vec3::new(-4.0, 0.0, 0.0)),
+            None,
+        );
+        let right = graph.add_node(
+            "right",
+            Transform::from_translation(Vec3::new(4.0, 0.0, 0.0)),
+            None,
+        );
+        let child = graph.add_node(
+            "child",
+            Transform::from_translation(Vec3::new(0.0, 1.0, 0.0)),
+            Some(left),
+        );
+        let grandchild = graph.add_node(
+            "grandchild",
+            Transform::from_translation(Vec3::new(0.0, 0.0, 1.0)),
+            Some(child),
+        );
+        graph.update_world_matrices();
+        assert_near(
+            world_origin(&mut graph, grandchild),
+            Vec3::new(-4.0, 1.0, 1.0),
+        );
+
+        graph.set_parent(child, Some(right));
+        assert!(graph.node(grandchild).unwrap().dirty);
+        assert!(graph.node(left).unwrap().children.is_empty());
+        assert_eq!(graph.node(right).unwrap().children, [child]);
+        graph.update_world_matrices();
+        assert_near(
+            world_origin(&mut graph, grandchild),
+            Vec3::new(4.0, 1.0, 1.0),
+        );
+
+        graph.set_parent(right, Some(grandchild));
+        assert_eq!(graph.node(right).unwrap().parent(), None);
+        assert_eq!(graph.roots, [left, right]);
+
+        graph.set_parent(child, None);
+        assert_eq!(graph.roots, [left, right, child]);
+        assert_near(
+            world_origin(&mut graph, grandchild),
+            Vec3::new(0.0, 1.0, 1.0),
+        );
+    }
+
+    #[test]
+    fn removed_node_ids_are_rejected() {
+        let mut graph = SceneGraph::new();
+        let root = graph.add_node("root", Transform::identity(), None);
+        let child = graph.add_node("child", Transform::identity(), Some(root));
+        let grandchild = graph.add_node("grandchild", Transform::identity(), Some(child));
+
+        graph.remove_node(child);
+        assert!(graph.contains(root));
+        assert!(!graph.contains(child));
+        assert!(!graph.contains(grandchild));
+        assert!(graph.node(root).unwrap().children.is_empty());
+
+        let reused = graph.add_node("reused", Transform::identity(), None);
+        assert!(reused.index() == child.index() || reused.index() == grandchild.index());
+        assert!(graph.contains(reused));
+        assert!(graph.node(child).is_none());
+        assert!(graph.node(grandchild).is_none());
+        assert!(graph.node_mut(child).is_none());
+        assert!(graph.world_matrix(child).is_none());
+
+        let moved = Transform::from_translation(Vec3::new(1.0, 2.0, 3.0));
+        graph.update_local(child, |local| *local = moved);
+        graph.update_local(grandchild, |local| *local = moved);
+        assert_eq!(graph.node(reused).unwrap().local, Transform::identity());
+
+        let orphan = graph.add_node("orphan", Transform::identity(), Some(child));
+        assert_eq!(graph.node(orphan).unwrap().parent(), None);
+        graph.set_parent(reused, Some(grandchild));
+        assert_eq!(graph.node(reused).unwrap().parent(), None);
+
+        graph.remove_node(child);
+        assert!(graph.contains(reused));
+        assert!(graph.contains(orphan));
+        assert_eq!(graph.collect().renderables.len(), 0);
+    }
+}
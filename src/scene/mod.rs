@@ -0,0 +1,4 @@
+pub mod component;
+pub mod graph;
+pub mod node;
+pub mod transform;
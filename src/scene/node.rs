@@ -0,0 +1,55 @@
+use glm::Mat4x4;
+
+use super::component::Component;
+use super::transform::Transform;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId {
+    index: u32,
+    generation: u32,
+}
+
+impl NodeId {
+    pub(super) fn new(index: usize, generation: u32) -> Self {
+        Self {
+            index: index as u32,
+            generation,
+        }
+    }
+
+    pub fn index(self) -> usize {
+        self.index as usize
+    }
+
+    pub fn generation(self) -> u32 {
+        self.generation
+    }
+}
+
+pub struct Node {
+    pub name: String,
+    pub components: Vec<Component>,
+    pub(super) local: Transform,
+    pub(super) world: Mat4x4,
+    pub(super) dirty: bool,
+    pub(super) parent: Option<NodeId>,
+    pub(super) children: Vec<NodeId>,
+}
+
+impl Node {
+    pub(super) fn new(name: &str, local: Transform, parent: Option<NodeId>) -> Self {
+        Self {
+            name: name.into(),
+            components: vec![],
+            local,
+            world: Mat4x4::identity(),
+            dirty: true,
+            parent,
+            children: vec![],
+        }
+    }
+
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+}
@@ -0,0 +1,47 @@
+use glm::{Mat4x4, Vec3};
+use na::UnitQuaternion;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    pub translation: Vec3,
+    pub rotation: UnitQuaternion<f32>,
+    pub scale: Vec3,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Transform {
+    pub fn identity() -> Self {
+        Self {
+            translation: Vec3::zeros(),
+            rotation: UnitQuaternion::identity(),
+            scale: Vec3::repeat(1.0),
+        }
+    }
+
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self {
+            translation,
+            ..Self::identity()
+        }
+    }
+
+    pub fn with_scale(mut self, scale: Vec3) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn with_uniform_scale(self, scale: f32) -> Self {
+        self.with_scale(Vec3::repeat(scale))
+    }
+
+    pub fn matrix(&self) -> Mat4x4 {
+        Mat4x4::new_translation(&self.translation)
+            * self.rotation.to_homogeneous()
+            * Mat4x4::new_nonuniform_scaling(&self.scale)
+    }
+}
@@ -1,5 +1,10 @@
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url, UrlSearchParams};
+
 use crate::utils::logging::ExtUnwrapLog;
 
+const REVOKE_OBJECT_URL_DELAY_MS: i32 = 1000;
+
 pub fn window() -> web_sys::Window {
     web_sys::window().ok_or("Can't access window").unwrap_log()
 }
@@ -23,3 +28,30 @@ pub fn window_size() -> (u32, u32) {
     let height = window().inner_height().unwrap().as_f64().unwrap() as u32;
     (width, height)
 }
+
+pub fn download_bytes(file_name: &str, mime_type: &str, bytes: &[u8]) -> Result<(), JsValue> {
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = Blob::new_with_u8_array_sequence_and_options(&parts, &options)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let anchor: HtmlAnchorElement = document().create_element("a")?.dyn_into()?;
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+
+    let revoke = Closure::once_into_js(move || {
+        let _ = Url::revoke_object_url(&url);
+    });
+    window().set_timeout_with_callback_and_timeout_and_arguments_0(
+        revoke.unchecked_ref(),
+        REVOKE_OBJECT_URL_DELAY_MS,
+    )?;
+    Ok(())
+}
+
+pub fn query_param(name: &str) -> Option<String> {
+    let search = window().location().search().ok()?;
+    UrlSearchParams::new_with_str(&search).ok()?.get(name)
+}